@@ -1,21 +1,103 @@
 import React, { useState, useEffect, useCallback, useRef, useMemo } from "react"
 import { Box, useInput, useStdout, useApp } from "ink"
 import type { Graph, SessionInfo, Source } from "./core/types"
-import type { ZoomLevel, CellMode } from "./core/zoom"
-import { getVisualBranch } from "./core/zoom"
+import type { ZoomLevel, CellMode, VisibilityMask } from "./core/zoom"
+import { applyFileLanes } from "./core/files"
+import { getVisualBranch, getNodePreview, applyVisibility, getHiddenLabels, SHOW_ALL } from "./core/zoom"
+import { parseFilter, nextQuickFilter } from "./core/filter"
+import type { SessionSort } from "./core/sessions"
+import { sortSessions, nextSessionSort, filterSessions, sessionStamp, shortSessionId, refreshedSessions, cycleRecentSession } from "./core/sessions"
+import { createLru } from "./core/lru"
+import { findSameKind, findParent, findFirstChild, findAgentLink, getBreadcrumb } from "./core/navigation"
+import { computeRelations } from "./core/related"
+import { tokenHeat } from "./core/heat"
+import { buildGanttBars, barForNode } from "./core/gantt"
+import { needsBuckets, bucketNodes, bucketOf, stepBucket } from "./core/buckets"
+import { LANES } from "./core/lanes"
+import { buildTranscript, buildReadingLines, lineOfNode } from "./core/transcript"
+import { getTurnBounds, listTurns, computeTurnStats, turnPrompt } from "./core/turns"
+import { turnToMarkdown, turnToJson, pinsToMarkdown, forkToMarkdown, forkToJson } from "./core/export"
+import { writeExport } from "./storage/exports"
+import { extractCodeBlocks, codeBlockExtension } from "./core/markdown"
+import { copyToClipboard } from "./ui/clipboard"
+import { tr } from "./ui/i18n"
+import { detectLoop } from "./core/loops"
+import { totalTokens } from "./core/stats"
+import { checkBudget } from "./core/budget"
+import { attributeToolTokens } from "./core/attribution"
+import { recordJump, jumpBack, jumpForward, EMPTY_JUMPS } from "./core/jumps"
+import type { JumpList } from "./core/jumps"
+import { togglePin, resolvePins } from "./core/pins"
+import { issueComment } from "./core/issues"
+import { createSessionNotifier } from "./notify/webhooks"
+import { graphAtTime, eventTimes, stepTime } from "./core/frames"
+import type { FeedItem } from "./core/feed"
+import { FEED_HEIGHT } from "./core/feed"
+import type { FeedProject } from "./sources/feed"
+import { watchFeed } from "./sources/feed"
+import { readProvenance } from "./sources/provenance"
+import type { Annotations } from "./core/annotations"
+import { withAnnotations } from "./core/annotations"
+import { heavyPrompts, withPromptWeights } from "./core/weight"
+import { flaggedTimings } from "./core/timing"
+import { toShellScript } from "./core/script"
+import type { Annotator } from "./plugins/annotator"
+import { startAnnotator } from "./plugins/annotator"
+import { findOverdueTools } from "./core/active"
+import { loadConfig } from "./storage/config"
+import { formatStrictFailure } from "./core/strict"
+import type { CameraMode } from "./core/camera"
+import { isCameraMode, nextCameraMode } from "./core/camera"
+import type { FocusDock } from "./core/dock"
+import { isFocusDock, nextFocusDock, clampFocusLines, clampFocusWidth, DEFAULT_FOCUS_LINES, DEFAULT_FOCUS_WIDTH } from "./core/dock"
+import { loadNotes, saveNote, sessionNoteKey, type NotesMap } from "./storage/notes"
 import { Timeline } from "./components/Timeline"
 import { DetailsPanel } from "./components/DetailsPanel"
+import { FocusPane, focusScrollLimit } from "./components/FocusPane"
+import { TranscriptPanel } from "./components/TranscriptPanel"
+import { DiagnosticsPanel } from "./components/DiagnosticsPanel"
+import { LoopBanner } from "./components/LoopBanner"
+import { BudgetBanner } from "./components/BudgetBanner"
+import { TurnHeader } from "./components/TurnHeader"
+import { TutorialBanner, TUTORIAL_HEIGHT } from "./components/TutorialBanner"
+import { advanceTutorial } from "./core/tutorial"
+import { PinTray } from "./components/PinTray"
+import { ReadingView } from "./components/ReadingView"
 import { SessionList } from "./components/SessionList"
 import { StatusBar } from "./components/StatusBar"
 import { CommandInput } from "./components/CommandInput"
+import { ListView } from "./components/ListView"
+import { GanttView } from "./components/GanttView"
+import { BucketView } from "./components/BucketView"
+import { MixPanel, MIX_HEIGHT } from "./components/MixPanel"
+import { bucketMix } from "./core/mix"
+import { FeedPanel } from "./components/FeedPanel"
+import { TestStrip } from "./components/TestStrip"
+import { findTestRuns, runAt } from "./core/testruns"
+import { KeysPanel, KEYS_HEIGHT } from "./components/KeysPanel"
+import { remapKey, cheatsheetMarkdown, cheatsheetText } from "./core/keymap"
+import type { StartupCommand } from "./core/commands"
+import { landingIndex } from "./core/commands"
+import { resolveGotoTarget } from "./core/goto"
+import { sessionLifecycle } from "./core/lifecycle"
 
-type Mode = "normal" | "input"
+type Mode = "normal" | "input" | "note" | "search" | "filter"
+export type ViewMode = "timeline" | "list" | "reading" | "gantt" | "overview"
 
 type Props = {
   initialGraph: Graph
   sessionId: string
   source: Source
   initialSessionListOpen?: boolean
+  initialViewMode?: ViewMode
+  tickRate?: number // blink interval in ms; 0 disables animation
+  initialNodeIdx?: number | null // deep-link target (--goto)
+  strict?: boolean // exit on the first unparseable line instead of skipping it
+  tutorial?: boolean // vizier tutorial: show guided steps over the demo session
+  startupCommands?: StartupCommand[] // --cmd, run in order after the first render
+  annotate?: { command: string; cwd: string } // the project's annotation hook
+  feed?: FeedProject[] // this project and the watched ones, for the activity feed
+  live?: boolean // false under --no-watch: the session picker is not polled either
 }
 
 // Get the nth node at a given level (returns global index)
@@ -58,24 +140,40 @@ function findNearestInLevel(graph: Graph, level: number, zoom: ZoomLevel, target
   return bestPos
 }
 
-// Move to the next/prev node chronologically across all levels
-// Returns { level, pos } for the target node, or null if at boundary
-function stepChronological(
-  graph: Graph, zoom: ZoomLevel, currentNodeIdx: number | null, direction: 1 | -1
-): { level: number; pos: number } | null {
-  if (currentNodeIdx === null) return null
-  const nextIdx = currentNodeIdx + direction
-  if (nextIdx < 0 || nextIdx >= graph.nodes.length) return null
-  const node = graph.nodes[nextIdx]
-  const level = getVisualBranch(node, zoom)
+// Level and position-within-level of the node at a global index
+function positionOfNode(graph: Graph, zoom: ZoomLevel, idx: number): { level: number; pos: number } {
+  const level = getVisualBranch(graph.nodes[idx], zoom)
   let pos = 0
-  for (let i = 0; i < nextIdx; i++) {
+  for (let i = 0; i < idx; i++) {
     if (getVisualBranch(graph.nodes[i], zoom) === level) pos++
   }
   return { level, pos }
 }
 
+// Move `steps` nodes chronologically across all levels, stopping at the ends
+// Returns { level, pos } for the target node, or null if already at boundary
+function stepChronological(
+  graph: Graph, zoom: ZoomLevel, currentNodeIdx: number | null, direction: 1 | -1, steps = 1
+): { level: number; pos: number } | null {
+  if (currentNodeIdx === null) return null
+  const nextIdx = Math.max(0, Math.min(graph.nodes.length - 1, currentNodeIdx + direction * steps))
+  if (nextIdx === currentNodeIdx) return null
+  return positionOfNode(graph, zoom, nextIdx)
+}
+
 const DETAILS_HEIGHT = 20
+const TRANSCRIPT_HEIGHT = 14
+const DIAGNOSTICS_HEIGHT = 10
+const TRAY_HEIGHT = 13
+const NO_PINS: string[] = []
+const FLASH_MS = 3000
+const READING_MAX_WIDTH = 100
+const DEFAULT_TICK_RATE = 500
+const SESSION_LIST_REFRESH_MS = 2000
+// Quiet sessions drift to IDLE and ENDED without any new events
+const LIFECYCLE_REFRESH_MS = 30_000
+const SESSION_CYCLE_SIZE = 9 // Tab cycles among this many recent sessions
+const GRAPH_CACHE_SIZE = 8 // parsed sessions kept for instant switching back
 
 // Find the last node's visual branch and position within that branch
 function getLatestNodePosition(graph: Graph, zoom: ZoomLevel): { level: number; pos: number } {
@@ -89,16 +187,50 @@ function getLatestNodePosition(graph: Graph, zoom: ZoomLevel): { level: number;
   return { level, pos: Math.max(0, pos - 1) }
 }
 
-export function App({ initialGraph, sessionId: initialSessionId, source, initialSessionListOpen }: Props) {
+// What the views draw: the visible nodes, with file lanes assigned when the
+// timeline is laid out by file
+function layoutGraph(raw: Graph, mask: VisibilityMask, zoom: ZoomLevel): Graph {
+  const visible = applyVisibility(raw, mask)
+  return zoom === "files" ? applyFileLanes(visible) : visible
+}
+
+// The unfocused half of a split view. Switching focus swaps it with the live
+// session state, so every key binding keeps acting on the focused pane.
+type PaneState = { sessionId: string; graph: Graph; level: number; cursor: number }
+
+export function App({ initialGraph, sessionId: initialSessionId, source, initialSessionListOpen, initialViewMode, tickRate = DEFAULT_TICK_RATE, initialNodeIdx, strict, tutorial, startupCommands, annotate, feed, live = true }: Props) {
   const { stdout } = useStdout()
   const { exit } = useApp()
   const termWidth = stdout?.columns ?? 120
   const termHeight = stdout?.rows ?? 40
 
-  const [graph, setGraph] = useState<Graph>(initialGraph)
+  // rawGraph is what the source delivered; graph is the visible subset that
+  // every view and motion works on
+  const [rawGraph, setGraph] = useState<Graph>(initialGraph)
+  const [visibility, setVisibility] = useState<VisibilityMask>(SHOW_ALL)
+  const visibilityRef = useRef(visibility)
+  visibilityRef.current = visibility
+  const [zoom, setZoom] = useState<ZoomLevel>("details")
+  const zoomRef = useRef(zoom)
+  zoomRef.current = zoom
+  const [annotations, setAnnotations] = useState<Annotations>({})
+  // B scrubber: while set, every view shows the session as it stood at this moment
+  const [scrubTime, setScrubTime] = useState<number | null>(null)
+  const scrubRef = useRef(scrubTime)
+  scrubRef.current = scrubTime
+  const pastGraph = useMemo(() => scrubTime === null ? rawGraph : graphAtTime(rawGraph, scrubTime), [rawGraph, scrubTime])
+  const heavy = useMemo(() => heavyPrompts(pastGraph.nodes), [pastGraph])
+  const graph = useMemo(
+    () => withPromptWeights(withAnnotations(layoutGraph(pastGraph, visibility, zoom), annotations), heavy),
+    [pastGraph, visibility, zoom, annotations, heavy],
+  )
   const [sessionId, setSessionId] = useState(initialSessionId)
-  const [currentLevel, setCurrentLevel] = useState(0)
+  const initialPosition = initialNodeIdx != null && initialGraph.nodes[initialNodeIdx]
+    ? positionOfNode(initialGraph, "details", initialNodeIdx)
+    : null
+  const [currentLevel, setCurrentLevel] = useState(() => initialPosition?.level ?? 0)
   const [cursorInLevel, setCursorInLevel] = useState(() => {
+    if (initialPosition) return initialPosition.pos
     // Start on last user message
     let count = 0
     let lastUserPos = 0
@@ -110,12 +242,40 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
     }
     return lastUserPos
   })
-  const [zoom, setZoom] = useState<ZoomLevel>("details")
+  // The watcher callback outlives renders; it reads the cursor through this
+  const cursorRef = useRef({ level: currentLevel, pos: cursorInLevel })
+  cursorRef.current = { level: currentLevel, pos: cursorInLevel }
   const [cellMode, setCellMode] = useState<CellMode>("symbol")
+  const [viewMode, setViewMode] = useState<ViewMode>(initialViewMode ?? "timeline")
+  const [readingScroll, setReadingScroll] = useState(0)
+  const [readingReturnMode, setReadingReturnMode] = useState<ViewMode>("timeline")
+  const [highlightRelated, setHighlightRelated] = useState(false)
+  const [heatOn, setHeatOn] = useState(false)
+  const [camera, setCamera] = useState<CameraMode>(() => {
+    const configured = loadConfig().camera
+    return isCameraMode(configured) ? configured : "center"
+  })
+  const [focusDock, setFocusDock] = useState<FocusDock>(() => {
+    const configured = loadConfig().focusDock
+    return isFocusDock(configured) ? configured : "bottom"
+  })
+  const [focusLines, setFocusLines] = useState(DEFAULT_FOCUS_LINES)
+  const [focusWidth, setFocusWidth] = useState(DEFAULT_FOCUS_WIDTH)
+  // Set while the focus pane has the keyboard: j/k scroll its content
+  const [focusScroll, setFocusScroll] = useState<number | null>(null)
   const [blinkState, setBlinkState] = useState(false)
 
   const [timelineOpen, setTimelineOpen] = useState(true)
   const [detailsOpen, setDetailsOpen] = useState(false)
+  const [transcriptOpen, setTranscriptOpen] = useState(false)
+  const [diagnosticsOpen, setDiagnosticsOpen] = useState(false)
+  const [trayOpen, setTrayOpen] = useState(false)
+  const [mixOpen, setMixOpen] = useState(false)
+  const [testsOpen, setTestsOpen] = useState(false)
+  const [keysOpen, setKeysOpen] = useState(false)
+  const [pins, setPins] = useState<Record<string, string[]>>({}) // node ids by session
+  const [jumps, setJumps] = useState<Record<string, JumpList>>({}) // jump list by session
+  const [tutorialStep, setTutorialStep] = useState<number | null>(tutorial ? 0 : null)
   const [sessionListOpen, setSessionListOpen] = useState(
     initialSessionListOpen ?? initialGraph.nodes.length === 0
   )
@@ -123,18 +283,59 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
   const [selectedSessionId, setSelectedSessionId] = useState<string | null>(null)
   const [sessionListSessions, setSessionListSessions] = useState<SessionInfo[]>([])
   const [sessions, setSessions] = useState<SessionInfo[]>([])
+  const [sessionSort, setSessionSort] = useState<SessionSort>("activity")
+  const [sessionQuery, setSessionQuery] = useState("")
+  const [notes, setNotes] = useState<NotesMap>(() => loadNotes())
 
   const [detailsScroll, setDetailsScroll] = useState(0)
   const [follow, setFollow] = useState(false)
   const followRef = useRef(false)
+  // Transcript tailing: with the transcript open and the cursor on the newest
+  // node, live updates keep the cursor (and transcript) at the end
+  const transcriptTailRef = useRef(false)
+  // Paused: watcher updates are buffered (latest graph wins) until resumed
+  const [paused, setPaused] = useState(false)
+  const pausedRef = useRef(false)
+  const bufferedGraphRef = useRef<Graph | null>(null)
+  const pausedNodeCountRef = useRef(0)
+  const [bufferedEvents, setBufferedEvents] = useState(0)
   const [mode, setMode] = useState<Mode>("normal")
+  const [countPrefix, setCountPrefix] = useState("")
+  const [pendingBracket, setPendingBracket] = useState<"[" | "]" | null>(null)
+  const [pendingCrumb, setPendingCrumb] = useState(false)
+  const [pendingBlock, setPendingBlock] = useState<"copy" | "save" | null>(null)
+  const [otherPane, setOtherPane] = useState<PaneState | null>(null)
+  const [focusRight, setFocusRight] = useState(false)
+  const [flash, setFlash] = useState<string | null>(null)
+  const [watchNotice, setWatchNotice] = useState<string | null>(null) // e.g. fell back to polling; stays for the run
+
+  // Only commit a new session list when it actually changed
+  const refreshSessions = useCallback(() => {
+    source.listSessions().then(next => setSessions(prev => refreshedSessions(prev, next)))
+  }, [source])
 
   // Load sessions on mount
   useEffect(() => {
-    source.listSessions().then(setSessions)
-  }, [source])
+    refreshSessions()
+  }, [refreshSessions])
+
+  const noteFor = useCallback((s: SessionInfo) => notes[sessionNoteKey(s)]?.note, [notes])
+  const activeSessions = sessionListOpen
+    ? filterSessions(sessionListSessions, sessionQuery, noteFor)
+    : sessions
 
-  const activeSessions = sessionListOpen ? sessionListSessions : sessions
+  // Refreshed sessions (e.g. changed waiting states) flow into the open picker;
+  // the cursor follows the selected id if the order shifts
+  useEffect(() => {
+    if (sessionListOpen) setSessionListSessions(sortSessions(sessions, sessionSort))
+  }, [sessions, sessionSort])
+
+  // Poll while the picker is open so new sessions and waiting states show up live
+  useEffect(() => {
+    if (!sessionListOpen || !live) return
+    const interval = setInterval(refreshSessions, SESSION_LIST_REFRESH_MS)
+    return () => clearInterval(interval)
+  }, [sessionListOpen, refreshSessions, live])
 
   // Keep cursor aligned to selected session when sessions list reorders
   useEffect(() => {
@@ -153,43 +354,221 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
     return last.nodeType.kind === "tool_call" && last.nodeType.output === null
   }, [graph])
 
+  // The blink timer is the fast periodic redraw — idle sessions otherwise render
+  // on input, watcher updates and the slow lifecycle clock below
   useEffect(() => {
-    if (!hasActiveNodes) {
+    if (!hasActiveNodes || tickRate <= 0) {
       setBlinkState(false)
       return
     }
-    const interval = setInterval(() => setBlinkState(b => !b), 500)
+    const interval = setInterval(() => setBlinkState(b => !b), tickRate)
     return () => clearInterval(interval)
-  }, [hasActiveNodes])
+  }, [hasActiveNodes, tickRate])
+
+  const [lifecycleTick, setLifecycleTick] = useState(0)
+  useEffect(() => {
+    if (!live) return
+    const interval = setInterval(() => setLifecycleTick(t => t + 1), LIFECYCLE_REFRESH_MS)
+    return () => clearInterval(interval)
+  }, [live])
+
+  // --strict: a graph with skipped lines ends the session with a report
+  const failsStrict = (g: Graph): boolean => {
+    if (!strict || !g.skipped) return false
+    exit(new Error(formatStrictFailure(g.skipped)))
+    return true
+  }
 
   // File watcher via source
   useEffect(() => {
+    const hooks = loadConfig().webhooks ?? []
+    const notifier = hooks.length > 0 ? createSessionNotifier(sessionId, hooks, rawGraph) : null
     const cleanup = source.watch(sessionId, (newGraph) => {
-      setGraph(prev => {
-        if (followRef.current) {
-          const latest = getLatestNodePosition(newGraph, zoom)
+      if (failsStrict(newGraph)) return
+      notifier?.update(newGraph)
+      if (pausedRef.current) {
+        bufferedGraphRef.current = newGraph
+        setBufferedEvents(Math.max(0, newGraph.nodes.length - pausedNodeCountRef.current))
+        refreshSessions()
+        return
+      }
+      setGraph(prevRaw => {
+        if (scrubRef.current !== null) return newGraph // the past doesn't change
+        const zoom = zoomRef.current
+        const prev = layoutGraph(prevRaw, visibilityRef.current, zoom)
+        const next = layoutGraph(newGraph, visibilityRef.current, zoom)
+        if (followRef.current || transcriptTailRef.current) {
+          const latest = getLatestNodePosition(next, zoom)
           setCurrentLevel(latest.level)
           setCursorInLevel(latest.pos)
         } else {
-          const oldCount = prev.nodes.filter(n => getVisualBranch(n, zoom) === currentLevel).length
-          const newCount = newGraph.nodes.filter(n => getVisualBranch(n, zoom) === currentLevel).length
-          const isAtEnd = cursorInLevel >= oldCount - 2
+          const { level, pos } = cursorRef.current
+          const oldCount = prev.nodes.filter(n => getVisualBranch(n, zoom) === level).length
+          const newCount = next.nodes.filter(n => getVisualBranch(n, zoom) === level).length
+          const isAtEnd = pos >= oldCount - 2
           if (isAtEnd && newCount > oldCount) {
             setCursorInLevel(Math.max(0, newCount - 1))
           }
         }
         return newGraph
       })
-      source.listSessions().then(setSessions)
-    })
+      refreshSessions()
+    }, setWatchNotice)
+    return () => {
+      cleanup()
+      notifier?.close()
+    }
+  }, [sessionId, source, refreshSessions])
+
+  // Annotation hook: one process per session, fed every node as it appears.
+  // Starting it and feeding it share one effect, so a new session's hook
+  // never sees the previous session's graph.
+  const annotatorRef = useRef<{ sessionId: string; annotator: Annotator } | null>(null)
+  useEffect(() => {
+    if (!annotate) return
+    let current = annotatorRef.current
+    if (current?.sessionId !== sessionId) {
+      current?.annotator.close()
+      setAnnotations({})
+      const annotator = startAnnotator(annotate.command, annotate.cwd, batch => setAnnotations(prev => ({ ...prev, ...batch })), setFlash)
+      current = { sessionId, annotator }
+      annotatorRef.current = current
+    }
+    current.annotator.annotate(rawGraph.nodes)
+  }, [sessionId, rawGraph])
+  useEffect(() => () => annotatorRef.current?.annotator.close(), [])
+
+  // W: activity feed across watched projects, followed only while it's open
+  const [feedOpen, setFeedOpen] = useState(false)
+  const [feedEntries, setFeedEntries] = useState<FeedItem[]>([])
+  useEffect(() => {
+    if (!feedOpen || !feed) return
+    const watcher = watchFeed(feed, FEED_HEIGHT - 1, setFeedEntries, setWatchNotice)
+    return () => watcher.close()
+  }, [feedOpen, feed])
+
+  // Status-bar messages (e.g. export paths) clear themselves
+  useEffect(() => {
+    if (!flash) return
+    const timeout = setTimeout(() => setFlash(null), FLASH_MS)
+    return () => clearTimeout(timeout)
+  }, [flash])
+
+  // Second watcher for the split pane
+  const otherSessionId = otherPane?.sessionId
+  useEffect(() => {
+    if (!otherSessionId) return
+    const cleanup = source.watch(otherSessionId, (newGraph) => {
+      setOtherPane(prev => prev && prev.sessionId === otherSessionId ? { ...prev, graph: newGraph } : prev)
+    }, setWatchNotice)
     return cleanup
-  }, [sessionId, source])
+  }, [otherSessionId, source])
 
   // Derived values
   const nodesInLevel = graph.nodes.filter(n => getVisualBranch(n, zoom) === currentLevel).length
   const currentNodeIdx = getNthNodeInLevel(graph, currentLevel, zoom, cursorInLevel)
   const currentNode = currentNodeIdx !== null ? graph.nodes[currentNodeIdx] : null
-  const levelName = currentLevel === 0 ? "User" : currentLevel === 1 ? "Asst" : "Tools"
+  const levelName = zoom === "lanes" ? LANES[currentLevel] ?? "Tools"
+    : zoom === "files" && currentLevel > 0 ? graph.fileLanes?.[currentLevel - 1] ?? "Files"
+    : currentLevel === 0 ? "User" : currentLevel === 1 ? "Asst" : "Tools"
+  const latestPosition = getLatestNodePosition(graph, zoom)
+  const atTail = currentLevel === latestPosition.level && cursorInLevel >= latestPosition.pos
+  transcriptTailRef.current = transcriptOpen && atTail
+  const breadcrumb = useMemo(
+    () => currentNodeIdx !== null ? getBreadcrumb(graph.nodes, currentNodeIdx) : [],
+    [graph, currentNodeIdx],
+  )
+  // Re-evaluated on every blink tick so elapsed times stay current
+  const overdueTools = useMemo(
+    () => hasActiveNodes ? findOverdueTools(graph.nodes, Date.now(), loadConfig().toolThresholds) : [],
+    [graph, hasActiveNodes, blinkState],
+  )
+  const overdueNodes = useMemo(() => new Set(overdueTools.map(t => t.idx)), [overdueTools])
+  const lifecycle = useMemo(() => sessionLifecycle(rawGraph.nodes, Date.now()), [rawGraph, blinkState, lifecycleTick])
+  // The split pane's session, laid out when it or the layout changes rather
+  // than on every blink
+  const otherGraph = otherPane?.graph
+  const otherLayout = useMemo(() => otherGraph ? layoutGraph(otherGraph, visibility, zoom) : null, [otherGraph, visibility, zoom])
+  const otherLifecycle = useMemo(() => otherGraph ? sessionLifecycle(otherGraph.nodes, Date.now()) : undefined, [otherGraph, lifecycleTick])
+
+  // Loop warnings only matter while the agent is still working
+  const waitingForUser = sessions.find(s => s.id === sessionId)?.waitingForUser === true
+  const loopWarning = useMemo(
+    () => waitingForUser ? null : detectLoop(graph.nodes),
+    [graph, waitingForUser],
+  )
+
+  // Budget warnings stay up for the rest of the session once a limit is crossed
+  const budget = loadConfig().budget
+  const toolGroups = loadConfig().toolGroups
+  const budgetOverruns = useMemo(() => checkBudget(rawGraph.stats, budget), [rawGraph.stats, budget])
+  const bellRungRef = useRef(new Set<string>())
+  useEffect(() => {
+    if (!budget?.bell) return
+    for (const o of budgetOverruns) {
+      const key = `${sessionId}:${o.kind}`
+      if (bellRungRef.current.has(key)) continue
+      bellRungRef.current.add(key)
+      process.stdout.write("\x07")
+    }
+  }, [budgetOverruns, sessionId, budget])
+
+  const currentKind = currentNode?.nodeType.kind
+  const turnStats = useMemo(
+    () => detailsOpen && currentNodeIdx !== null && (currentKind === "user" || currentKind === "assistant")
+      ? computeTurnStats(graph.nodes, getTurnBounds(graph.nodes, currentNodeIdx), toolGroups)
+      : null,
+    [detailsOpen, graph, currentNodeIdx, currentKind],
+  )
+  const currentPrompt = useMemo(
+    () => currentNodeIdx !== null ? turnPrompt(graph.nodes, currentNodeIdx) : null,
+    [graph, currentNodeIdx],
+  )
+  const sessionPins = pins[sessionId] ?? NO_PINS
+  const pinnedNodes = useMemo(() => resolvePins(rawGraph.nodes, sessionPins), [rawGraph, sessionPins])
+  const sessionFeedback = useMemo(() => turnStats ? attributeToolTokens(graph.nodes, toolGroups) : null, [turnStats, graph])
+  const sessionTiming = useMemo(() => turnStats ? flaggedTimings(graph.nodes) : null, [turnStats, graph])
+  const transcript = useMemo(
+    () => transcriptOpen ? buildTranscript(graph.nodes, termWidth - 6) : [],
+    [transcriptOpen, graph, termWidth],
+  )
+  const readingWidth = Math.min(READING_MAX_WIDTH, termWidth - 6)
+  const readingHeight = Math.max(5, termHeight - 3)
+  const readingLines = useMemo(
+    () => viewMode === "reading" ? buildReadingLines(graph.nodes, readingWidth) : [],
+    [viewMode, graph, readingWidth],
+  )
+  const maxReadingScroll = (lines: { length: number }) => Math.max(0, lines.length - (readingHeight - 1))
+  const relations = useMemo(
+    () => highlightRelated ? computeRelations(graph.nodes, currentNodeIdx) : null,
+    [highlightRelated, graph, currentNodeIdx],
+  )
+  const heat = useMemo(() => heatOn ? tokenHeat(graph.nodes) : null, [heatOn, graph])
+  // blinkState ticks while anything runs, so running bars keep growing
+  const ganttBars = useMemo(() => viewMode === "gantt" ? buildGanttBars(graph.nodes, Date.now()) : [], [viewMode, graph, blinkState])
+  const ganttCursor = currentNodeIdx !== null ? barForNode(ganttBars, currentNodeIdx) : -1
+  const bucketColumns = Math.max(10, termWidth - 13) // border, padding, row label
+  const buckets = useMemo(
+    () => viewMode === "overview" ? bucketNodes(graph.nodes, bucketColumns) : [],
+    [viewMode, graph, bucketColumns],
+  )
+  // Zoomed out while the session grows too long for it: the overview takes over
+  useEffect(() => {
+    if (zoom === "conversations" && viewMode === "timeline" && needsBuckets(graph.nodes.length, bucketColumns)) setViewMode("overview")
+  }, [zoom, viewMode, graph, bucketColumns])
+  const bucketCursor = currentNode ? bucketOf(buckets, currentNode.timestamp) : -1
+
+  // M: node mix per time bucket, for any session length; short sessions get
+  // fewer, wider buckets instead of a mostly empty chart
+  const showMix = mixOpen && timelineOpen && viewMode === "timeline"
+  const mixColumns = Math.min(bucketColumns, Math.max(10, Math.ceil(graph.nodes.length / 2)))
+  const mixBuckets = useMemo(() => showMix ? bucketNodes(graph.nodes, mixColumns) : [], [showMix, graph, mixColumns])
+  const mixes = useMemo(() => bucketMix(graph.nodes, mixBuckets), [graph, mixBuckets])
+  const mixCursor = currentNode ? bucketOf(mixBuckets, currentNode.timestamp) : -1
+  const showTests = testsOpen && timelineOpen && viewMode !== "reading"
+  const showKeys = keysOpen && viewMode !== "reading"
+  const testRuns = useMemo(() => showTests ? findTestRuns(graph.nodes) : [], [showTests, graph])
+  const testCursor = currentNodeIdx !== null ? runAt(testRuns, currentNodeIdx) : -1
 
   // Reset detail scroll when selected node changes — only if scrolled and details open
   const prevNodeRef = useRef<string | null>(null)
@@ -197,26 +576,345 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
   if (currentNodeId !== prevNodeRef.current) {
     prevNodeRef.current = currentNodeId
     if (detailsOpen && detailsScroll !== 0) setDetailsScroll(0)
+    if (focusScroll !== null && focusScroll !== 0) setFocusScroll(0)
   }
 
+  // Tokens added by the latest live update; a new session starts from zero
+  const tokenTotal = totalTokens(rawGraph.stats)
+  const tokenBaseRef = useRef({ sessionId, total: tokenTotal })
+  const [tokenGrowth, setTokenGrowth] = useState(0)
+  if (tokenBaseRef.current.total !== tokenTotal || tokenBaseRef.current.sessionId !== sessionId) {
+    const grown = tokenBaseRef.current.sessionId === sessionId ? tokenTotal - tokenBaseRef.current.total : 0
+    tokenBaseRef.current = { sessionId, total: tokenTotal }
+    setTokenGrowth(Math.max(0, grown))
+  }
+
+  // What switchSession needs from the session being left; kept in a ref since
+  // the callback outlives the render that created it
+  const leavingRef = useRef({ sessionId, level: currentLevel, cursor: cursorInLevel, graph: rawGraph, sessions })
+  leavingRef.current = { sessionId, level: currentLevel, cursor: cursorInLevel, graph: bufferedGraphRef.current ?? rawGraph, sessions }
+  // Cursor of every session visited this run, restored when switching back
+  const savedPositionsRef = useRef(new Map<string, { level: number; cursor: number }>())
+  // Parsed graphs of recently left sessions, valid while their stamp matches
+  const graphCacheRef = useRef(createLru<string, { graph: Graph; stamp: string | null }>(GRAPH_CACHE_SIZE))
+  // Set while the current graph came from the cache, until the session list confirms it
+  const fromCacheRef = useRef<{ sessionId: string; stamp: string } | null>(null)
+
   // Switch session helper
   const switchSession = useCallback(async (newSessionId: string) => {
-    const newGraph = await source.readGraph(newSessionId)
+    const leaving = leavingRef.current
+    savedPositionsRef.current.set(leaving.sessionId, { level: leaving.level, cursor: leaving.cursor })
+    graphCacheRef.current.set(leaving.sessionId, {
+      graph: leaving.graph,
+      stamp: sessionStamp(leaving.sessions.find(s => s.id === leaving.sessionId)),
+    })
+    const cached = graphCacheRef.current.get(newSessionId)
+    const stamp = sessionStamp(leaving.sessions.find(s => s.id === newSessionId))
+    const hitStamp = stamp !== null && cached?.stamp === stamp ? stamp : null
+    const newGraph = hitStamp !== null && cached ? cached.graph : await source.readGraph(newSessionId)
+    if (failsStrict(newGraph)) return
+    fromCacheRef.current = hitStamp !== null ? { sessionId: newSessionId, stamp: hitStamp } : null
+    const saved = savedPositionsRef.current.get(newSessionId)
+    // A buffered update belongs to the old session
+    bufferedGraphRef.current = null
+    pausedNodeCountRef.current = newGraph.nodes.length
+    setBufferedEvents(0)
     setGraph(newGraph)
+    setScrubTime(null)
     setSessionId(newSessionId)
-    setCurrentLevel(0)
-    setCursorInLevel(0)
+    setCurrentLevel(saved?.level ?? 0)
+    setCursorInLevel(saved?.cursor ?? 0)
     setSessionListOpen(false)
     setTimelineOpen(true)
+    refreshSessions()
+  }, [source])
+
+  // The list a cache hit was checked against may have been old: reload if the
+  // fresh one says the transcript moved on
+  useEffect(() => {
+    const fromCache = fromCacheRef.current
+    if (!fromCache || fromCache.sessionId !== sessionId) return
+    const stamp = sessionStamp(sessions.find(s => s.id === sessionId))
+    if (stamp === fromCache.stamp) return
+    fromCacheRef.current = null
+    source.readGraph(sessionId).then(g => {
+      if (leavingRef.current.sessionId === sessionId) setGraph(g)
+    })
+  }, [sessions, sessionId, source])
+
+  const openSplit = useCallback(async (otherId: string) => {
+    const otherGraph = await source.readGraph(otherId)
+    if (failsStrict(otherGraph)) return
+    setOtherPane({ sessionId: otherId, graph: otherGraph, level: 0, cursor: 0 })
+    setFocusRight(false)
   }, [source])
 
+  const swapPanes = () => {
+    if (!otherPane) return
+    setOtherPane({ sessionId, graph: bufferedGraphRef.current ?? rawGraph, level: currentLevel, cursor: cursorInLevel })
+    bufferedGraphRef.current = null
+    pausedNodeCountRef.current = otherPane.graph.nodes.length
+    setBufferedEvents(0)
+    setGraph(otherPane.graph)
+    setScrubTime(null)
+    setSessionId(otherPane.sessionId)
+    setCurrentLevel(otherPane.level)
+    setCursorInLevel(otherPane.cursor)
+    setFocusRight(prev => !prev)
+  }
+
+  const sessionLabel = (id: string) => {
+    const info = sessions.find(s => s.id === id)
+    return info?.title || info?.slug || shortSessionId(id)
+  }
+
+  // Writes an export and flashes where it went, or why it failed; the
+  // messages are catalog keys with {path} and {error}
+  const runExport = (label: string, write: () => string, failed = "export failed: {error}") => {
+    try {
+      setFlash(tr(label, { path: write() }))
+    } catch (e) {
+      setFlash(tr(failed, { error: e instanceof Error ? e.message : String(e) }))
+    }
+  }
+
+  // e/E: write the selected turn to markdown/JSON
+  // The exports hash the session files first; that runs async so a large
+  // transcript doesn't stall the key that asked for it
+  const exportTurn = async (format: "md" | "json") => {
+    if (currentNodeIdx === null) return
+    const bounds = getTurnBounds(graph.nodes, currentNodeIdx)
+    const turn = listTurns(graph.nodes).findIndex(t => t.start === bounds.start) + 1
+    const meta = { sessionId, turn, provenance: await readProvenance(source, sessionId) }
+    const content = format === "md" ? turnToMarkdown(graph.nodes, bounds, meta) : turnToJson(graph.nodes, bounds, meta)
+    runExport("exported {path}", () => writeExport(`vizier-${shortSessionId(sessionId)}-turn${turn}.${format}`, content))
+  }
+
+  const exportPins = async () => {
+    const content = pinsToMarkdown(rawGraph.nodes, pinnedNodes, { sessionId, exportedAt: Date.now(), provenance: await readProvenance(source, sessionId) })
+    runExport("exported {path}", () => writeExport(`vizier-${shortSessionId(sessionId)}-pins.md`, content))
+  }
+
+  // N: the conversation up to the cursor, ready to seed a new session from there
+  const exportFork = async () => {
+    if (!currentNode) return
+    const idx = rawGraph.nodes.findIndex(n => n.id === currentNode.id)
+    if (idx < 0) return
+    const meta = { sessionId, exportedAt: Date.now(), provenance: await readProvenance(source, sessionId) }
+    const name = `vizier-${shortSessionId(sessionId)}-fork-${currentNode.id.slice(0, 8)}`
+    runExport("exported {path} (and .json)", () => {
+      const path = writeExport(`${name}.md`, forkToMarkdown(rawGraph.nodes, idx, meta))
+      writeExport(`${name}.json`, forkToJson(rawGraph.nodes, idx, meta))
+      return path
+    })
+  }
+
+  // S: mark where a range starts; S again writes the range's shell commands as a script
+  const [scriptMark, setScriptMark] = useState<{ sessionId: string; nodeId: string } | null>(null)
+  const exportScript = async () => {
+    if (!currentNode) return
+    if (!scriptMark || scriptMark.sessionId !== sessionId) {
+      setScriptMark({ sessionId, nodeId: currentNode.id })
+      setFlash(tr("range start marked: press S again at its end"))
+      return
+    }
+    setScriptMark(null)
+    const from = rawGraph.nodes.findIndex(n => n.id === scriptMark.nodeId)
+    const to = rawGraph.nodes.findIndex(n => n.id === currentNode.id)
+    if (from < 0 || to < 0) {
+      setFlash(tr("the range start is gone"))
+      return
+    }
+    const range = rawGraph.nodes.slice(Math.min(from, to), Math.max(from, to) + 1)
+    const content = toShellScript(range, { sessionId, exportedAt: Date.now(), provenance: await readProvenance(source, sessionId) })
+    runExport("exported {path}", () => writeExport(`vizier-${shortSessionId(sessionId)}-commands.sh`, content))
+  }
+
+  // ?: the cheatsheet as the user has remapped it, for a wiki or a printout
+  const exportKeys = (format: "md" | "txt") => {
+    runExport("exported {path}", () => writeExport(`vizier-keys.${format}`, format === "md" ? cheatsheetMarkdown() : cheatsheetText()))
+  }
+
+  // I: summary for an issue comment, with the links from the session's note;
+  // copied as well as saved, since it's meant for pasting
+  const exportIssueComment = async () => {
+    const info = sessions.find(s => s.id === sessionId)
+    const content = issueComment(rawGraph.nodes, rawGraph.stats, { sessionId, note: info ? noteFor(info) : undefined, pinned: pinnedNodes, provenance: await readProvenance(source, sessionId) })
+    copyToClipboard(content)
+    runExport("copied, exported {path}", () => writeExport(`vizier-${shortSessionId(sessionId)}-issue.md`, content), "copied; export failed: {error}")
+  }
+
+  // Keep the cursor on the same node across layouts; in the file layout a
+  // node without a file lane falls back to the nearest earlier one that has one
+  const switchLayout = (next: ZoomLevel) => {
+    setZoom(next)
+    if (currentNodeIdx === null) return
+    const id = graph.nodes[currentNodeIdx].id
+    const nextGraph = layoutGraph(rawGraph, visibility, next)
+    let idx = nextGraph.nodes.findIndex(n => n.id === id)
+    while (idx > 0 && getVisualBranch(nextGraph.nodes[idx], next) < 0) idx--
+    if (idx < 0 || getVisualBranch(nextGraph.nodes[idx], next) < 0) return
+    const target = positionOfNode(nextGraph, next, idx)
+    setCurrentLevel(target.level)
+    setCursorInLevel(target.pos)
+  }
+
+  // Changing the mask re-indexes nodes; keep the cursor on the same node when
+  // it stays visible, otherwise on the nearest earlier one
+  const toggleVisibility = (next: VisibilityMask) => {
+    const currentId = currentNodeIdx !== null ? graph.nodes[currentNodeIdx].id : null
+    const nextGraph = layoutGraph(rawGraph, next, zoom)
+    setVisibility(next)
+    if (currentId === null || nextGraph.nodes.length === 0) return
+    const rawIdx = rawGraph.nodes.findIndex(n => n.id === currentId)
+    const visibleIds = new Set(nextGraph.nodes.map(n => n.id))
+    let target = -1
+    for (let i = 0; i <= rawIdx; i++) {
+      if (visibleIds.has(rawGraph.nodes[i].id)) target++
+    }
+    const position = positionOfNode(nextGraph, zoom, Math.max(0, target))
+    setCurrentLevel(position.level)
+    setCursorInLevel(position.pos)
+  }
+
+  // y/Y + N: copy or save a fenced code block from the selected reply
+  const codeBlocks = useMemo(
+    () => detailsOpen && currentNode?.nodeType.kind === "assistant" ? extractCodeBlocks(currentNode.nodeType.text) : [],
+    [detailsOpen, currentNode],
+  )
+  const takeCodeBlock = (action: "copy" | "save", n: number) => {
+    const block = codeBlocks[n - 1]
+    if (!block) return
+    if (action === "copy") {
+      copyToClipboard(block.code)
+      setFlash(tr("copied block {n}", { n }))
+      return
+    }
+    const name = `vizier-${shortSessionId(sessionId)}-block${n}.${codeBlockExtension(block.lang)}`
+    runExport("saved {path}", () => writeExport(name, block.code.endsWith("\n") ? block.code : block.code + "\n"), "save failed: {error}")
+  }
+
+  const togglePause = () => {
+    if (!pausedRef.current) {
+      pausedRef.current = true
+      pausedNodeCountRef.current = rawGraph.nodes.length
+      setPaused(true)
+      return
+    }
+    pausedRef.current = false
+    setPaused(false)
+    setBufferedEvents(0)
+    const buffered = bufferedGraphRef.current
+    bufferedGraphRef.current = null
+    if (!buffered) return
+    setGraph(buffered)
+    if (followRef.current) {
+      const latest = getLatestNodePosition(layoutGraph(buffered, visibility, zoom), zoom)
+      setCurrentLevel(latest.level)
+      setCursorInLevel(latest.pos)
+    }
+  }
+
   const canSendMessage = !!source.sendMessage
 
-  useInput((input, key) => {
-    if (mode === "input") {
+  // Move the cursor onto a node by global index (manual navigation — disables follow)
+  const moveToNode = (idx: number) => {
+    setFollow(false); followRef.current = false
+    const target = positionOfNode(graph, zoom, idx)
+    setCurrentLevel(target.level)
+    setCursorInLevel(target.pos)
+  }
+
+  // Show the session as of `time`, the cursor on nodeId or else the newest node by then
+  const scrubTo = (time: number, nodeId?: string) => {
+    setScrubTime(time)
+    const past = layoutGraph(graphAtTime(rawGraph, time), visibility, zoom)
+    const idx = nodeId ? past.nodes.findIndex(n => n.id === nodeId) : -1
+    const target = idx >= 0 ? positionOfNode(past, zoom, idx) : getLatestNodePosition(past, zoom)
+    setCurrentLevel(target.level)
+    setCursorInLevel(target.pos)
+  }
+
+  // Long jumps leave an entry in the jump list for Ctrl-o
+  const markJump = () => {
+    if (currentNode) setJumps(prev => ({ ...prev, [sessionId]: recordJump(prev[sessionId] ?? EMPTY_JUMPS, currentNode.id) }))
+  }
+  const jumpToNode = (idx: number) => {
+    if (idx !== currentNodeIdx) markJump()
+    moveToNode(idx)
+  }
+
+  // Ctrl-o / Ctrl-n: step through the jump list, skipping nodes no longer visible
+  const stepJumps = (dir: -1 | 1) => {
+    if (!currentNode) return
+    let list = jumps[sessionId] ?? EMPTY_JUMPS
+    for (;;) {
+      const step = dir < 0 ? jumpBack(list, currentNode.id) : jumpForward(list)
+      if (!step) { setFlash(tr(dir < 0 ? "no older jumps" : "no newer jumps")); break }
+      list = step.list
+      const idx = graph.nodes.findIndex(n => n.id === step.target)
+      if (idx >= 0 && idx !== currentNodeIdx) { moveToNode(idx); break }
+    }
+    setJumps(prev => ({ ...prev, [sessionId]: list }))
+  }
+
+  // --cmd: applied in order to the initial session, then the cursor lands once
+  useEffect(() => {
+    if (!startupCommands || startupCommands.length === 0) return
+    let mask = visibility
+    let layout = zoom
+    let target = currentNode ? rawGraph.nodes.findIndex(n => n.id === currentNode.id) : -1
+    let follows = false
+    for (const cmd of startupCommands) {
+      switch (cmd.kind) {
+        case "filter": mask = { ...mask, filter: cmd.filter ?? undefined }; break
+        case "layout": layout = cmd.zoom; break
+        case "view": setViewMode(cmd.view); break
+        case "follow": follows = true; break
+        case "open": {
+          const open = { details: setDetailsOpen, transcript: setTranscriptOpen, mix: setMixOpen, tests: setTestsOpen, tray: setTrayOpen, keys: setKeysOpen }
+          open[cmd.panel](true)
+          break
+        }
+        case "goto": {
+          const idx = resolveGotoTarget(rawGraph.nodes, cmd.target)
+          if (idx === null) setFlash(tr(":goto {spec}: not found", { spec: cmd.spec }))
+          else { target = idx; follows = false }
+          break
+        }
+      }
+    }
+    setVisibility(mask)
+    setZoom(layout)
+    const shown = layoutGraph(rawGraph, mask, layout)
+    if (follows) {
+      setFollow(true); followRef.current = true
+      const latest = getLatestNodePosition(shown, layout)
+      setCurrentLevel(latest.level)
+      setCursorInLevel(latest.pos)
+      return
+    }
+    let idx = target >= 0 ? landingIndex(rawGraph.nodes, shown.nodes, target) : null
+    while (idx !== null && idx > 0 && getVisualBranch(shown.nodes[idx], layout) < 0) idx--
+    if (idx === null || getVisualBranch(shown.nodes[idx], layout) < 0) return
+    const position = positionOfNode(shown, layout, idx)
+    setCurrentLevel(position.level)
+    setCursorInLevel(position.pos)
+  }, [])
+
+  useInput((rawInput, key) => {
+    if (mode !== "normal") {
       if (key.escape) setMode("normal")
       return
     }
+    // Remaps from config.json: everything below sees built-in keys
+    const input = key.ctrl ? rawInput : remapKey(rawInput)
+
+    // Tutorial steps watch keys without taking them
+    if (tutorialStep !== null) {
+      const token = key.leftArrow ? "h" : key.rightArrow ? "l" : key.downArrow ? "j" : key.upArrow ? "k" : input
+      setTutorialStep(advanceTutorial(tutorialStep, token))
+    }
 
     // Normal mode
     if (input === "q") {
@@ -227,7 +925,7 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
     if (input === "s") {
       setSessionListOpen(prev => !prev)
       if (!sessionListOpen) {
-        const list = sessions
+        const list = sortSessions(sessions, sessionSort)
         setSessionListSessions(list)
         const idx = list.findIndex(s => s.id === sessionId)
         setSessionListCursor(idx >= 0 ? idx : 0)
@@ -239,9 +937,148 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
       return
     }
 
+    // Ctrl+W moves focus to the other split pane
+    if (key.ctrl && input === "w") { swapPanes(); return }
+    if (key.ctrl && input === "o") { stepJumps(-1); return }
+    if (key.ctrl && input === "n") { stepJumps(1); return }
+
     if (input === "t") { setTimelineOpen(prev => !prev); return }
     if (input === "d") { setDetailsOpen(prev => !prev); return }
+    if (input === "T") { setTranscriptOpen(prev => !prev); return }
+    if (input === "!" && rawGraph.skipped) { setDiagnosticsOpen(prev => !prev); return }
     if (input === "w") { setCellMode(prev => prev === "symbol" ? "preview" : "symbol"); return }
+    if (input === "v" && viewMode !== "reading") { setViewMode(prev => prev === "timeline" ? "list" : "timeline"); return }
+    if (input === "V" && viewMode !== "reading") { setViewMode(prev => prev === "gantt" ? "timeline" : "gantt"); return }
+    // z zooms out to prompts and replies; a session too long for that to help
+    // gets the bucketed overview instead. z again (or Enter) zooms back in.
+    if (input === "z" && viewMode === "timeline") {
+      if (zoom === "conversations") switchLayout("details")
+      else if (needsBuckets(graph.nodes.length, bucketColumns)) setViewMode("overview")
+      else switchLayout("conversations")
+      return
+    }
+    if (viewMode === "overview") {
+      if (input === "z" || key.return) {
+        setViewMode("timeline")
+        if (zoom === "conversations") switchLayout("details")
+        return
+      }
+      if (input === "h" || input === "l" || key.leftArrow || key.rightArrow) {
+        const next = stepBucket(buckets, bucketCursor, input === "l" || key.rightArrow ? 1 : -1)
+        if (next !== null) jumpToNode(buckets[next].first)
+        return
+      }
+    }
+    if (viewMode === "gantt" && (input === "j" || input === "k" || key.downArrow || key.upArrow)) {
+      const next = ganttBars[ganttCursor + (input === "j" || key.downArrow ? 1 : -1)]
+      if (next) jumpToNode(next.nodeIdx)
+      return
+    }
+
+    // Reading mode: R enters at the selected message and leaves at the one on top
+    if (input === "R") {
+      if (viewMode === "reading") {
+        const top = readingLines[Math.min(readingScroll, readingLines.length - 1)]
+        setViewMode(readingReturnMode)
+        if (top) jumpToNode(top.nodeIdx)
+      } else {
+        const lines = buildReadingLines(graph.nodes, readingWidth)
+        setReadingReturnMode(viewMode)
+        setReadingScroll(currentNodeIdx !== null ? Math.min(lineOfNode(lines, currentNodeIdx), maxReadingScroll(lines)) : 0)
+        setViewMode("reading")
+      }
+      return
+    }
+    if (viewMode === "reading") {
+      const page = Math.max(1, Math.floor(readingHeight / 2))
+      const maxScroll = maxReadingScroll(readingLines)
+      const scrollBy = (delta: number) => setReadingScroll(prev => Math.max(0, Math.min(maxScroll, prev + delta)))
+      if (input === "j" || key.downArrow) { scrollBy(1); return }
+      if (input === "k" || key.upArrow) { scrollBy(-1); return }
+      if ((key.ctrl && input === "d") || key.pageDown) { scrollBy(page); return }
+      if ((key.ctrl && input === "u") || key.pageUp) { scrollBy(-page); return }
+      if (input === "g") { setReadingScroll(0); return }
+      if (input === "G") { setReadingScroll(maxScroll); return }
+    }
+    if (input === "r") { setHighlightRelated(prev => !prev); return }
+    if (input === "H") { setHeatOn(prev => !prev); return }
+    if (input === "L" || input === "F") {
+      const layout: ZoomLevel = input === "L" ? "lanes" : "files"
+      switchLayout(zoom === layout ? "details" : layout)
+      return
+    }
+    if (input === "m") { setCamera(prev => nextCameraMode(prev)); return }
+    if (input === "D") { setFocusDock(prev => nextFocusDock(prev)); return }
+    // +/-: grow or shrink the focus pane along its docked edge
+    if ((input === "+" || input === "=" || input === "-") && focusDock !== "off") {
+      const delta = input === "-" ? -1 : 1
+      if (focusDock === "bottom") setFocusLines(prev => clampFocusLines(prev + delta, termHeight))
+      else setFocusWidth(prev => clampFocusWidth(prev + delta * 4, termWidth))
+      return
+    }
+    // Enter gives the focus pane the keyboard so long outputs can be read in place
+    if (focusScroll !== null && focusDock !== "off" && timelineOpen && viewMode === "timeline") {
+      if (key.escape || key.return) { setFocusScroll(null); return }
+      if (input === "j" || key.downArrow) { setFocusScroll(prev => Math.min(maxFocusScroll, (prev ?? 0) + 1)); return }
+      if (input === "k" || key.upArrow) { setFocusScroll(prev => Math.max(0, Math.min(maxFocusScroll, prev ?? 0) - 1)); return }
+    }
+    if (input === " ") { togglePause(); return }
+    if (input === "P") { toggleVisibility({ ...visibility, hideProgress: !visibility.hideProgress }); return }
+    if (input === "C") { toggleVisibility({ ...visibility, hideOkResults: !visibility.hideOkResults }); return }
+    if (input === "/" && !sessionListOpen) { setMode("filter"); return }
+    if (input === "*" && !sessionListOpen) { quickFilter(); return }
+    if (input === "?") { setKeysOpen(prev => !prev); return }
+    if ((input === "e" || input === "E") && showKeys) { exportKeys(input === "e" ? "md" : "txt"); return }
+    if (input === "e" && trayOpen && pinnedNodes.length > 0) { void exportPins(); return }
+    if (input === "e") { void exportTurn("md"); return }
+    if (input === "E") { void exportTurn("json"); return }
+    if (input === "I") { void exportIssueComment(); return }
+    if (input === "S") { void exportScript(); return }
+    if (input === "N") { void exportFork(); return }
+    if (input === "a" && currentNode) {
+      const next = togglePin(sessionPins, currentNode.id)
+      setPins(prev => ({ ...prev, [sessionId]: next }))
+      if (next.length > sessionPins.length) setTrayOpen(true)
+      return
+    }
+    if (input === "A") { setTrayOpen(prev => !prev); return }
+    if (input === "M") { setMixOpen(prev => !prev); return }
+    if (input === "U") { setTestsOpen(prev => !prev); return }
+    if (input === "W" && feed) { setFeedOpen(prev => !prev); return }
+    // X: a --summarize summary → the full session, keeping the cursor's node
+    if (input === "X") {
+      if (!source.expandSession || !rawGraph.nodes.some(n => n.summarized)) {
+        setFlash(tr("the session is already in full"))
+        return
+      }
+      source.expandSession(sessionId)
+      const keepId = currentNode?.id
+      const expanding = sessionId
+      void source.readGraph(sessionId).then(full => {
+        if (leavingRef.current.sessionId !== expanding) return
+        setGraph(full)
+        const shown = layoutGraph(full, visibility, zoom)
+        const idx = shown.nodes.findIndex(n => n.id === keepId)
+        if (idx >= 0) {
+          const target = positionOfNode(shown, zoom, idx)
+          setCurrentLevel(target.level)
+          setCursorInLevel(target.pos)
+        }
+        setFlash(tr("loaded {count} nodes", { count: full.nodes.length }))
+      })
+      return
+    }
+    // B: travel back to the cursor's moment; B again returns to the present
+    if (input === "B") {
+      if (scrubTime !== null) {
+        setScrubTime(null)
+        setFlash(tr("back to the present"))
+      } else if (currentNode) {
+        setFollow(false); followRef.current = false
+        scrubTo(currentNode.timestamp, currentNode.id)
+      }
+      return
+    }
 
     if (input === "f") {
       setFollow(prev => {
@@ -279,6 +1116,22 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
 
     // Session list navigation
     if (sessionListOpen) {
+      if (input === "o") {
+        setSessionSort(prev => nextSessionSort(prev))
+        return
+      }
+      if (input === "n" && activeSessions[sessionListCursor]) {
+        setMode("note")
+        return
+      }
+      if (input === "/") {
+        setMode("search")
+        return
+      }
+      if (key.escape && sessionQuery) {
+        setSessionQuery("")
+        return
+      }
       if (input === "j" || key.downArrow) {
         const next = Math.min(sessionListCursor + 1, activeSessions.length - 1)
         setSessionListCursor(next)
@@ -291,10 +1144,12 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
         setSelectedSessionId(activeSessions[next]?.id ?? null)
         return
       }
-      if (key.return) {
+      // | opens the selected session beside the current one
+      if (key.return || input === "|") {
         const selected = activeSessions[sessionListCursor]
         if (selected && selected.id !== sessionId) {
-          switchSession(selected.id)
+          if (input === "|") openSplit(selected.id)
+          else switchSession(selected.id)
         }
         setSessionListOpen(false)
         setSessionListSessions([])
@@ -304,24 +1159,121 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
       return
     }
 
+    // Tab / Shift+Tab: next older / newer of the most recent sessions
+    if (key.tab) {
+      const next = cycleRecentSession(sessions, sessionId, key.shift ? -1 : 1, SESSION_CYCLE_SIZE)
+      if (!next) return
+      switchSession(next.id)
+      setFlash(tr("session {n}/{total}: {label}", { n: next.index + 1, total: next.total, label: sessionLabel(next.id) }))
+      return
+    }
+
+    if (key.return && timelineOpen && viewMode === "timeline" && currentNode) {
+      if (focusDock === "off") setFocusDock("bottom")
+      setFocusScroll(0)
+      return
+    }
+
+    if (input === "|" && otherPane) {
+      setOtherPane(null)
+      setFocusRight(false)
+      return
+    }
+
+    // b + digit: jump to that breadcrumb element
+    if (pendingCrumb) {
+      setPendingCrumb(false)
+      const target = breadcrumb[Number(input) - 1]
+      if (/^[1-9]$/.test(input) && target !== undefined) jumpToNode(target)
+      return
+    }
+    if (input === "b" && detailsOpen && breadcrumb.length > 1) {
+      setPendingCrumb(true)
+      return
+    }
+    if (pendingBlock) {
+      setPendingBlock(null)
+      if (/^[1-9]$/.test(input)) takeCodeBlock(pendingBlock, Number(input))
+      return
+    }
+    if ((input === "y" || input === "Y") && codeBlocks.length > 0) {
+      const action = input === "y" ? "copy" : "save"
+      // A single block needs no number
+      if (codeBlocks.length === 1) takeCodeBlock(action, 1)
+      else setPendingBlock(action)
+      return
+    }
+
+    // Vim-style count prefix (10l, 5k): digits accumulate until the next key consumes them
+    if (/^[0-9]$/.test(input) && (input !== "0" || countPrefix)) {
+      setCountPrefix(prev => (prev + input).slice(0, 5))
+      return
+    }
+    const hasCount = countPrefix !== ""
+    const count = hasCount ? Math.max(1, Number(countPrefix)) : 1
+    if (hasCount) setCountPrefix("")
+
+    // Bracket motions: ]t / [t jump to the next/previous node with the same tool (or node type)
+    if (pendingBracket) {
+      setPendingBracket(null)
+      if (input === "t" && currentNodeIdx !== null) {
+        const target = findSameKind(graph.nodes, currentNodeIdx, pendingBracket === "]" ? 1 : -1, count, visibility)
+        if (target !== null) jumpToNode(target)
+      }
+      return
+    }
+    // ,/. step the scrubber one event back/forward
+    if ((input === "," || input === ".") && scrubTime !== null) {
+      scrubTo(stepTime(eventTimes(rawGraph), scrubTime, input === "." ? count : -count))
+      return
+    }
+    // p/c: follow the actual parent/child links in the graph
+    if (input === "p" && currentNodeIdx !== null) {
+      const target = findParent(graph.nodes, currentNodeIdx)
+      if (target !== null) jumpToNode(target)
+      return
+    }
+    if (input === "c" && currentNodeIdx !== null) {
+      const target = findFirstChild(graph.nodes, currentNodeIdx)
+      if (target !== null) jumpToNode(target)
+      return
+    }
+    // O: between a subagent's last reply and the Task result its parent received
+    if (input === "O" && currentNodeIdx !== null) {
+      const target = findAgentLink(graph.nodes, currentNodeIdx)
+      if (target === null) {
+        setFlash(tr("not in a subagent or on a Task call"))
+      } else {
+        jumpToNode(target)
+        setFlash(tr(graph.nodes[target].nodeType.kind === "tool_call" ? "result the parent received" : "agent's conclusion"))
+      }
+      return
+    }
+
+    if (input === "[" || input === "]") {
+      setPendingBracket(input)
+      if (hasCount) setCountPrefix(countPrefix) // keep the count for the motion key
+      return
+    }
+
     // Timeline navigation — any manual nav disables follow
 
     // Shift+arrow: stay within current level
     if (key.shift && key.leftArrow) {
       setFollow(false); followRef.current = false
-      setCursorInLevel(prev => Math.max(prev - 1, 0))
+      setCursorInLevel(prev => Math.max(prev - count, 0))
       return
     }
     if (key.shift && key.rightArrow) {
       setFollow(false); followRef.current = false
-      setCursorInLevel(prev => Math.min(prev + 1, nodesInLevel - 1))
+      setCursorInLevel(prev => Math.max(0, Math.min(prev + count, nodesInLevel - 1)))
       return
     }
 
     // h/l/arrows: chronological — move to next/prev node across all levels
     if (input === "h" || key.leftArrow) {
       setFollow(false); followRef.current = false
-      const target = stepChronological(graph, zoom, currentNodeIdx, -1)
+      const target = stepChronological(graph, zoom, currentNodeIdx, -1, count)
       if (target) {
         setCurrentLevel(target.level)
         setCursorInLevel(target.pos)
@@ -330,7 +1282,7 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
     }
     if (input === "l" || key.rightArrow) {
       setFollow(false); followRef.current = false
-      const target = stepChronological(graph, zoom, currentNodeIdx, 1)
+      const target = stepChronological(graph, zoom, currentNodeIdx, 1, count)
       if (target) {
         setCurrentLevel(target.level)
         setCursorInLevel(target.pos)
@@ -342,8 +1294,9 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
       const maxLevel = getMaxLevel(graph, zoom)
       if (currentLevel < maxLevel) {
         const ts = currentNode?.timestamp
-        setCurrentLevel(prev => prev + 1)
-        if (ts) setCursorInLevel(findNearestInLevel(graph, currentLevel + 1, zoom, ts))
+        const nextLevel = Math.min(maxLevel, currentLevel + count)
+        setCurrentLevel(nextLevel)
+        if (ts) setCursorInLevel(findNearestInLevel(graph, nextLevel, zoom, ts))
         else setCursorInLevel(0)
       }
       return
@@ -352,20 +1305,25 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
       setFollow(false); followRef.current = false
       if (currentLevel > 0) {
         const ts = currentNode?.timestamp
-        setCurrentLevel(prev => prev - 1)
-        if (ts) setCursorInLevel(findNearestInLevel(graph, currentLevel - 1, zoom, ts))
+        const nextLevel = Math.max(0, currentLevel - count)
+        setCurrentLevel(nextLevel)
+        if (ts) setCursorInLevel(findNearestInLevel(graph, nextLevel, zoom, ts))
         else setCursorInLevel(0)
       }
       return
     }
     if (input === "g") {
       setFollow(false); followRef.current = false
+      markJump()
       setCursorInLevel(0)
       return
     }
+    // G jumps to the end; with a count (12G) to that position in the level
     if (input === "G") {
       setFollow(false); followRef.current = false
-      setCursorInLevel(Math.max(0, nodesInLevel - 1))
+      const last = Math.max(0, nodesInLevel - 1)
+      markJump()
+      setCursorInLevel(hasCount ? Math.min(count - 1, last) : last)
       return
     }
   })
@@ -379,6 +1337,63 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
     setMode("normal")
   }, [source, sessionId])
 
+  // *: filter like the cursor's node; pressed again it moves on to the next
+  // kind of likeness, and past the last one the filter is cleared
+  const quickFilter = () => {
+    if (!currentNode) return
+    const next = nextQuickFilter(currentNode, visibility.filter?.text)
+    const parsed = next === null ? null : parseFilter(next)
+    if (parsed && "error" in parsed) {
+      setFlash(parsed.error)
+      return
+    }
+    toggleVisibility({ ...visibility, filter: parsed ?? undefined })
+    setFlash(parsed ? tr("filter: {expr}", { expr: parsed.text }) : tr("filter cleared"))
+  }
+
+  // Empty clears the filter; a bad expression keeps the old one
+  const handleFilterSubmit = (text: string) => {
+    setMode("normal")
+    if (!text.trim()) {
+      toggleVisibility({ ...visibility, filter: undefined })
+      return
+    }
+    const parsed = parseFilter(text)
+    if ("error" in parsed) {
+      setFlash(parsed.error)
+      return
+    }
+    toggleVisibility({ ...visibility, filter: parsed })
+  }
+
+  const noteTarget = sessionListOpen ? activeSessions[sessionListCursor] : undefined
+
+  const handleNoteSubmit = useCallback((text: string) => {
+    if (noteTarget) setNotes(saveNote(sessionNoteKey(noteTarget), text))
+    setMode("normal")
+  }, [noteTarget])
+
+  const handleSearchSubmit = useCallback((text: string) => {
+    setSessionQuery(text)
+    setSessionListCursor(0)
+    setSelectedSessionId(null)
+    setMode("normal")
+  }, [])
+
+  const skipped = rawGraph.skipped ?? []
+  const showDiagnostics = diagnosticsOpen && skipped.length > 0 && viewMode !== "reading"
+  const showTurnHeader = timelineOpen && viewMode !== "reading" && currentPrompt !== null
+  const showTray = trayOpen && viewMode !== "reading"
+  const showFeed = feedOpen && !!feed && viewMode !== "reading"
+  const otherPanelsHeight = (tutorialStep !== null ? TUTORIAL_HEIGHT : 0) + (showMix ? MIX_HEIGHT : 0) + (showTests ? 1 : 0) + (showFeed ? FEED_HEIGHT : 0) + (showKeys ? KEYS_HEIGHT : 0) + (showTurnHeader ? 1 : 0) + (showTray ? TRAY_HEIGHT : 0) + (detailsOpen ? DETAILS_HEIGHT : 0) + (transcriptOpen ? TRANSCRIPT_HEIGHT : 0)
+    + (showDiagnostics ? DIAGNOSTICS_HEIGHT : 0) + (loopWarning ? 3 : 0) + (budgetOverruns.length > 0 ? 3 : 0)
+  const showFocus = timelineOpen && viewMode === "timeline" && focusDock !== "off"
+  const paneScroll = showFocus && focusScroll !== null ? focusScroll : undefined
+  const focusPaneWidth = clampFocusWidth(focusWidth, termWidth)
+  const focusPaneHeight = focusDock === "right" ? Math.max(5, termHeight - 3 - otherPanelsHeight) : clampFocusLines(focusLines, termHeight) + 3
+  const maxFocusScroll = focusScrollLimit(currentNode, focusDock === "right" ? focusPaneWidth : termWidth, focusPaneHeight)
+  const timelineWidth = showFocus && focusDock === "right" ? termWidth - focusPaneWidth : termWidth
+
   // Use termHeight - 1 so Ink uses eraseLines (with output diff) instead of
   // clearTerminal (full screen flash). Ink triggers clearTerminal when
   // outputHeight >= stdout.rows, which causes visible flicker in iTerm.
@@ -387,24 +1402,133 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
       {sessionListOpen && (
         <SessionList
           sessions={activeSessions}
+          sort={sessionSort}
+          query={sessionQuery}
+          noteFor={noteFor}
           currentSessionId={sessionId}
           cursor={sessionListCursor}
         />
       )}
-      {timelineOpen && (
-        <Timeline
+      {budgetOverruns.length > 0 && <BudgetBanner overruns={budgetOverruns} />}
+      {loopWarning && <LoopBanner warning={loopWarning} canIntervene={canSendMessage} />}
+      {showTurnHeader && currentPrompt && <TurnHeader prompt={currentPrompt.text} turn={currentPrompt.turn} badges={graph.nodes[currentPrompt.index]?.badges} width={termWidth} />}
+      {viewMode === "reading" && (
+        <ReadingView lines={readingLines} scroll={readingScroll} height={readingHeight} />
+      )}
+      {timelineOpen && viewMode === "overview" && (
+        <BucketView buckets={buckets} cursor={bucketCursor} nodeCount={graph.nodes.length} />
+      )}
+      {timelineOpen && viewMode === "gantt" && (
+        <GanttView
+          bars={ganttBars}
+          cursor={ganttCursor}
+          height={Math.max(5, termHeight - 3 - otherPanelsHeight)}
+          termWidth={termWidth}
+        />
+      )}
+      {timelineOpen && viewMode === "list" && (
+        <ListView
           graph={graph}
-          currentLevel={currentLevel}
-          cursorInLevel={cursorInLevel}
           zoom={zoom}
-          cellMode={cellMode}
-          blinkState={blinkState}
+          currentNodeIdx={currentNodeIdx}
+          height={Math.max(5, termHeight - 3 - otherPanelsHeight)}
           termWidth={termWidth}
         />
       )}
-      {detailsOpen && (
+      {timelineOpen && viewMode === "timeline" && (
+        <Box flexDirection="row">
+          <Box flexDirection="column" width={timelineWidth}>
+            {!otherPane && (
+              <Timeline
+                graph={graph}
+                currentLevel={currentLevel}
+                cursorInLevel={cursorInLevel}
+                zoom={zoom}
+                cellMode={cellMode}
+                blinkState={blinkState}
+                termWidth={timelineWidth}
+                relations={relations}
+                heat={heat}
+                overdueNodes={overdueNodes}
+                now={scrubTime ?? Date.now()}
+                hidden={getHiddenLabels(visibility)}
+                filter={visibility.filter?.text}
+                camera={camera}
+                paused={paused}
+                lifecycle={live ? lifecycle : undefined}
+              />
+            )}
+            {otherPane && otherLayout && (
+              <Box flexDirection={focusRight ? "row-reverse" : "row"}>
+                <Timeline
+                  graph={graph}
+                  currentLevel={currentLevel}
+                  cursorInLevel={cursorInLevel}
+                  zoom={zoom}
+                  cellMode={cellMode}
+                  blinkState={blinkState}
+                  termWidth={Math.floor(timelineWidth / 2)}
+                  relations={relations}
+                  heat={heat}
+                  overdueNodes={overdueNodes}
+                  now={scrubTime ?? Date.now()}
+                  hidden={getHiddenLabels(visibility)}
+                  filter={visibility.filter?.text}
+                  camera={camera}
+                  title={sessionLabel(sessionId)}
+                  paused={paused}
+                  lifecycle={live ? lifecycle : undefined}
+                />
+                <Timeline
+                  graph={otherLayout}
+                  currentLevel={otherPane.level}
+                  cursorInLevel={otherPane.cursor}
+                  zoom={zoom}
+                  cellMode={cellMode}
+                  blinkState={false}
+                  termWidth={Math.floor(timelineWidth / 2)}
+                  camera={camera}
+                  title={sessionLabel(otherPane.sessionId)}
+                  focused={false}
+                  lifecycle={live ? otherLifecycle : undefined}
+                />
+              </Box>
+            )}
+          </Box>
+          {showFocus && focusDock === "right" && (
+            <FocusPane
+              node={currentNode}
+              width={focusPaneWidth}
+              height={focusPaneHeight}
+              scroll={paneScroll}
+            />
+          )}
+        </Box>
+      )}
+      {showFocus && focusDock === "bottom" && (
+        <FocusPane node={currentNode} width={termWidth} height={focusPaneHeight} scroll={paneScroll} />
+      )}
+      {showTests && <TestStrip runs={testRuns} current={testCursor} width={termWidth} />}
+      {showFeed && <FeedPanel items={feedEntries} projects={feed?.length ?? 0} width={termWidth} height={FEED_HEIGHT} />}
+      {showMix && <MixPanel buckets={mixBuckets} mixes={mixes} cursor={mixCursor} />}
+      {showTray && <PinTray nodes={pinnedNodes} width={termWidth} height={TRAY_HEIGHT} />}
+      {showKeys && <KeysPanel width={termWidth} height={KEYS_HEIGHT} />}
+      {showDiagnostics && <DiagnosticsPanel issues={skipped} height={DIAGNOSTICS_HEIGHT} />}
+      {transcriptOpen && viewMode !== "reading" && (
+        <TranscriptPanel
+          lines={transcript}
+          currentNodeIdx={currentNodeIdx}
+          tailing={transcriptOpen && atTail}
+          height={TRANSCRIPT_HEIGHT}
+        />
+      )}
+      {detailsOpen && viewMode !== "reading" && (
         <DetailsPanel
           node={currentNode}
+          breadcrumb={breadcrumb.map(i => ({ label: getNodePreview(graph.nodes[i], 24) }))}
+          turnStats={turnStats}
+          sessionFeedback={sessionFeedback}
+          sessionTiming={sessionTiming}
           levelName={levelName}
           position={cursorInLevel + 1}
           total={nodesInLevel}
@@ -418,16 +1542,55 @@ export function App({ initialGraph, sessionId: initialSessionId, source, initial
           onCancel={() => setMode("normal")}
         />
       )}
+      {mode === "note" && noteTarget && (
+        <CommandInput
+          prompt="note:"
+          hint="(issue URLs go into I exports; Enter to save, empty to clear, Esc to cancel)"
+          initialValue={noteFor(noteTarget) ?? ""}
+          allowEmpty
+          onSubmit={handleNoteSubmit}
+          onCancel={() => setMode("normal")}
+        />
+      )}
+      {mode === "search" && (
+        <CommandInput
+          prompt="/"
+          hint="(Enter to filter sessions, Esc to cancel)"
+          initialValue={sessionQuery}
+          allowEmpty
+          onSubmit={handleSearchSubmit}
+          onCancel={() => setMode("normal")}
+        />
+      )}
+      {mode === "filter" && (
+        <CommandInput
+          prompt="filter:"
+          hint="(e.g. type:tool name:Bash error:true after:14:00 agent:researcher; empty to clear)"
+          initialValue={visibility.filter?.text ?? ""}
+          allowEmpty
+          onSubmit={handleFilterSubmit}
+          onCancel={() => setMode("normal")}
+        />
+      )}
       <Box flexGrow={1} />
+      {tutorialStep !== null && <TutorialBanner step={tutorialStep} width={termWidth} />}
       <StatusBar
         levelName={levelName}
         position={cursorInLevel + 1}
         total={nodesInLevel}
         totalNodes={graph.nodes.length}
         zoom={zoom}
-        isLive={true}
+        lifecycle={lifecycle}
         follow={follow}
         stats={graph.stats}
+        pendingCount={`${countPrefix}${pendingBracket ?? ""}`}
+        message={flash ?? undefined}
+        overdue={overdueTools[0]}
+        pausedEvents={paused ? bufferedEvents : undefined}
+        skippedLines={skipped.length}
+        tokenGrowth={tokenGrowth}
+        scrubTime={scrubTime ?? undefined}
+        notice={watchNotice ?? undefined}
       />
     </Box>
   )
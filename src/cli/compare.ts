@@ -0,0 +1,17 @@
+import type { Graph, Source } from "../core/types"
+import { compareTools, formatToolComparison, toolComparisonToJson } from "../core/compare"
+import { readSessionProvenance } from "../sources/provenance"
+
+// vizier compare-tools [--last N] [--json] — sessions are listed newest first
+export async function runCompareTools(source: Source, last: number, json = false): Promise<void> {
+  const sessions = (await source.listSessions()).slice(0, last).reverse()
+  const graphs: Graph[] = []
+  for (const s of sessions) graphs.push(await source.readGraph(s.id))
+  const rows = compareTools(graphs)
+  if (!json) {
+    process.stdout.write(formatToolComparison(rows, graphs.length))
+    return
+  }
+  const sources = await readSessionProvenance(source, sessions.map(s => s.id))
+  process.stdout.write(toolComparisonToJson(rows, graphs.length, sources))
+}
@@ -0,0 +1,13 @@
+import type { Graph, Source } from "../core/types"
+import { analyzeErrors, formatErrorReport, errorReportToJson } from "../core/errors"
+import { readProvenance } from "../sources/provenance"
+
+// vizier errors [--session <id>] [--json]
+export async function runErrors(source: Source, graph: Graph, sessionId: string, json = false): Promise<void> {
+  const errors = analyzeErrors(graph.nodes)
+  if (!json) {
+    process.stdout.write(formatErrorReport(graph.nodes, errors, sessionId))
+    return
+  }
+  process.stdout.write(errorReportToJson(graph.nodes, errors, sessionId, await readProvenance(source, sessionId)))
+}
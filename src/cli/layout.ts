@@ -0,0 +1,8 @@
+import type { Graph } from "../core/types"
+import { layoutNeeds, formatLayoutNeeds, layoutNeedsToJson } from "../core/layout"
+
+// vizier layout-check [--session <id>] [--width N] [--json]
+export function runLayoutCheck(graph: Graph, sessionId: string, width: number, json = false): void {
+  const needs = layoutNeeds(graph, width)
+  process.stdout.write(json ? layoutNeedsToJson(needs, sessionId, width) : formatLayoutNeeds(needs, sessionId, graph.nodes.length, width))
+}
@@ -0,0 +1,83 @@
+import React from "react"
+import { EventEmitter } from "node:events"
+import { render, Box } from "ink"
+import type { Graph } from "../core/types"
+import type { Frame } from "../core/frames"
+import { graphAtFrame } from "../core/frames"
+import { getVisualBranch } from "../core/zoom"
+import { DEFAULT_FOCUS_LINES } from "../core/dock"
+import { Timeline } from "../components/Timeline"
+import { FocusPane } from "../components/FocusPane"
+import { StatusBar } from "../components/StatusBar"
+
+export type RenderOptions = { width: number; height: number; frame: Frame }
+
+// Collects Ink's output instead of writing to a terminal
+class FrameCapture extends EventEmitter {
+  columns: number
+  rows: number
+  output = ""
+  constructor(columns: number, rows: number) {
+    super()
+    this.columns = columns
+    this.rows = rows
+  }
+  write(chunk: string): boolean {
+    this.output = chunk
+    return true
+  }
+}
+
+const ANSI = /\x1b\[[0-9;?]*[ -/]*[@-~]/g
+
+function Snapshot({ graph, width }: { graph: Graph; width: number }) {
+  const last = graph.nodes.length - 1
+  const level = last >= 0 ? getVisualBranch(graph.nodes[last], "details") : 0
+  const cursor = Math.max(0, graph.nodes.filter(n => getVisualBranch(n, "details") === level).length - 1)
+  const now = last >= 0 ? graph.nodes[last].timestamp : 0
+  return (
+    <Box flexDirection="column" width={width}>
+      <Timeline
+        graph={graph}
+        currentLevel={level}
+        cursorInLevel={cursor}
+        zoom="details"
+        cellMode="symbol"
+        blinkState={false}
+        termWidth={width}
+        now={now}
+      />
+      <FocusPane node={graph.nodes[last] ?? null} width={width} height={DEFAULT_FOCUS_LINES + 3} now={now} />
+      <StatusBar
+        levelName={level === 0 ? "User" : level === 1 ? "Asst" : "Tools"}
+        position={cursor + 1}
+        total={cursor + 1}
+        totalNodes={graph.nodes.length}
+        zoom="details"
+        follow={false}
+        stats={graph.stats}
+      />
+    </Box>
+  )
+}
+
+// Plain-text frame: no raw mode, no alternate screen, no colors; the clock is
+// the frame's newest event so the output only depends on the transcript
+export function renderSnapshot(graph: Graph, options: RenderOptions): string {
+  const capture = new FrameCapture(options.width, options.height)
+  const instance = render(<Snapshot graph={graphAtFrame(graph, options.frame)} width={options.width} />, {
+    stdout: capture as unknown as NodeJS.WriteStream,
+    debug: true,
+    patchConsole: false,
+    exitOnCtrlC: false,
+  })
+  instance.unmount()
+  const lines = capture.output.replace(ANSI, "").split("\n").map(l => l.trimEnd())
+  while (lines.length > 0 && lines[lines.length - 1] === "") lines.pop()
+  return lines.slice(0, options.height).join("\n") + "\n"
+}
+
+// vizier render [--width N] [--height N] [--frame <n|last>]
+export function runRender(graph: Graph, options: RenderOptions): void {
+  process.stdout.write(renderSnapshot(graph, options))
+}
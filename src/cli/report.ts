@@ -0,0 +1,23 @@
+import type { Graph, Source } from "../core/types"
+import { buildReport, formatReport, formatReportMarkdown, reportToJson } from "../core/report"
+import { loadConfig } from "../storage/config"
+import { readSessionProvenance } from "../sources/provenance"
+
+export type ReportOptions = { since: string; sinceMs: number; format: "table" | "markdown" | "json" }
+
+// vizier report [--since 7d] [--markdown|--json] — every session active in the window
+export async function runReport(source: Source, options: ReportOptions): Promise<void> {
+  const until = Date.now()
+  const since = until - options.sinceMs
+  const sessions = (await source.listSessions()).filter(s => s.timestamp >= since)
+  const graphs: Graph[] = []
+  for (const s of sessions) graphs.push(await source.readGraph(s.id))
+  const report = buildReport(graphs, since, until, loadConfig().toolGroups)
+  if (options.format === "table") {
+    process.stdout.write(formatReport(report, options.since))
+    return
+  }
+  const sources = await readSessionProvenance(source, sessions.map(s => s.id))
+  const format = options.format === "json" ? reportToJson : formatReportMarkdown
+  process.stdout.write(format(report, options.since, sources))
+}
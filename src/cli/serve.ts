@@ -0,0 +1,41 @@
+import type { Graph, Source } from "../core/types"
+import type { StreamMessage } from "../core/stream"
+import { STREAM_PROTOCOL, appendMessage } from "../core/stream"
+
+const TOPIC = "session"
+
+// vizier serve [--session <id>] [--port 7777] — streams the session's nodes
+// over a WebSocket until interrupted
+export function runServe(source: Source, sessionId: string, initial: Graph, port: number): void {
+  let current = initial
+  const send = (message: StreamMessage) => JSON.stringify(message)
+
+  const server = Bun.serve({
+    port,
+    fetch(req, server) {
+      if (server.upgrade(req)) return undefined
+      return new Response("vizier stream: connect with a WebSocket client\n", { status: 426 })
+    },
+    websocket: {
+      open(ws) {
+        ws.send(send({ type: "hello", protocol: STREAM_PROTOCOL, sessionId, source: source.kind }))
+        ws.send(send({ type: "snapshot", sessionId, nodes: current.nodes, stats: current.stats }))
+        ws.subscribe(TOPIC)
+      },
+      message() {
+        // Clients only listen
+      },
+      close(ws) {
+        ws.unsubscribe(TOPIC)
+      },
+    },
+  })
+
+  source.watch(sessionId, next => {
+    const message = appendMessage(current, next, sessionId)
+    current = next
+    if (message) server.publish(TOPIC, send(message))
+  }, notice => console.error(`vizier: ${notice}`))
+
+  console.error(`Streaming ${sessionId} on ws://localhost:${server.port} (Ctrl+C to stop)`)
+}
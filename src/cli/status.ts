@@ -0,0 +1,9 @@
+import type { Graph } from "../core/types"
+import { summarizeStatus, formatStatus, formatStatusBrief, statusExitCode } from "../core/status"
+
+// vizier status [--session <id>] [--brief] — returns the exit code
+export function runStatus(graph: Graph, sessionId: string, brief = false): number {
+  const status = summarizeStatus(graph.nodes, sessionId, Date.now())
+  process.stdout.write((brief ? formatStatusBrief(status) : formatStatus(status)) + "\n")
+  return statusExitCode(status)
+}
@@ -0,0 +1,33 @@
+import type { Source } from "../core/types"
+import type { SessionActivity } from "../core/top"
+import { isRecentSession, sessionActivity, rankActivity, formatTop, topToJson, TOP_REFRESH_MS } from "../core/top"
+
+async function snapshot(source: Source, now: number, windowMs: number): Promise<SessionActivity[]> {
+  const sessions = (await source.listSessions()).filter(s => isRecentSession(s, now))
+  const rows: SessionActivity[] = []
+  for (const s of sessions) rows.push(sessionActivity(await source.readGraph(s.id), s, now, windowMs))
+  return rankActivity(rows)
+}
+
+// vizier top [--since 5m] [--json] — redraws until interrupted; --json prints once
+export async function runTop(source: Source, label: string, windowMs: number, json = false): Promise<void> {
+  if (json) {
+    const now = Date.now()
+    process.stdout.write(topToJson(await snapshot(source, now, windowMs), now, label, windowMs))
+    return
+  }
+  let busy = false
+  const draw = async () => {
+    if (busy) return // a slow read shouldn't pile up redraws
+    busy = true
+    try {
+      const now = Date.now()
+      const rows = await snapshot(source, now, windowMs)
+      process.stdout.write("\x1b[H\x1b[2J" + formatTop(rows, now, label))
+    } finally {
+      busy = false
+    }
+  }
+  await draw()
+  setInterval(() => { void draw() }, TOP_REFRESH_MS)
+}
@@ -0,0 +1,40 @@
+import { existsSync, readFileSync, readdirSync } from "node:fs"
+import { basename, dirname, extname, join } from "node:path"
+import { getClaudeDir, getSessionFile, listSessions } from "../sources/claude/watcher"
+import { validateSession, formatValidationReport, validationReportToJson } from "../sources/claude/validate"
+
+// A transcript's subagent files live in <dir>/<session>/subagents/
+function agentFilesOf(sessionFile: string): string[] {
+  const dir = join(dirname(sessionFile), basename(sessionFile, ".jsonl"), "subagents")
+  if (!existsSync(dir)) return []
+  return readdirSync(dir).filter(f => extname(f) === ".jsonl").sort().map(f => join(dir, f))
+}
+
+// Without a target, the newest session across every workspace project
+function resolveSessionFile(target: string | undefined, projects: string[]): string | null {
+  if (target && existsSync(target)) return target
+  const claudeDir = getClaudeDir()
+  const id = target?.replace(/^claude:/, "")
+    ?? projects.flatMap(p => listSessions(claudeDir, p)).sort((a, b) => b.timestamp - a.timestamp)[0]?.id
+  if (!id) return null
+  const files = projects.map(p => getSessionFile(claudeDir, p, id))
+  return files.find(f => existsSync(f)) ?? null
+}
+
+// vizier validate [<session id or .jsonl path>] [--json] — returns the exit code.
+// projects are the Claude project slugs to search, as from workspaceDirs.
+export function runValidate(target: string | undefined, projects: string[], json = false): number {
+  if (target?.startsWith("opencode:")) {
+    console.error("validate only checks Claude transcripts")
+    return 1
+  }
+  const sessionFile = resolveSessionFile(target, projects)
+  if (!sessionFile) {
+    console.error(target ? `Session not found: ${target}` : "No Claude sessions found")
+    return 1
+  }
+  const files = [sessionFile, ...agentFilesOf(sessionFile)].map(path => ({ path, content: readFileSync(path, "utf-8") }))
+  const report = validateSession(files)
+  process.stdout.write(json ? validationReportToJson(report) : formatValidationReport(report))
+  return report.issues.length > 0 ? 1 : 0
+}
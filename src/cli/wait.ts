@@ -0,0 +1,39 @@
+import type { Graph, Source } from "../core/types"
+import type { WaitCondition } from "../core/status"
+import { waitSatisfied, summarizeStatus, statusExitCode, formatStatusBrief, EXIT_ERROR } from "../core/status"
+import { lastActivity } from "../core/state"
+
+// vizier wait [--session <id>] --until waiting|error|idle [--for 5m]
+// Resolves with the exit code once the condition holds
+export function runWait(source: Source, sessionId: string, initial: Graph, condition: WaitCondition, idleMs: number): Promise<number> {
+  return new Promise(resolve => {
+    let current = initial
+    let idleTimer: ReturnType<typeof setTimeout> | null = null
+    let stop = () => {}
+
+    const check = () => {
+      if (!waitSatisfied(condition, initial, current, Date.now(), idleMs)) return false
+      if (idleTimer) clearTimeout(idleTimer)
+      stop()
+      const status = summarizeStatus(current.nodes, sessionId, Date.now())
+      process.stdout.write(formatStatusBrief(status) + "\n")
+      resolve(condition === "error" ? EXIT_ERROR : statusExitCode(status))
+      return true
+    }
+
+    // Idleness is the absence of updates, so it needs a clock of its own
+    const armIdle = () => {
+      if (condition !== "idle") return
+      if (idleTimer) clearTimeout(idleTimer)
+      const due = (lastActivity(current.nodes) ?? Date.now()) + idleMs - Date.now()
+      idleTimer = setTimeout(check, Math.max(0, due) + 50)
+    }
+
+    if (check()) return
+    stop = source.watch(sessionId, next => {
+      current = next
+      if (!check()) armIdle()
+    }, notice => console.error(`vizier: ${notice}`))
+    armIdle()
+  })
+}
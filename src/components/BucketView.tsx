@@ -0,0 +1,48 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { Bucket } from "../core/buckets"
+import { glyphs } from "../ui/glyphs"
+import { padToWidth } from "../ui/width"
+import { paint, borderColor } from "../ui/theme"
+import { formatClock } from "../core/time"
+
+type Props = {
+  buckets: Bucket[]
+  cursor: number // bucket index, -1 for none
+  nodeCount: number
+}
+
+const LABEL_W = 7
+const TIME_EVERY = 10 // columns between time labels
+
+// Zoomed-out timeline: a column per time bucket with its node density and
+// whether anything in it failed
+export function BucketView({ buckets, cursor, nodeCount }: Props) {
+  const g = glyphs()
+  const max = Math.max(1, ...buckets.map(b => b.count))
+  const density = buckets.map(b => b.count === 0 ? " " : g.density[Math.min(g.density.length - 1, Math.floor(b.count / max * (g.density.length - 1)))]).join("")
+  const errors = buckets.map(b => b.errors > 0 ? g.error : " ").join("")
+  const pointer = buckets.map((_, i) => i === cursor ? "^" : " ").join("")
+  let times = ""
+  for (let i = 0; i < buckets.length; i += TIME_EVERY) times += padToWidth(formatClock(buckets[i].start, { seconds: false }), TIME_EVERY)
+  const current = buckets[cursor]
+
+  return (
+    <Box flexDirection="column" borderStyle={g.border} borderColor={borderColor("cyan")} paddingX={1}>
+      <Text wrap="truncate">
+        <Text {...paint("magenta")} bold>[OVERVIEW] </Text>
+        <Text dimColor>{nodeCount} nodes in {buckets.length} buckets  h/l:bucket enter/z:zoom in</Text>
+      </Text>
+      <Text>{" "}</Text>
+      <Text wrap="truncate">{padToWidth("Time", LABEL_W)}<Text dimColor>{times}</Text></Text>
+      <Text wrap="truncate">{padToWidth("Nodes", LABEL_W)}<Text {...paint("green")}>{density}</Text></Text>
+      <Text wrap="truncate">{padToWidth("Errors", LABEL_W)}<Text {...paint("red")}>{errors}</Text></Text>
+      <Text wrap="truncate">{padToWidth("", LABEL_W)}<Text bold>{pointer}</Text></Text>
+      <Text wrap="truncate" dimColor>
+        {current && current.count > 0
+          ? `${formatClock(current.start, { seconds: false })}-${formatClock(current.end, { seconds: false })}  ${current.count} nodes, ${current.tools} tool calls, ${current.errors} errors`
+          : "empty bucket"}
+      </Text>
+    </Box>
+  )
+}
@@ -0,0 +1,28 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { BudgetOverrun } from "../core/budget"
+import { formatTokens } from "../core/stats"
+import { glyphs } from "../ui/glyphs"
+import { paint, borderColor } from "../ui/theme"
+
+type Props = {
+  overruns: BudgetOverrun[]
+}
+
+function describe(o: BudgetOverrun): string {
+  return o.kind === "tokens"
+    ? `${formatTokens(o.used)} of ${formatTokens(o.limit)} tokens`
+    : `$${o.used.toFixed(2)} of $${o.limit.toFixed(2)}`
+}
+
+export function BudgetBanner({ overruns }: Props) {
+  const g = glyphs()
+  return (
+    <Box borderStyle={g.border} borderColor={borderColor("red")} paddingX={1}>
+      <Text wrap="truncate">
+        <Text {...paint("red")} bold>{g.warning} over budget: </Text>
+        <Text>{overruns.map(describe).join(` ${g.toolSep} `)}</Text>
+      </Text>
+    </Box>
+  )
+}
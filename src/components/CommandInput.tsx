@@ -1,26 +1,39 @@
 import React, { useState } from "react"
 import { Box, Text } from "ink"
 import TextInput from "ink-text-input"
+import { glyphs } from "../ui/glyphs"
+import { paint, borderColor } from "../ui/theme"
 
 type Props = {
   onSubmit: (text: string) => void
   onCancel: () => void
+  prompt?: string
+  hint?: string
+  initialValue?: string
+  allowEmpty?: boolean
 }
 
-export function CommandInput({ onSubmit, onCancel }: Props) {
-  const [value, setValue] = useState("")
+export function CommandInput({
+  onSubmit,
+  onCancel,
+  prompt = ">",
+  hint = "(Enter to send, Esc to cancel)",
+  initialValue = "",
+  allowEmpty = false,
+}: Props) {
+  const [value, setValue] = useState(initialValue)
 
   return (
-    <Box borderStyle="single" borderColor="cyan" paddingX={1}>
-      <Text color="cyan" bold>{">"} </Text>
+    <Box borderStyle={glyphs().border} borderColor={borderColor("cyan")} paddingX={1}>
+      <Text {...paint("cyan")} bold>{prompt} </Text>
       <TextInput
         value={value}
         onChange={setValue}
         onSubmit={(text) => {
-          if (text.trim()) onSubmit(text.trim())
+          if (text.trim() || allowEmpty) onSubmit(text.trim())
         }}
       />
-      <Text dimColor> (Enter to send, Esc to cancel)</Text>
+      <Text dimColor> {hint}</Text>
     </Box>
   )
 }
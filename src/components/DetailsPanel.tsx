@@ -1,9 +1,28 @@
 import React from "react"
 import { Box, Text } from "ink"
 import type { Node } from "../core/types"
+import type { TurnStats } from "../core/turns"
+import type { ToolFeedback } from "../core/attribution"
+import type { ToolTiming } from "../core/timing"
+import { timingAdvice } from "../core/timing"
+import { formatTokens } from "../core/stats"
+import type { MdSpan } from "../core/markdown"
+import { renderMarkdown, extractCodeBlocks } from "../core/markdown"
+import { MarkdownSpans } from "./MarkdownSpans"
+import { formatDuration } from "../core/time"
+import { pluginDetails } from "../core/plugins"
+import { glyphs } from "../ui/glyphs"
+import { paint, borderColor, type InkColor } from "../ui/theme"
+import { tr } from "../ui/i18n"
+
+export type Crumb = { label: string }
 
 type Props = {
   node: Node | null
+  breadcrumb?: Crumb[]
+  turnStats?: TurnStats | null // shown for user/assistant nodes
+  sessionFeedback?: ToolFeedback[] | null // tool result tokens across the whole session
+  sessionTiming?: ToolTiming[] | null // tools the session's durations flag
   levelName: string
   position: number
   total: number
@@ -11,12 +30,58 @@ type Props = {
   scrollOffset: number
 }
 
-type InkColor = "black" | "red" | "green" | "yellow" | "blue" | "magenta" | "cyan" | "white" | "gray" | undefined
-
 // Flatten node content into plain text lines with optional color hints
-type ContentLine = { text: string; color?: InkColor; dimColor?: boolean; bold?: boolean; keyLen?: number }
+type ContentLine = { text: string; color?: InkColor; dimColor?: boolean; bold?: boolean; keyLen?: number; spans?: MdSpan[] }
 
-function nodeToLines(node: Node): ContentLine[] {
+// "── Request ─────…" style section rule, padded to a fixed width
+function sectionRule(label: string): string {
+  const h = glyphs().hLine
+  return `${h}${h} ${label} `.padEnd(41, h)
+}
+
+// "Read:12.1k (64%) Bash:3.0k (16%)" — the top tools by tokens fed back
+function feedbackText(feedback: ToolFeedback[]): string {
+  const total = feedback.reduce((sum, f) => sum + f.tokens, 0)
+  return feedback
+    .slice(0, 5)
+    .map(f => `${f.name}:${formatTokens(f.tokens)} (${total > 0 ? Math.round(f.tokens / total * 100) : 0}%)`)
+    .join(" ")
+}
+
+const MAX_TIMING_LINES = 3
+
+function turnStatsToLines(lines: ContentLine[], stats: TurnStats, sessionFeedback?: ToolFeedback[] | null, sessionTiming?: ToolTiming[] | null): void {
+  const tok = stats.tokens
+  lines.push({ text: sectionRule("Turn"), color: "cyan" })
+  lines.push({ text: `Duration: ${formatDuration(stats.durationMs)}  Nodes: ${stats.nodeCount}`, dimColor: true })
+  lines.push({ text: `Tokens: in:${tok.totalInputTokens} out:${tok.totalOutputTokens} cache_read:${tok.totalCacheRead}`, dimColor: true })
+  if (tok.totalCost) lines.push({ text: `Cost: $${tok.totalCost.toFixed(4)}`, dimColor: true })
+  const tools = Object.entries(stats.tools).sort((a, b) => b[1] - a[1])
+  const toolText = tools.length > 0 ? tools.map(([name, n]) => `${name}:${n}`).join(" ") : "none"
+  lines.push({ text: `Tools (${stats.toolCalls}): ${toolText}`, dimColor: true })
+  if (stats.errors > 0) lines.push({ text: `Errors: ${stats.errors}`, color: "red" })
+  if (stats.feedback.length > 0) lines.push({ text: `Result tokens: ${feedbackText(stats.feedback)}`, dimColor: true })
+  if (sessionFeedback && sessionFeedback.length > 0) {
+    lines.push({ text: `Session result tokens: ${feedbackText(sessionFeedback)}`, dimColor: true })
+  }
+  for (const t of (sessionTiming ?? []).slice(0, MAX_TIMING_LINES)) {
+    lines.push({ text: `Timing: ${t.key}: ${timingAdvice(t)}`, color: "yellow" })
+  }
+}
+
+function codeBlocksToLines(lines: ContentLine[], text: string): void {
+  const blocks = extractCodeBlocks(text)
+  if (blocks.length === 0) return
+  lines.push({ text: "" })
+  lines.push({ text: sectionRule("Code blocks"), color: "yellow" })
+  blocks.forEach((b, i) => {
+    const count = b.code.split("\n").length
+    lines.push({ text: `${i + 1}: ${b.lang || "text"}, ${count} line${count === 1 ? "" : "s"}`, dimColor: true })
+  })
+  lines.push({ text: "y+N: copy block  Y+N: save block to file", dimColor: true })
+}
+
+function nodeToLines(node: Node, turnStats?: TurnStats | null, sessionFeedback?: ToolFeedback[] | null, sessionTiming?: ToolTiming[] | null): ContentLine[] {
   const lines: ContentLine[] = []
   const time = new Date(node.timestamp).toISOString().replace("T", " ").slice(0, 19)
   lines.push({ text: `ID: ${node.id}`, dimColor: true })
@@ -34,6 +99,7 @@ function nodeToLines(node: Node): ContentLine[] {
   if (node.cost !== undefined && node.cost > 0) {
     lines.push({ text: `Cost: $${node.cost.toFixed(4)}`, dimColor: true })
   }
+  if (turnStats) turnStatsToLines(lines, turnStats, sessionFeedback, sessionTiming)
   lines.push({ text: "" })
 
   switch (node.nodeType.kind) {
@@ -43,7 +109,8 @@ function nodeToLines(node: Node): ContentLine[] {
       break
     case "assistant":
       lines.push({ text: "Assistant Message:", color: "green" })
-      for (const l of node.nodeType.text.split("\n")) lines.push({ text: l })
+      for (const l of renderMarkdown(node.nodeType.text)) lines.push({ text: l.text, spans: l.spans })
+      codeBlocksToLines(lines, node.nodeType.text)
       break
     case "tool_use":
       lines.push({ text: `Tool: ${node.nodeType.name}`, color: "yellow" })
@@ -69,13 +136,16 @@ function nodeToLines(node: Node): ContentLine[] {
         : node.nodeType.isError ? "ERROR" : "OK"
       lines.push({ text: `Tool: ${node.nodeType.name} [${statusLabel}]`, color: statusColor })
       lines.push({ text: "" })
-      lines.push({ text: "\u2500\u2500 Request \u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500", color: "yellow" })
+      lines.push({ text: sectionRule("Request"), color: "yellow" })
       jsonToLines(lines, node.nodeType.input, 1)
       if (node.nodeType.output !== null) {
         lines.push({ text: "" })
-        lines.push({ text: "\u2500\u2500 Response \u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500\u2500", color: statusColor })
+        lines.push({ text: sectionRule("Response"), color: statusColor })
         const out = node.nodeType.output.trim()
-        if (out) {
+        const rendered = pluginDetails(node)
+        if (rendered) {
+          for (const l of rendered) lines.push({ text: `  ${l}` })
+        } else if (out) {
           jsonToLines(lines, out, 1)
         } else {
           lines.push({ text: "  (empty)", dimColor: true })
@@ -163,17 +233,18 @@ function jsonValueToLines(lines: ContentLine[], value: unknown, indent: number)
   lines.push({ text: `${pad}${value}` })
 }
 
-export function DetailsPanel({ node, levelName, position, total, height, scrollOffset }: Props) {
+export function DetailsPanel({ node, breadcrumb = [], turnStats, sessionFeedback, sessionTiming, levelName, position, total, height, scrollOffset }: Props) {
   if (!node) {
     return (
-      <Box flexDirection="column" height={height} borderStyle="single" borderColor="gray" paddingX={1}>
-        <Text dimColor>No node selected</Text>
+      <Box flexDirection="column" height={height} borderStyle={glyphs().border} borderColor={borderColor("gray")} paddingX={1}>
+        <Text dimColor>{tr("No node selected")}</Text>
       </Box>
     )
   }
 
-  const allLines = nodeToLines(node)
-  const innerHeight = height - 2 // border top + bottom
+  const allLines = nodeToLines(node, turnStats, sessionFeedback, sessionTiming)
+  const hasCrumbs = breadcrumb.length > 1
+  const innerHeight = height - 2 - (hasCrumbs ? 1 : 0) // border top + bottom, breadcrumb
   const maxScroll = Math.max(0, allLines.length - innerHeight)
   const offset = Math.min(scrollOffset, maxScroll)
   const visibleLines = allLines.slice(offset, offset + innerHeight)
@@ -185,11 +256,23 @@ export function DetailsPanel({ node, levelName, position, total, height, scrollO
     : ""
 
   return (
-    <Box flexDirection="column" height={height} borderStyle="single" borderColor="gray" paddingX={1}>
+    <Box flexDirection="column" height={height} borderStyle={glyphs().border} borderColor={borderColor("gray")} paddingX={1}>
       <Text>
         <Text bold>{title}</Text>
         <Text dimColor>{scrollHint}</Text>
       </Text>
+      {hasCrumbs && (
+        <Text wrap="truncate">
+          {breadcrumb.map((crumb, i) => (
+            <Text key={i}>
+              {i > 0 && <Text dimColor>{` ${glyphs().pointer} `}</Text>}
+              <Text dimColor>{`${i + 1}:`}</Text>
+              <Text bold={i === breadcrumb.length - 1}>{crumb.label}</Text>
+            </Text>
+          ))}
+          <Text dimColor>  (b+N to jump)</Text>
+        </Text>
+      )}
       {visibleLines.map((line, i) => {
         // Handle key:value lines where key should be colored
         if (line.keyLen && line.color) {
@@ -199,13 +282,13 @@ export function DetailsPanel({ node, levelName, position, total, height, scrollO
           const valPart = line.text.slice(pad + line.keyLen)
           return (
             <Text key={i}>
-              {prefix}<Text color={line.color}>{keyPart}</Text>{valPart}
+              {prefix}<Text {...paint(line.color)}>{keyPart}</Text>{valPart}
             </Text>
           )
         }
         return (
-          <Text key={i} color={line.color} dimColor={line.dimColor} bold={line.bold}>
-            {line.text}
+          <Text key={i} dimColor={line.dimColor} bold={line.bold} {...paint(line.color)}>
+            {line.spans && line.text ? <MarkdownSpans spans={line.spans} /> : line.text}
           </Text>
         )
       })}
@@ -0,0 +1,33 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { ParseIssue } from "../core/types"
+import { glyphs } from "../ui/glyphs"
+import { paint, borderColor } from "../ui/theme"
+
+type Props = {
+  issues: ParseIssue[]
+  height: number
+}
+
+// Lines the source skipped while loading the session, by file and line number
+export function DiagnosticsPanel({ issues, height }: Props) {
+  const g = glyphs()
+  const innerHeight = Math.max(1, height - 3) // border top + bottom, title
+  const visible = issues.slice(0, innerHeight)
+  const more = issues.length - visible.length
+
+  return (
+    <Box flexDirection="column" height={height} borderStyle={g.border} borderColor={borderColor("yellow")} paddingX={1}>
+      <Text>
+        <Text {...paint("yellow")} bold>{g.warning} {issues.length} line{issues.length === 1 ? "" : "s"} skipped </Text>
+        <Text dimColor>{more > 0 ? `(+${more} more, run vizier validate) ` : ""}!:close</Text>
+      </Text>
+      {visible.map((issue, i) => (
+        <Text key={i} wrap="truncate">
+          <Text dimColor>{`${issue.file.split("/").pop()}:${issue.line} `}</Text>
+          {issue.message}
+        </Text>
+      ))}
+    </Box>
+  )
+}
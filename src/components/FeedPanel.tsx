@@ -0,0 +1,39 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { FeedItem } from "../core/feed"
+import { FEED_WINDOW_MS } from "../core/feed"
+import { paint } from "../ui/theme"
+import { formatClock } from "../core/time"
+import { shortSessionId } from "../core/sessions"
+import { displayWidth, padToWidth } from "../ui/width"
+
+type Props = {
+  items: FeedItem[] // newest first
+  projects: number
+  width: number
+  height: number // including the header line
+}
+
+// Newest events from every watched project, one per line
+const WINDOW = `${FEED_WINDOW_MS / 60_000}m`
+
+export function FeedPanel({ items, projects, width, height }: Props) {
+  const nameWidth = Math.min(16, Math.max(0, ...items.map(i => displayWidth(i.project))))
+  return (
+    <Box flexDirection="column" width={width} height={height} flexShrink={0}>
+      <Text wrap="truncate">
+        <Text bold>Activity</Text>
+        <Text dimColor>{`  ${projects} project${projects === 1 ? "" : "s"}, last ${WINDOW}  W:close`}</Text>
+      </Text>
+      {items.length === 0 && <Text dimColor>No activity in the last {WINDOW}</Text>}
+      {items.slice(0, height - 1).map(item => (
+        <Text key={`${item.sessionId}:${item.nodeId}`} wrap="truncate">
+          <Text dimColor>{formatClock(item.timestamp)} </Text>
+          <Text {...paint("cyan")}>{padToWidth(item.project, nameWidth)}</Text>
+          <Text dimColor> {shortSessionId(item.sessionId)} </Text>
+          <Text {...paint(item.error ? "red" : undefined)}>{item.text}</Text>
+        </Text>
+      ))}
+    </Box>
+  )
+}
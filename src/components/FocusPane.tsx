@@ -0,0 +1,158 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { Node } from "../core/types"
+import { formatDuration } from "../core/time"
+import { buildCheck, formatBuildCounts } from "../core/builds"
+import { formatBadges } from "../core/annotations"
+import { glyphs } from "../ui/glyphs"
+import { truncateToWidth } from "../ui/width"
+import { paint, borderColor, type InkColor } from "../ui/theme"
+import { tr } from "../ui/i18n"
+
+type Props = {
+  node: Node | null
+  width: number
+  height: number // including the border and label line
+  now?: number
+  scroll?: number // set while the pane is focused: first visible body line
+  title?: string // shown before the label, e.g. a pin number and time
+}
+
+function getFocusLabel(node: Node, now: number): { text: string; color: InkColor; usage: string } {
+  let label = ""
+  let color: InkColor = "gray"
+  const t = node.nodeType
+  switch (t.kind) {
+    case "user": label = "User"; color = "cyan"; break
+    case "assistant": label = "Asst"; color = "green"; break
+    case "tool_call": {
+      const status = t.output === null ? `PENDING ${formatDuration(now - node.timestamp)}` : t.isError ? "ERROR" : "OK"
+      label = `${t.name} [${status}]`
+      const check = buildCheck(node)
+      if (check) label += ` ${check.kind}: ${formatBuildCounts(check)}`
+      if (node.repeatCount && node.repeatCount > 1) label += ` ${glyphs().repeat}${node.repeatCount} repeated`
+      color = t.output === null ? "yellow" : t.isError ? "red" : "green"
+      break
+    }
+    case "tool_use": label = t.name; color = "yellow"; break
+    case "tool_result": {
+      label = t.isError ? "Result [ERROR]" : "Result [OK]"
+      color = t.isError ? "red" : "green"
+      break
+    }
+    case "agent_start": label = `Agent: ${t.agentType}`; color = "magenta"; break
+    case "agent_end": label = "Agent End"; color = "gray"; break
+    case "progress": label = "Progress"; color = "gray"; break
+    case "reasoning": label = "Reasoning"; color = "gray"; break
+    case "patch": label = `Patch: ${t.files.length} files`; color = "blue"; break
+  }
+  if (node.badges?.length) label += ` ${formatBadges(node.badges)}`
+  if (node.summarized) label += ` +${node.summarized} summarized away (X)`
+  let usage = ""
+  if (node.usage) {
+    const u = node.usage
+    const parts: string[] = []
+    if (u.input_tokens || u.output_tokens) parts.push(`t:${(u.input_tokens ?? 0) + (u.output_tokens ?? 0)}`)
+    if (u.cache_read_input_tokens) parts.push(`cr:${u.cache_read_input_tokens}`)
+    if (u.cache_creation_input_tokens) parts.push(`cc:${u.cache_creation_input_tokens}`)
+    usage = parts.join(" ")
+  }
+  return { text: label, color, usage }
+}
+
+function getFocusLines(node: Node, maxWidth: number): string[] {
+  const trunc = (s: string) => truncateToWidth(s.replace(/\r/g, ""), maxWidth)
+
+  let rawText = ""
+  const t = node.nodeType
+  switch (t.kind) {
+    case "user": rawText = t.text; break
+    case "assistant": rawText = t.text; break
+    case "progress": rawText = t.text; break
+    case "tool_call": rawText = t.input; break
+    case "tool_use": rawText = t.input; break
+    case "tool_result": rawText = t.output; break
+    case "agent_start": rawText = `Type: ${t.agentType}  ID: ${t.agentId}`; break
+    case "agent_end": rawText = `ID: ${t.agentId}`; break
+    case "reasoning": rawText = t.text; break
+    case "patch": rawText = t.files.join("\n"); break
+  }
+
+  // For tool inputs, try to format JSON as key: value pairs
+  if (t.kind === "tool_call" || t.kind === "tool_use") {
+    try {
+      const parsed = JSON.parse(rawText)
+      if (typeof parsed === "object" && parsed !== null && !Array.isArray(parsed)) {
+        const lines: string[] = []
+        for (const [key, val] of Object.entries(parsed)) {
+          const valStr = typeof val === "string"
+            ? val.replace(/\n/g, " ").replace(/\r/g, "")
+            : JSON.stringify(val)
+          lines.push(trunc(`${key}: ${valStr}`))
+        }
+        return withOutput(lines, node, trunc)
+      }
+    } catch { /* fall through */ }
+  }
+
+  return withOutput(rawText.split("\n").map(trunc), node, trunc)
+}
+
+// Tool calls carry their result: append it below the input so it can be scrolled to
+function withOutput(lines: string[], node: Node, trunc: (s: string) => string): string[] {
+  const t = node.nodeType
+  if (t.kind !== "tool_call" || t.output === null) return lines
+  return [...lines, trunc(`${glyphs().hLine.repeat(2)} output`), ...t.output.split("\n").map(trunc)]
+}
+
+// Largest useful scroll offset for a pane of the given height
+function maxFocusScroll(lineCount: number, height: number): number {
+  return Math.max(0, lineCount - Math.max(1, height - 3))
+}
+
+// How far the pane can scroll for this node, so j stops at the last line
+export function focusScrollLimit(node: Node | null, width: number, height: number): number {
+  return node ? maxFocusScroll(getFocusLines(node, Math.max(8, width - 4) - 2).length, height) : 0
+}
+
+// Expanded view of the cursor node, docked below or beside the timeline.
+// Lives in its own box so a long node never overflows the timeline rows.
+export function FocusPane({ node, width, height, now = Date.now(), scroll, title }: Props) {
+  const g = glyphs()
+  const bodyLines = Math.max(1, height - 3) // border top + bottom, label
+  const innerWidth = Math.max(8, width - 4) // border + padding
+  const label = node ? getFocusLabel(node, now) : null
+  const allLines = node ? getFocusLines(node, innerWidth - 2) : []
+  const focused = scroll !== undefined
+  const offset = Math.min(scroll ?? 0, maxFocusScroll(allLines.length, height))
+  const lines = allLines.slice(offset, offset + bodyLines)
+  const scrollHint = focused
+    ? `  [${offset + 1}-${offset + lines.length}/${allLines.length}] j/k:scroll esc:exit`
+    : allLines.length > bodyLines ? "  enter:scroll" : ""
+
+  return (
+    <Box
+      flexDirection="column"
+      width={width}
+      height={height}
+      flexShrink={0}
+      borderStyle={g.border}
+      borderColor={borderColor(focused ? "cyan" : "gray")}
+      paddingX={1}
+    >
+      {label ? (
+        <Text wrap="truncate">
+          {title ? <Text dimColor>{title + " "}</Text> : null}
+          <Text {...paint(label.color)} bold>{`${g.pointer} ${label.text}`}</Text>
+          {label.usage ? <Text dimColor>{" " + label.usage}</Text> : null}
+          {scrollHint && <Text dimColor>{scrollHint}</Text>}
+        </Text>
+      ) : (
+        <Text dimColor>{tr("No node selected")}</Text>
+      )}
+      {lines.map((line, i) => (
+        <Text key={i} dimColor wrap="truncate">{"  " + line}</Text>
+      ))}
+    </Box>
+  )
+}
@@ -0,0 +1,76 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { GanttBar } from "../core/gantt"
+import { barColumns } from "../core/gantt"
+import { formatDuration, formatClock } from "../core/time"
+import { glyphs } from "../ui/glyphs"
+import { padToWidth, truncateToWidth } from "../ui/width"
+import { paint, cursorStyle, type InkColor } from "../ui/theme"
+import { tr } from "../ui/i18n"
+
+type Props = {
+  bars: GanttBar[]
+  cursor: number // index into bars, -1 for none
+  height: number
+  termWidth: number
+}
+
+const LABEL_W = 24
+const DURATION_W = 8
+
+// Paired with the color so failures read without it
+function barStatus(bar: GanttBar): string {
+  const g = glyphs()
+  return bar.isError ? g.error : bar.running ? g.running : " "
+}
+
+function barColor(bar: GanttBar): InkColor {
+  if (bar.isError) return "red"
+  if (bar.running) return "yellow"
+  return bar.kind === "agent" ? "magenta" : "green"
+}
+
+// One row per tool call or subagent, drawn as a bar on a shared time axis
+export function GanttView({ bars, cursor, height, termWidth }: Props) {
+  const g = glyphs()
+  const rows = Math.max(1, height - 1)
+  const axisW = Math.max(10, termWidth - LABEL_W - DURATION_W - 6)
+  // One pass: spreading every bar into Math.min/max overflows the stack on long sessions
+  let from = bars.length > 0 ? Infinity : 0
+  let to = bars.length > 0 ? -Infinity : 0
+  for (const b of bars) {
+    if (b.start < from) from = b.start
+    if (b.end > to) to = b.end
+  }
+  const start = Math.max(0, Math.min(bars.length - rows, cursor - Math.floor(rows / 2)))
+  const visible = bars.slice(start, start + rows)
+
+  return (
+    <Box flexDirection="column" paddingX={1}>
+      <Text wrap="truncate">
+        <Text bold>{tr("Gantt")} </Text>
+        <Text dimColor>
+          {bars.length === 0
+            ? tr("no tool calls yet")
+            : tr("{count} bars  {from} - {to} ({duration})  j/k:bar V:exit", {
+                count: bars.length, from: formatClock(from), to: formatClock(to), duration: formatDuration(to - from),
+              })}
+        </Text>
+      </Text>
+      {visible.map((bar, i) => {
+        const isCursor = start + i === cursor
+        const label = padToWidth(truncateToWidth(`${"  ".repeat(bar.depth)}${bar.label}`, LABEL_W - 2), LABEL_W - 2)
+        const cols = barColumns(bar, from, to, axisW)
+        return (
+          <Text key={`${bar.kind}-${bar.nodeIdx}`} wrap="truncate">
+            <Text {...(isCursor ? cursorStyle() : {})}>{`${isCursor ? g.pointer : " "} ${label}`}</Text>
+            <Text>{" ".repeat(cols.start)}</Text>
+            <Text {...paint(barColor(bar))} bold={isCursor}>{g.bar.repeat(cols.length)}</Text>
+            <Text dimColor>{" ".repeat(axisW - cols.start - cols.length)} {formatDuration(bar.end - bar.start)}</Text>
+            <Text {...paint(barColor(bar))}> {barStatus(bar)}</Text>
+          </Text>
+        )
+      })}
+    </Box>
+  )
+}
@@ -0,0 +1,37 @@
+import React from "react"
+import { Box, Text } from "ink"
+import { cheatsheetLines } from "../core/keymap"
+import { glyphs } from "../ui/glyphs"
+import { paint, borderColor } from "../ui/theme"
+import { tr } from "../ui/i18n"
+
+type Props = {
+  width: number
+  height: number
+}
+
+export const KEYS_HEIGHT = 16
+
+// The active keymap, flowed into as many columns as the width allows
+export function KeysPanel({ width, height }: Props) {
+  const g = glyphs()
+  const lines = cheatsheetLines()
+  const rows = Math.max(1, height - 3) // border top + bottom, title
+  const colW = Math.max(...lines.map(l => l.length)) + 2
+  const cols = Math.max(1, Math.floor((width - 4) / colW))
+  const shown = Math.min(lines.length, rows * cols)
+
+  return (
+    <Box flexDirection="column" height={height} borderStyle={g.border} borderColor={borderColor("cyan")} paddingX={1}>
+      <Text>
+        <Text {...paint("cyan")} bold>{tr("Keys")} </Text>
+        <Text dimColor>{shown < lines.length ? `${tr("(widen the terminal for the rest)")} ` : ""}{tr("e:export markdown E:export text ?:close")}</Text>
+      </Text>
+      {Array.from({ length: Math.min(rows, shown) }, (_, row) => (
+        <Text key={row} wrap="truncate">
+          {Array.from({ length: cols }, (_, col) => lines[col * rows + row] ?? "").map(l => l.padEnd(colW)).join("").trimEnd()}
+        </Text>
+      ))}
+    </Box>
+  )
+}
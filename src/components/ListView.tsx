@@ -0,0 +1,46 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { Graph } from "../core/types"
+import type { ZoomLevel } from "../core/zoom"
+import { filterByZoom, getVisualBranch, getZoomLabel } from "../core/zoom"
+import { describeNode } from "../core/describe"
+import { formatBadges } from "../core/annotations"
+import { formatClock } from "../core/time"
+
+type Props = {
+  graph: Graph
+  zoom: ZoomLevel
+  currentNodeIdx: number | null
+  height: number
+  termWidth: number
+}
+
+// Linear rendering: one node per line, no box drawing or glyph art, so the
+// screen is readable by terminal screen readers
+export function ListView({ graph, zoom, currentNodeIdx, height, termWidth }: Props) {
+  const visible = filterByZoom(graph.nodes, zoom)
+    .filter(i => getVisualBranch(graph.nodes[i], zoom) >= 0)
+  const rows = Math.max(1, height - 1)
+  const cursorPos = Math.max(0, currentNodeIdx === null ? 0 : visible.indexOf(currentNodeIdx))
+  const start = Math.max(0, Math.min(visible.length - rows, cursorPos - Math.floor(rows / 2)))
+  const windowIndices = visible.slice(start, start + rows)
+  const maxLen = Math.max(20, termWidth - 20)
+
+  return (
+    <Box flexDirection="column">
+      <Text bold>
+        {getZoomLabel(zoom)} list, node {visible.length === 0 ? 0 : cursorPos + 1} of {visible.length}
+      </Text>
+      {windowIndices.length === 0 && <Text>No nodes at this zoom level</Text>}
+      {windowIndices.map(idx => {
+        const node = graph.nodes[idx]
+        const isCursor = idx === currentNodeIdx
+        return (
+          <Text key={idx} bold={isCursor} wrap="truncate">
+            {isCursor ? "> " : "  "}{formatClock(node.timestamp)} {node.badges?.length ? `${formatBadges(node.badges)} ` : ""}{describeNode(node, maxLen)}
+          </Text>
+        )
+      })}
+    </Box>
+  )
+}
@@ -0,0 +1,25 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { LoopWarning } from "../core/loops"
+import { glyphs } from "../ui/glyphs"
+import { paint, borderColor } from "../ui/theme"
+
+type Props = {
+  warning: LoopWarning
+  canIntervene: boolean
+}
+
+export function LoopBanner({ warning, canIntervene }: Props) {
+  const g = glyphs()
+  const who = warning.agentId ? `agent ${warning.agentId.slice(0, 8)}` : "agent"
+  const cycle = warning.tools.join(` ${g.pointer} `)
+  return (
+    <Box borderStyle={g.border} borderColor={borderColor("red")} paddingX={1}>
+      <Text wrap="truncate">
+        <Text {...paint("red")} bold>{g.warning} possible loop: </Text>
+        <Text>{who} repeated {cycle} {g.repeat}{warning.repeats}</Text>
+        {canIntervene && <Text dimColor>  (i: send a message)</Text>}
+      </Text>
+    </Box>
+  )
+}
@@ -0,0 +1,15 @@
+import React from "react"
+import { Text } from "ink"
+import type { MdSpan } from "../core/markdown"
+import { paint } from "../ui/theme"
+
+// Styled runs of one rendered markdown line; nest inside a <Text>
+export function MarkdownSpans({ spans }: { spans: MdSpan[] }) {
+  return (
+    <>
+      {spans.map((s, i) => (
+        <Text key={i} bold={s.bold} {...paint(s.code ? "yellow" : undefined)}>{s.text}</Text>
+      ))}
+    </>
+  )
+}
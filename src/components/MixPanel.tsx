@@ -0,0 +1,66 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { Bucket } from "../core/buckets"
+import type { MixKind, NodeMix } from "../core/mix"
+import { MIX_KINDS, stackColumn } from "../core/mix"
+import { glyphs } from "../ui/glyphs"
+import { padToWidth } from "../ui/width"
+import { paint, borderColor, type InkColor } from "../ui/theme"
+import { formatClock } from "../core/time"
+
+type Props = {
+  buckets: Bucket[]
+  mixes: NodeMix[] // one per bucket
+  cursor: number // bucket index, -1 for none
+}
+
+export const MIX_ROWS = 5
+export const MIX_HEIGHT = MIX_ROWS + 5 // border, header, time and pointer rows
+
+const LABEL_W = 7
+const TIME_EVERY = 10
+
+const MIX_COLORS: Record<MixKind, InkColor> = { message: "cyan", read: "blue", edit: "yellow", run: "magenta", error: "red" }
+
+// Kinds get their own fill as well as a color, so the chart reads without color
+function fill(kind: MixKind): string {
+  return glyphs().mix[MIX_KINDS.indexOf(kind)]
+}
+
+// Stacked bars of the node mix per time bucket, top row first
+export function MixPanel({ buckets, mixes, cursor }: Props) {
+  const g = glyphs()
+  const max = Math.max(1, ...mixes.map(m => MIX_KINDS.reduce((sum, k) => sum + m[k], 0)))
+  // Bar height follows the bucket's node count, split by kind
+  const columns = mixes.map(m => {
+    const total = MIX_KINDS.reduce((sum, k) => sum + m[k], 0)
+    const height = total === 0 ? 0 : Math.max(1, Math.round(total / max * MIX_ROWS))
+    return stackColumn(m, height)
+  })
+  let times = ""
+  for (let i = 0; i < buckets.length; i += TIME_EVERY) times += padToWidth(formatClock(buckets[i].start, { seconds: false }), TIME_EVERY)
+
+  return (
+    <Box flexDirection="column" borderStyle={g.border} borderColor={borderColor("gray")} paddingX={1}>
+      <Text wrap="truncate">
+        <Text {...paint("magenta")} bold>[MIX] </Text>
+        {MIX_KINDS.map(k => <Text key={k} {...paint(MIX_COLORS[k])}>{fill(k)} {k}  </Text>)}
+        <Text dimColor>M:close</Text>
+      </Text>
+      {Array.from({ length: MIX_ROWS }, (_, r) => {
+        const level = MIX_ROWS - 1 - r
+        return (
+          <Text key={r} wrap="truncate">
+            {padToWidth(r === MIX_ROWS - 1 ? "Nodes" : "", LABEL_W)}
+            {columns.map((col, i) => {
+              const kind = col[level]
+              return kind ? <Text key={i} {...paint(MIX_COLORS[kind])}>{fill(kind)}</Text> : " "
+            })}
+          </Text>
+        )
+      })}
+      <Text wrap="truncate">{padToWidth("Time", LABEL_W)}<Text dimColor>{times}</Text></Text>
+      <Text wrap="truncate">{padToWidth("", LABEL_W)}<Text bold>{buckets.map((_, i) => i === cursor ? "^" : " ").join("")}</Text></Text>
+    </Box>
+  )
+}
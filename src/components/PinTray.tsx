@@ -0,0 +1,41 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { Node } from "../core/types"
+import { MAX_PINS } from "../core/pins"
+import { FocusPane } from "./FocusPane"
+import { formatClock } from "../core/time"
+import { tr } from "../ui/i18n"
+
+type Props = {
+  nodes: Node[] // pinned, oldest first
+  width: number
+  height: number // including the header line
+}
+
+// Pinned nodes side by side, e.g. a failing Bash run next to the one that passed
+export function PinTray({ nodes, width, height }: Props) {
+  const paneWidth = Math.floor(width / Math.max(1, nodes.length))
+  return (
+    <Box flexDirection="column" width={width} height={height} flexShrink={0}>
+      <Text wrap="truncate">
+        <Text bold>{tr("Pinned {count}/{max}", { count: nodes.length, max: MAX_PINS })}</Text>
+        <Text dimColor>  {tr("a:pin/unpin selected  e:export report  A:close tray")}</Text>
+      </Text>
+      {nodes.length === 0 ? (
+        <Text dimColor>{tr("Nothing pinned: press a on a node to add it here")}</Text>
+      ) : (
+        <Box flexDirection="row">
+          {nodes.map((node, i) => (
+            <FocusPane
+              key={node.id}
+              node={node}
+              width={paneWidth}
+              height={height - 1}
+              title={`#${i + 1} ${formatClock(node.timestamp)}`}
+            />
+          ))}
+        </Box>
+      )}
+    </Box>
+  )
+}
@@ -0,0 +1,35 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { TranscriptLine } from "../core/transcript"
+import { MarkdownSpans } from "./MarkdownSpans"
+import { paint } from "../ui/theme"
+import { tr } from "../ui/i18n"
+
+type Props = {
+  lines: TranscriptLine[]
+  scroll: number
+  height: number
+}
+
+// Distraction-free transcript: prompts and replies only, no timeline chrome
+export function ReadingView({ lines, scroll, height }: Props) {
+  const rows = Math.max(1, height - 1)
+  const visible = lines.slice(scroll, scroll + rows)
+  const percent = lines.length <= rows ? 100 : Math.round(Math.min(1, (scroll + rows) / lines.length) * 100)
+
+  return (
+    <Box flexDirection="column" paddingX={2}>
+      <Text dimColor>{tr("Reading {percent}%  j/k:scroll ctrl+d/u:page g/G:top/end R:exit", { percent })}</Text>
+      {lines.length === 0 && <Text dimColor>{tr("No messages yet")}</Text>}
+      {visible.map((line, i) => (
+        <Text
+          key={scroll + i}
+          bold={line.heading}
+          {...paint(line.heading ? (line.role === "user" ? "cyan" : "green") : undefined)}
+        >
+          {line.spans && line.text ? <MarkdownSpans spans={line.spans} /> : line.text || " "}
+        </Text>
+      ))}
+    </Box>
+  )
+}
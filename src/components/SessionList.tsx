@@ -1,47 +1,49 @@
 import React from "react"
 import { Box, Text, useStdout } from "ink"
 import type { SessionInfo } from "../core/types"
-import stringWidth from "string-width"
+import type { SessionSort } from "../core/sessions"
+import { getSessionSortLabel } from "../core/sessions"
+import { lifecycleOfInfo, LIFECYCLE_LABELS } from "../core/lifecycle"
+import { glyphs } from "../ui/glyphs"
+import { truncateToWidth, padToWidth as padColumns } from "../ui/width"
+import { paint, borderColor } from "../ui/theme"
 
 const DEBUG_LIST = process.env.VIZIER_DEBUG_LIST === "1"
 
+function formatBytes(n: number): string {
+  if (n >= 1024 * 1024) return `${(n / (1024 * 1024)).toFixed(1)}MB`
+  if (n >= 1024) return `${Math.round(n / 1024)}KB`
+  return `${n}B`
+}
+
 type Props = {
   sessions: SessionInfo[]
+  sort: SessionSort
+  query: string
+  noteFor: (session: SessionInfo) => string | undefined
   currentSessionId: string
   cursor: number
 }
 
-export function SessionList({ sessions, currentSessionId, cursor }: Props) {
+export function SessionList({ sessions, sort, query, noteFor, currentSessionId, cursor }: Props) {
   const { stdout } = useStdout()
   const termWidth = stdout?.columns ?? 120
   const termHeight = stdout?.rows ?? 40
   const lineWidth = Math.max(40, termWidth - 4)
   const maxRows = Math.max(5, termHeight - 6) // header + borders + spacing
 
-  const fitToWidth = (text: string, width: number): string => {
-    if (stringWidth(text) <= width) return text
-    let out = ""
-    let w = 0
-    for (const ch of text) {
-      const cw = stringWidth(ch)
-      if (w + cw > width - 1) break
-      out += ch
-      w += cw
-    }
-    return out + "…"
-  }
-
-  const padToWidth = (text: string, width: number): string => {
-    const trimmed = fitToWidth(text, width)
-    const w = stringWidth(trimmed)
-    return w >= width ? trimmed : trimmed + " ".repeat(width - w)
-  }
+  const padToWidth = (text: string, width: number): string => padColumns(truncateToWidth(text, width), width)
+  const now = Date.now()
 
   return (
-    <Box flexDirection="column" borderStyle="single" borderColor="cyan" paddingX={1}>
-      <Text bold> Sessions (Enter to switch, s to close) </Text>
+    <Box flexDirection="column" borderStyle={glyphs().border} borderColor={borderColor("cyan")} paddingX={1}>
+      <Text>
+        <Text bold> Sessions (Enter to switch, o to sort, n to note, / to search, s to close) </Text>
+        <Text dimColor>sort: {getSessionSortLabel(sort)}</Text>
+        {query && <Text {...paint("yellow")}> filter: {query}</Text>}
+      </Text>
       {DEBUG_LIST && (
-        <Text color="yellow">
+        <Text {...paint("yellow")}>
           dbg: rows={maxRows} total={sessions.length} cursor={cursor} w={termWidth} h={termHeight}
         </Text>
       )}
@@ -56,8 +58,9 @@ export function SessionList({ sessions, currentSessionId, cursor }: Props) {
         const idWidth = 8
         const timeWidth = 16
         const eventsWidth = 10
-        const separatorsWidth = 1 + 3 + 3 // space before id, " | ", " | "
-        const fixedWidth = idxWidth + prefixWidth + sourceWidth + separatorsWidth + idWidth + timeWidth + eventsWidth
+        const sizeWidth = 12 // "12.3MB !999"
+        const separatorsWidth = 1 + 3 + 3 + 3 // space before id, " | " ×3
+        const fixedWidth = idxWidth + prefixWidth + sourceWidth + separatorsWidth + idWidth + timeWidth + eventsWidth + sizeWidth
         const titleWidth = Math.max(0, lineWidth - fixedWidth)
 
         return visible.map((session, i) => {
@@ -77,16 +80,23 @@ export function SessionList({ sessions, currentSessionId, cursor }: Props) {
             minute: "2-digit",
           })
           const currentMarker = isCurrent ? " (current)" : ""
+          const state = lifecycleOfInfo(session, now)
+          const stateMarker = state === "ended" ? "" : ` [${LIFECYCLE_LABELS[state].toLowerCase()}]`
 
-          const color = isCurrent ? "green" : undefined
+          const style = paint(isCurrent ? "green" : undefined)
 
           const prefixCol = padToWidth(prefix, prefixWidth)
           const sourceCol = padToWidth(sourceLabel, sourceWidth)
           const idCol = padToWidth(shortId, idWidth)
           const timeCol = padToWidth(time, timeWidth)
           const eventsCol = padToWidth(`${String(session.nodeCount).padStart(4)} events`, eventsWidth)
-          const titleStr = session.title ? ` ${session.title}` : ""
-          const titleCol = padToWidth(`${titleStr}${currentMarker}`, titleWidth)
+          // "!N": lines that failed to parse — a corrupted or truncated transcript
+          const badLines = session.parseErrors ?? 0
+          const sizeText = session.sizeBytes !== undefined ? formatBytes(session.sizeBytes).padStart(6) : ""
+          const sizeCol = padToWidth(`${sizeText}${badLines > 0 ? ` !${badLines}` : ""}`, sizeWidth)
+          const note = noteFor(session)
+          const titleStr = `${session.title ? ` ${session.title}` : ""}${note ? ` [${note}]` : ""}`
+          const titleCol = padToWidth(`${stateMarker}${titleStr}${currentMarker}`, titleWidth)
           const idxLabel = DEBUG_LIST ? padToWidth(`${String(idx).padStart(4)} `, idxWidth) : ""
 
           return (
@@ -98,29 +108,33 @@ export function SessionList({ sessions, currentSessionId, cursor }: Props) {
             >
               {DEBUG_LIST && (
                 <Box width={idxWidth} flexShrink={0}>
-                  <Text color={color} bold={isSelected} wrap="truncate">{idxLabel}</Text>
+                  <Text {...style} bold={isSelected} wrap="truncate">{idxLabel}</Text>
                 </Box>
               )}
               <Box width={prefixWidth} flexShrink={0}>
-                <Text color={color} bold={isSelected} wrap="truncate">{prefixCol}</Text>
+                <Text {...style} bold={isSelected} wrap="truncate">{prefixCol}</Text>
               </Box>
               <Box width={sourceWidth} flexShrink={0}>
-                <Text color={color} bold={isSelected} wrap="truncate">{sourceCol}</Text>
+                <Text {...style} bold={isSelected} wrap="truncate">{sourceCol}</Text>
               </Box>
-              <Text color={color} bold={isSelected}> </Text>
+              <Text {...style} bold={isSelected}> </Text>
               <Box width={idWidth} flexShrink={0}>
-                <Text color={color} bold={isSelected} wrap="truncate">{idCol}</Text>
+                <Text {...style} bold={isSelected} wrap="truncate">{idCol}</Text>
               </Box>
-              <Text color={color} bold={isSelected}> | </Text>
+              <Text {...style} bold={isSelected}> | </Text>
               <Box width={timeWidth} flexShrink={0}>
-                <Text color={color} bold={isSelected} wrap="truncate">{timeCol}</Text>
+                <Text {...style} bold={isSelected} wrap="truncate">{timeCol}</Text>
               </Box>
-              <Text color={color} bold={isSelected}> | </Text>
+              <Text {...style} bold={isSelected}> | </Text>
               <Box width={eventsWidth} flexShrink={0}>
-                <Text color={color} bold={isSelected} wrap="truncate">{eventsCol}</Text>
+                <Text {...style} bold={isSelected} wrap="truncate">{eventsCol}</Text>
+              </Box>
+              <Text {...style} bold={isSelected}> | </Text>
+              <Box width={sizeWidth} flexShrink={0}>
+                <Text {...(badLines > 0 ? paint("red") : style)} bold={isSelected} wrap="truncate">{sizeCol}</Text>
               </Box>
               <Box flexGrow={1} minWidth={0}>
-                <Text color={color} bold={isSelected} wrap="truncate">{titleCol}</Text>
+                <Text {...style} bold={isSelected} wrap="truncate">{titleCol}</Text>
               </Box>
             </Box>
           )
@@ -2,7 +2,15 @@ import React from "react"
 import { Box, Text } from "ink"
 import type { ZoomLevel } from "../core/zoom"
 import type { SessionStats } from "../core/types"
+import type { OverdueTool } from "../core/active"
+import type { Lifecycle } from "../core/lifecycle"
+import { LIFECYCLE_LABELS } from "../core/lifecycle"
+import { formatDuration, formatClock } from "../core/time"
+import { totalTokens, formatTokens } from "../core/stats"
 import { getZoomLabel } from "../core/zoom"
+import { glyphs } from "../ui/glyphs"
+import { tr } from "../ui/i18n"
+import { paint, type InkColor } from "../ui/theme"
 
 type Props = {
   levelName: string
@@ -10,31 +18,54 @@ type Props = {
   total: number
   totalNodes: number
   zoom: ZoomLevel
-  isLive: boolean
+  lifecycle?: Lifecycle // absent in snapshots, which have no clock
   follow: boolean
   stats: SessionStats
+  pendingCount?: string
+  message?: string
+  overdue?: OverdueTool // longest-running tool past its threshold
+  pausedEvents?: number // set while live updates are paused
+  skippedLines?: number // transcript lines the source could not parse
+  tokenGrowth?: number // tokens added by the latest live update
+  scrubTime?: number // set while the scrubber shows a past moment
+  notice?: string // lasting warning from the source, e.g. watching fell back to polling
 }
 
-function formatTokens(n: number): string {
-  if (n >= 1_000_000) return `${(n / 1_000_000).toFixed(1)}M`
-  if (n >= 1_000) return `${(n / 1_000).toFixed(1)}k`
-  return String(n)
+export const LIFECYCLE_COLORS: Record<Lifecycle, InkColor> = {
+  starting: "cyan",
+  streaming: "green",
+  "tool-running": "green",
+  "waiting-for-user": "yellow",
+  idle: "gray",
+  ended: "gray",
+  error: "red",
 }
 
-export function StatusBar({ levelName, position, total, totalNodes, zoom, isLive, follow, stats }: Props) {
-  const tokenStr = `in:${formatTokens(stats.totalInputTokens)} out:${formatTokens(stats.totalOutputTokens)} cache:${formatTokens(stats.totalCacheRead)}`
+export function StatusBar({ levelName, position, total, totalNodes, zoom, lifecycle, follow, stats, pendingCount, message, overdue, pausedEvents, skippedLines, tokenGrowth, scrubTime, notice }: Props) {
+  const tokenStr = `tok:${formatTokens(totalTokens(stats))} (in:${formatTokens(stats.totalInputTokens)} out:${formatTokens(stats.totalOutputTokens)} cache:${formatTokens(stats.totalCacheRead + stats.totalCacheCreation)})`
+  const contextStr = stats.contextTokens ? ` ctx:${formatTokens(stats.contextTokens)}` : ""
   const costStr = stats.totalCost ? ` $${stats.totalCost.toFixed(2)}` : ""
 
   return (
     <Box>
       <Text dimColor>
-        {levelName} {position}/{total} | {totalNodes} nodes | {getZoomLabel(zoom)}
+        {levelName} {position}/{total} | {tr("{count} nodes", { count: totalNodes })} | {getZoomLabel(zoom)}
       </Text>
       {stats.model && <Text dimColor> | {stats.model}</Text>}
       <Text dimColor> | {tokenStr}</Text>
+      {tokenGrowth ? <Text {...paint("green")}> +{formatTokens(tokenGrowth)}</Text> : null}
+      {contextStr && <Text dimColor>{contextStr}</Text>}
       {costStr && <Text dimColor> |{costStr}</Text>}
-      {isLive && <Text color="green" bold> LIVE</Text>}
-      {follow && <Text color="yellow" bold> FOLLOW</Text>}
+      {stats.repeatedToolCalls && <Text {...paint("yellow")}> | {tr("{count} repeated calls", { count: stats.repeatedToolCalls })}</Text>}
+      {scrubTime !== undefined && <Text {...paint("magenta")} bold> {tr("AT {time}", { time: formatClock(scrubTime) })}</Text>}
+      {lifecycle && pausedEvents === undefined && scrubTime === undefined && <Text {...paint(LIFECYCLE_COLORS[lifecycle])} bold> {tr(LIFECYCLE_LABELS[lifecycle])}</Text>}
+      {pausedEvents !== undefined && <Text {...paint("yellow")} bold> {tr("PAUSED")}{pausedEvents > 0 ? ` ${tr("+{count} new", { count: pausedEvents })}` : ""}</Text>}
+      {follow && <Text {...paint("yellow")} bold> {tr("FOLLOW")}</Text>}
+      {skippedLines ? <Text {...paint("yellow")}> {glyphs().warning} {skippedLines === 1 ? tr("1 line skipped (!)") : tr("{count} lines skipped (!)", { count: skippedLines })}</Text> : null}
+      {notice && <Text {...paint("yellow")}> {glyphs().warning} {notice}</Text>}
+      {overdue && <Text {...paint("red")} bold> {tr("{name} running {duration}", { name: overdue.name, duration: formatDuration(overdue.elapsedMs) })}</Text>}
+      {pendingCount && <Text bold> {pendingCount}</Text>}
+      {message && <Text {...paint("cyan")}> {message}</Text>}
     </Box>
   )
 }
@@ -0,0 +1,37 @@
+import React from "react"
+import { Text } from "ink"
+import type { TestRun } from "../core/testruns"
+import { formatTestCounts } from "../core/testruns"
+import { glyphs } from "../ui/glyphs"
+import { paint, cursorStyle } from "../ui/theme"
+import { formatClock } from "../core/time"
+
+type Props = {
+  runs: TestRun[]
+  current: number // index of the run at or before the cursor, -1 for none
+  width: number
+}
+
+const LABEL = "Tests "
+
+// One line: a mark per test run, oldest first, then the selected run's counts
+export function TestStrip({ runs, current, width }: Props) {
+  const g = glyphs()
+  const shown = runs[current] ?? runs[runs.length - 1]
+  const summary = shown ? `  ${formatClock(shown.timestamp, { seconds: false })} ${shown.runner}: ${formatTestCounts(shown)}` : "  no test runs yet"
+  // Keep the newest runs when they don't all fit
+  const room = Math.max(1, width - LABEL.length - summary.length - 2)
+  const first = Math.max(0, runs.length - room)
+  return (
+    <Text wrap="truncate">
+      <Text bold>{LABEL}</Text>
+      {first > 0 && <Text dimColor>{g.ellipsis}</Text>}
+      {runs.slice(first).map((run, i) => (
+        <Text key={run.nodeIdx} {...(first + i === current ? cursorStyle() : paint(run.ok ? "green" : "red"))}>
+          {run.ok ? g.ok : g.error}
+        </Text>
+      ))}
+      <Text dimColor>{summary}</Text>
+    </Text>
+  )
+}
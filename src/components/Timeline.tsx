@@ -1,10 +1,26 @@
 import React from "react"
 import { Box, Text } from "ink"
-import stringWidth from "string-width"
 import type { Node, Graph } from "../core/types"
+import type { Relation } from "../core/related"
+import type { HeatLevel } from "../core/heat"
+import { isNodeActive } from "../core/active"
+import { formatBadges } from "../core/annotations"
+import type { Lifecycle } from "../core/lifecycle"
+import { LIFECYCLE_LABELS, isAgentTurn } from "../core/lifecycle"
+import { formatDuration, formatClock } from "../core/time"
+import type { CameraMode } from "../core/camera"
+import { getWindowStart, getCameraLabel } from "../core/camera"
 import type { ZoomLevel, CellMode } from "../core/zoom"
 import { filterByZoom, getVisualBranch, getZoomLabel, getNodePreview, findStickyNode } from "../core/zoom"
+import { LANES } from "../core/lanes"
+import { helpLine } from "../core/keymap"
+import { COL_W_PREVIEW, MAX_BRANCH, FILE_LABEL_W, getColW, getStickyW, getLabelW, columnsPerScreen } from "../core/layout"
 import { getToolUi } from "../ui/tool-icons"
+import { glyphs } from "../ui/glyphs"
+import { tr } from "../ui/i18n"
+import { padToWidth, truncateToWidth } from "../ui/width"
+import { paint, borderColor, cursorStyle, isInkColor, type InkColor } from "../ui/theme"
+import { LIFECYCLE_COLORS } from "./StatusBar"
 
 type Props = {
   graph: Graph
@@ -14,9 +30,21 @@ type Props = {
   cellMode: CellMode
   blinkState: boolean
   termWidth: number
+  relations?: Relation[] | null // set when related-node highlighting is on
+  heat?: (HeatLevel | null)[] | null // set when token-weighted coloring is on
+  title?: string // session label, shown when panes are split
+  focused?: boolean
+  overdueNodes?: Set<number> // active tools past their duration threshold
+  now?: number // clock for elapsed counters on running tools
+  hidden?: string[] // node types hidden by the visibility mask
+  filter?: string // active "/" filter expression
+  camera?: CameraMode
+  paused?: boolean // live updates are held (space)
+  lifecycle?: Lifecycle // session state for the header; absent in snapshots
 }
 
-type InkColor = "black" | "red" | "green" | "yellow" | "blue" | "magenta" | "cyan" | "white" | "gray"
+// Cold to hot: below median, above p50, above p75, above p90
+const HEAT_COLORS: Record<HeatLevel, InkColor> = { 0: "gray", 1: "green", 2: "yellow", 3: "red" }
 
 const SIMPLE_ICON_SYMBOL: Record<string, string> = {
   "simple-icons:git": "🌿",
@@ -27,66 +55,70 @@ const SIMPLE_ICON_SYMBOL: Record<string, string> = {
 
 function getToolSymbol(node: Node): string | null {
   if (node.nodeType.kind !== "tool_call" && node.nodeType.kind !== "tool_use") return null
+  if (!glyphs().toolIcons) return null
   const ui = getToolUi(node)
   if (ui?.iconText) return ui.iconText
   if (ui?.iconId && SIMPLE_ICON_SYMBOL[ui.iconId]) return SIMPLE_ICON_SYMBOL[ui.iconId]
   return null
 }
 
+// Configured tool color; errors stay red whatever the tool
+function getToolColor(node: Node): InkColor | null {
+  const t = node.nodeType
+  if ((t.kind !== "tool_call" && t.kind !== "tool_use") || (t.kind === "tool_call" && t.isError)) return null
+  const color = getToolUi(node)?.color
+  return isInkColor(color) ? color : null
+}
+
 function getNodeInfo(node: Node): { symbol: string; color: InkColor } {
+  const info = getDefaultNodeInfo(node)
+  const color = getToolColor(node)
+  return color ? { ...info, color } : info
+}
+
+function getDefaultNodeInfo(node: Node): { symbol: string; color: InkColor } {
+  const g = glyphs()
   switch (node.nodeType.kind) {
-    case "user": return { symbol: "\u25CF", color: "cyan" }          // ●
-    case "assistant": return { symbol: "\u25C9", color: "green" }    // ◉
+    case "user": return { symbol: g.user, color: "cyan" }
+    case "assistant": return { symbol: g.assistant, color: "green" }
     case "tool_use": {
       const symbol = getToolSymbol(node)
-      return { symbol: symbol ? `${symbol}·` : "\u2B22", color: "yellow" }  // ⬢
+      return { symbol: symbol ? `${symbol}${g.toolSep}` : g.toolPending, color: "yellow" }
     }
     case "tool_result":
       return node.nodeType.isError
-        ? { symbol: "\u2717", color: "red" }                         // ✗
-        : { symbol: "\u2713", color: "green" }                       // ✓
+        ? { symbol: g.error, color: "red" }
+        : { symbol: g.ok, color: "green" }
     case "tool_call":
       {
         const symbol = getToolSymbol(node)
         if (symbol) {
-          const status = node.nodeType.output === null ? "…" : (node.nodeType.isError ? "✗" : "✓")
+          const status = node.nodeType.output === null ? g.running : (node.nodeType.isError ? g.error : g.ok)
           const color: InkColor = node.nodeType.output === null
             ? "yellow"
             : node.nodeType.isError ? "red" : "green"
           return { symbol: `${symbol} ${status}`, color }
         }
-        if (node.nodeType.output === null) return { symbol: "\u2B22", color: "yellow" }  // ⬢ pending
+        if (node.nodeType.output === null) return { symbol: g.toolPending, color: "yellow" }  // pending
         return node.nodeType.isError
-          ? { symbol: "\u2717", color: "red" }                         // ✗ failed
-          : { symbol: "\u2713", color: "green" }                       // ✓ success
+          ? { symbol: g.error, color: "red" }                         // failed
+          : { symbol: g.ok, color: "green" }                          // success
       }
-    case "agent_start": return { symbol: "\u27D0", color: "magenta" } // ⟐
-    case "agent_end": return { symbol: "\u27D0", color: "gray" }
-    case "progress": return { symbol: "\u25CB", color: "gray" }      // ○
-    case "reasoning": return { symbol: "\u25C7", color: "gray" }     // ◇
-    case "patch": return { symbol: "\u25A0", color: "blue" }         // ■
+    case "agent_start": return { symbol: g.agent, color: "magenta" }
+    case "agent_end": return { symbol: g.agent, color: "gray" }
+    case "progress": return { symbol: g.progress, color: "gray" }
+    case "reasoning": return { symbol: g.reasoning, color: "gray" }
+    case "patch": return { symbol: g.patch, color: "blue" }
   }
 }
 
-function isNodeActive(_graph: Graph, idx: number): boolean {
-  const node = _graph.nodes[idx]
-  if (node.nodeType.kind === "tool_call") return node.nodeType.output === null
-  if (node.nodeType.kind === "tool_use") {
-    return !_graph.nodes.slice(idx + 1).some(
-      n => n.parentId === node.id && n.nodeType.kind === "tool_result"
-    )
+function getRowLabel(row: number, zoom: ZoomLevel, fileLanes: string[] = []): string {
+  if (zoom === "lanes") return LANES[row] ?? ""
+  if (zoom === "files") {
+    if (row === 0) return "User "
+    const file = fileLanes[row - 1] ?? ""
+    return truncateToWidth(file.split("/").pop() ?? file, FILE_LABEL_W - 1) + " "
   }
-  return false
-}
-
-function formatTime(ts: number): string {
-  const d = new Date(ts)
-  const h = String(d.getHours()).padStart(2, "0")
-  const m = String(d.getMinutes()).padStart(2, "0")
-  return `${h}:${m}`
-}
-
-function getRowLabel(row: number): string {
   if (row === 0) return "User "
   if (row === 1) return "Asst "
   if (row === 2) return "Tool "
@@ -96,55 +128,16 @@ function getRowLabel(row: number): string {
   return (row - 3) % 2 === 0 ? " Asst" : " Tool"
 }
 
-// Column widths per cell mode
-// Symbol: "──WXYZ" = 6 chars (supports tool + spaced status, symbol padded to 4 cols)
-// Preview: "──WXYZ preview text    " = 25 chars (same ── prefix, symbol, then padded text)
-const COL_W_SYMBOL = 6
-const COL_W_PREVIEW = 25
 const PREVIEW_TEXT_W = COL_W_PREVIEW - 6  // 19 chars after "──WXYZ" for " preview text"
 const SYMBOL_W = 4
 
-// Sticky column widths per mode
-const STICKY_W_SYMBOL = 5           // "●x  │"
-const STICKY_W_PREVIEW = COL_W_PREVIEW  // full width
-
-function getColW(mode: CellMode): number {
-  return mode === "preview" ? COL_W_PREVIEW : COL_W_SYMBOL
-}
-
-function getStickyW(mode: CellMode): number {
-  return mode === "preview" ? STICKY_W_PREVIEW : STICKY_W_SYMBOL
-}
-
 function padSymbol(symbol: string): string {
   return padToWidth(symbol, SYMBOL_W)
 }
 
-function fitToWidth(text: string, width: number): string {
-  if (stringWidth(text) <= width) return text
-  let out = ""
-  let w = 0
-  for (const ch of text) {
-    const cw = stringWidth(ch)
-    if (w + cw > width) break
-    out += ch
-    w += cw
-  }
-  return out
-}
-
-function padToWidth(text: string, width: number): string {
-  const trimmed = fitToWidth(text, width)
-  const w = stringWidth(trimmed)
-  return w >= width ? trimmed : trimmed + " ".repeat(width - w)
-}
-
 // Detail line: only for node types where it adds info beyond the preview
 function getNodeDetailLine(node: Node, maxLen: number): string {
-  const trunc = (s: string) => {
-    const clean = s.replace(/[\n\r]+/g, " ").trim()
-    return clean.length > maxLen ? clean.slice(0, maxLen - 1) + "\u2026" : clean
-  }
+  const trunc = (s: string) => truncateToWidth(s.replace(/[\n\r]+/g, " ").trim(), maxLen)
   const t = node.nodeType
   switch (t.kind) {
     case "tool_call": return trunc(t.input)
@@ -154,101 +147,17 @@ function getNodeDetailLine(node: Node, maxLen: number): string {
   }
 }
 
-// --- Peek: expanded preview for cursor node ---
-const PEEK_BODY_LINES = 2
-
-function getNodePeekLabel(node: Node): { text: string; color: InkColor; usage: string } {
-  let label = ""
-  let color: InkColor = "gray"
-  const t = node.nodeType
-  switch (t.kind) {
-    case "user": label = "User"; color = "cyan"; break
-    case "assistant": label = "Asst"; color = "green"; break
-    case "tool_call": {
-      const status = t.output === null ? "PENDING" : t.isError ? "ERROR" : "OK"
-      label = `${t.name} [${status}]`
-      color = t.output === null ? "yellow" : t.isError ? "red" : "green"
-      break
-    }
-    case "tool_use": label = t.name; color = "yellow"; break
-    case "tool_result": {
-      label = t.isError ? "Result [ERROR]" : "Result [OK]"
-      color = t.isError ? "red" : "green"
-      break
-    }
-    case "agent_start": label = `Agent: ${t.agentType}`; color = "magenta"; break
-    case "agent_end": label = "Agent End"; color = "gray"; break
-    case "progress": label = "Progress"; color = "gray"; break
-    case "reasoning": label = "Reasoning"; color = "gray"; break
-    case "patch": label = `Patch: ${t.files.length} files`; color = "blue"; break
-  }
-  let usage = ""
-  if (node.usage) {
-    const u = node.usage
-    const parts: string[] = []
-    if (u.input_tokens || u.output_tokens) parts.push(`t:${(u.input_tokens ?? 0) + (u.output_tokens ?? 0)}`)
-    if (u.cache_read_input_tokens) parts.push(`cr:${u.cache_read_input_tokens}`)
-    if (u.cache_creation_input_tokens) parts.push(`cc:${u.cache_creation_input_tokens}`)
-    usage = parts.join(" ")
-  }
-  return { text: label, color, usage }
-}
-
-function getNodePeekLines(node: Node, maxWidth: number, maxLines: number): string[] {
-  const trunc = (s: string) => {
-    const clean = s.replace(/\r/g, "")
-    return clean.length > maxWidth ? clean.slice(0, maxWidth - 1) + "\u2026" : clean
-  }
-
-  let rawText = ""
-  const t = node.nodeType
-  switch (t.kind) {
-    case "user": rawText = t.text; break
-    case "assistant": rawText = t.text; break
-    case "progress": rawText = t.text; break
-    case "tool_call": rawText = t.input; break
-    case "tool_use": rawText = t.input; break
-    case "tool_result": rawText = t.output; break
-    case "agent_start": rawText = `Type: ${t.agentType}  ID: ${t.agentId}`; break
-    case "agent_end": rawText = `ID: ${t.agentId}`; break
-    case "reasoning": rawText = t.text; break
-    case "patch": rawText = t.files.join("\n"); break
-  }
-
-  // For tool inputs, try to format JSON as key: value pairs
-  if (t.kind === "tool_call" || t.kind === "tool_use") {
-    try {
-      const parsed = JSON.parse(rawText)
-      if (typeof parsed === "object" && parsed !== null && !Array.isArray(parsed)) {
-        const lines: string[] = []
-        for (const [key, val] of Object.entries(parsed)) {
-          if (lines.length >= maxLines) break
-          const valStr = typeof val === "string"
-            ? val.replace(/\n/g, " ").replace(/\r/g, "")
-            : JSON.stringify(val)
-          lines.push(trunc(`${key}: ${valStr}`))
-        }
-        return lines
-      }
-    } catch { /* fall through */ }
-  }
-
-  const lines: string[] = []
-  for (const l of rawText.split("\n")) {
-    if (lines.length >= maxLines) break
-    lines.push(trunc(l))
-  }
-  return lines
-}
-
-export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, blinkState, termWidth }: Props) {
+export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, blinkState, termWidth, relations, heat, title, focused = true, overdueNodes, now = Date.now(), hidden = [], filter, camera = "center", paused = false, lifecycle }: Props) {
+  const g = glyphs()
+  const stem = g.hLine.repeat(2)
   const isPreview = cellMode === "preview"
   const colW = getColW(cellMode)
   const stickyW = getStickyW(cellMode)
   const visibleIndices = filterByZoom(graph.nodes, zoom)
   if (visibleIndices.length === 0) {
     return (
-      <Box flexDirection="column" borderStyle="single" borderColor="cyan" paddingX={1}>
+      <Box flexDirection="column" borderStyle={g.border} borderColor={borderColor(focused ? "cyan" : "gray")} paddingX={1}>
+        {title && <Text bold>{title}</Text>}
         <Text dimColor>No nodes at this zoom level</Text>
       </Box>
     )
@@ -263,27 +172,10 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
   }
   const cursorGlobalPos = currentLevelPositions[cursorInLevel] ?? 0
 
-  // Peek: cursor node for expanded preview
-  const peekNodeGlobalIdx = cursorGlobalPos < visibleIndices.length ? visibleIndices[cursorGlobalPos] : undefined
-  const cursorNode = peekNodeGlobalIdx !== undefined ? graph.nodes[peekNodeGlobalIdx] : null
-  const peekLabel = cursorNode ? getNodePeekLabel(cursorNode) : null
-  const peekMaxW = termWidth - 10
-  const peekLines = cursorNode ? getNodePeekLines(cursorNode, peekMaxW, PEEK_BODY_LINES) : []
-
   // Camera-centric windowing — reserve space for sticky column
-  const labelW = 5
-  const availW = termWidth - labelW - 4 - stickyW
-  const nodesPerScreen = Math.max(1, Math.floor(availW / colW))
-  const halfScreen = Math.floor(nodesPerScreen / 2)
-
-  let start: number
-  if (cursorGlobalPos < halfScreen) {
-    start = 0
-  } else if (cursorGlobalPos + halfScreen >= visibleIndices.length) {
-    start = Math.max(0, visibleIndices.length - nodesPerScreen)
-  } else {
-    start = cursorGlobalPos - halfScreen
-  }
+  const labelW = getLabelW(zoom)
+  const nodesPerScreen = columnsPerScreen(zoom, cellMode, termWidth)
+  const start = getWindowStart(camera, cursorGlobalPos, visibleIndices.length, nodesPerScreen)
   const end = Math.min(start + nodesPerScreen, visibleIndices.length)
   const windowIndices = visibleIndices.slice(start, end)
   const numCols = windowIndices.length
@@ -294,7 +186,7 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
     const b = getVisualBranch(graph.nodes[idx], zoom)
     if (b > maxBranch) maxBranch = b
   }
-  maxBranch = Math.min(maxBranch, 14) // 3 main rows + up to ~5 parallel agents × 2 rows
+  maxBranch = Math.min(maxBranch, MAX_BRANCH)
 
   // Pre-compute connectors: │ at the start of the cell (position 0)
   const connectorGaps: Set<number>[] = Array.from({ length: maxBranch }, () => new Set())
@@ -347,7 +239,7 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
       || (node.timestamp - lastShownTime) >= 60000
 
     if (shouldShow) {
-      const t = formatTime(node.timestamp)
+      const t = formatClock(node.timestamp, { seconds: false })
       if (isPreview) {
         timeSpans.push(<Text key={`t${col}`} dimColor>{padToWidth(t, colW)}</Text>)
         lastShownTime = node.timestamp
@@ -366,38 +258,59 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
     node: Node, idx: number, isCursor: boolean, key: string | number,
   ): React.ReactNode {
     const { symbol, color } = getNodeInfo(node)
-    const active = isNodeActive(graph, idx)
+    // Collapsed repeats take over the stem: "×3" instead of "──"
+    const repeat = node.repeatCount ?? 1
+    const repeatBadge = repeat > 1
+      ? <Text {...paint("yellow")} bold>{`${g.repeat}${repeat > 9 ? "+" : repeat}`}</Text>
+      : null
+    const active = isNodeActive(graph.nodes, idx)
+    const overdue = active && overdueNodes?.has(idx)
     const allowBlink = !(node.nodeType.kind === "tool_call" || node.nodeType.kind === "tool_use")
-    const displaySymbol = padSymbol(active && allowBlink ? (blinkState ? "\u25D0" : "\u25D1") : symbol)
+    const displaySymbol = padSymbol(active && allowBlink ? (blinkState ? g.blinkOn : g.blinkOff) : symbol)
 
     // Preview: "──● preview text    " — same ── prefix, then text fills remaining space
+    const elapsed = active && isPreview ? `(${formatDuration(now - node.timestamp)}) ` : ""
+    const badges = node.badges?.length ? `${formatBadges(node.badges)} ` : ""
     const previewTail = isPreview
-      ? padToWidth(" " + getNodePreview(node, PREVIEW_TEXT_W - 1), PREVIEW_TEXT_W)
+      ? padToWidth(" " + elapsed + badges + getNodePreview(node, PREVIEW_TEXT_W - 1), PREVIEW_TEXT_W)
       : ""
 
     if (isCursor) {
       return (
         <Text key={key}>
-          <Text dimColor>{"──"}</Text>
-          <Text backgroundColor="white" color="black" bold>{displaySymbol}</Text>
-          {previewTail && <Text backgroundColor="white" color="black">{previewTail}</Text>}
+          {repeatBadge ?? <Text dimColor>{stem}</Text>}
+          <Text {...cursorStyle()} bold>{displaySymbol}</Text>
+          {previewTail && <Text {...cursorStyle()}>{previewTail}</Text>}
         </Text>
       )
     }
     if (active) {
       return (
         <Text key={key}>
-          <Text dimColor>{"──"}</Text>
-          <Text color="yellow" bold>{displaySymbol}</Text>
-          {previewTail && <Text color="yellow">{previewTail}</Text>}
+          {repeatBadge ?? <Text dimColor>{stem}</Text>}
+          <Text {...paint(overdue ? "red" : "yellow")} bold>{displaySymbol}</Text>
+          {previewTail && <Text {...paint(overdue ? "red" : "yellow")}>{previewTail}</Text>}
+        </Text>
+      )
+    }
+    // Related-node highlighting: lineage stands out, unrelated nodes fade
+    const relation = relations?.[idx]
+    if (relation === "none") {
+      return (
+        <Text key={key}>
+          {repeatBadge ?? <Text dimColor>{stem}</Text>}
+          <Text dimColor>{displaySymbol}</Text>
+          {previewTail && <Text dimColor>{previewTail}</Text>}
         </Text>
       )
     }
+    const emphasized = relation === "lineage"
+    const level = heat?.[idx]
     return (
       <Text key={key}>
-        <Text dimColor>{"──"}</Text>
-        <Text color={color}>{displaySymbol}</Text>
-        {previewTail && <Text dimColor>{previewTail}</Text>}
+        {repeatBadge ?? <Text dimColor={!emphasized}>{stem}</Text>}
+        <Text {...paint(level != null ? HEAT_COLORS[level] : color)} bold={emphasized || level === 3}>{displaySymbol}</Text>
+        {previewTail && <Text dimColor={!emphasized}>{previewTail}</Text>}
       </Text>
     )
   }
@@ -412,16 +325,16 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
       const preview = getNodePreview(node, PREVIEW_TEXT_W - 1)
       return (
         <Text key={key}>
-          <Text color={color}>{displaySymbol}</Text>
+          <Text {...paint(color)}>{displaySymbol}</Text>
           <Text dimColor>{padToWidth(" " + preview, stickyW - 5)}</Text>
-          <Text dimColor>{"\u2502"}</Text>
+          <Text dimColor>{g.vLine}</Text>
         </Text>
       )
     }
     return (
       <Text key={key}>
-        <Text color={color}>{displaySymbol}</Text>
-        <Text dimColor>{"\u2502"}</Text>
+        <Text {...paint(color)}>{displaySymbol}</Text>
+        <Text dimColor>{g.vLine}</Text>
       </Text>
     )
   }
@@ -431,15 +344,15 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
     const hasSticky = stickyNodes.has(vb) || stickyNodes.has(vb + 1)
     if (!hasSticky) return <Text key={key}>{pad(stickyW)}</Text>
     if (isPreview) {
-      return <Text key={key} dimColor>{pad(stickyW - 1) + "\u2502"}</Text>
+      return <Text key={key} dimColor>{pad(stickyW - 1) + g.vLine}</Text>
     }
-    return <Text key={key} dimColor>{" \u2502"}</Text>
+    return <Text key={key} dimColor>{" " + g.vLine}</Text>
   }
 
   // --- Build branch rows ---
   const rows: React.ReactNode[] = []
   for (let vb = 0; vb <= maxBranch; vb++) {
-    const label = getRowLabel(vb)
+    const label = getRowLabel(vb, zoom, graph.fileLanes)
     const isCurrentRow = vb === currentLevel
     const sticky = stickyNodes.get(vb)
 
@@ -454,6 +367,8 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
       }
     }
 
+    let lastActiveTool: number | null = null
+    let lastBadged: number | null = null
     for (let col = 0; col < numCols; col++) {
       const idx = windowIndices[col]
       const node = graph.nodes[idx]
@@ -462,14 +377,31 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
       if (nodeBranch === vb) {
         const isCursor = isCurrentRow && (start + col) === cursorGlobalPos
         cellSpans.push(renderNodeCell(node, idx, isCursor, col))
+        const kind = node.nodeType.kind
+        if ((kind === "tool_call" || kind === "tool_use") && isNodeActive(graph.nodes, idx)) lastActiveTool = idx
+        if (node.badges?.length) lastBadged = idx
       } else {
         cellSpans.push(<Text key={col}>{pad(colW)}</Text>)
       }
     }
+    // Symbol cells have no room for text: the row's latest running tool gets
+    // its elapsed counter at the end of the row instead, and its latest
+    // annotated node its badges
+    if (!isPreview && lastActiveTool !== null) {
+      const elapsedMs = now - graph.nodes[lastActiveTool].timestamp
+      cellSpans.push(
+        <Text key="elapsed" {...paint(overdueNodes?.has(lastActiveTool) ? "red" : "yellow")}>
+          {` (${formatDuration(elapsedMs)})`}
+        </Text>
+      )
+    }
+    if (!isPreview && lastBadged !== null) {
+      cellSpans.push(<Text key="badges" {...paint("cyan")}>{` ${formatBadges(graph.nodes[lastBadged].badges)}`}</Text>)
+    }
 
     rows.push(
       <Text key={`row-${vb}`}>
-        <Text color={isCurrentRow ? "yellow" : undefined} bold={isCurrentRow} dimColor={!isCurrentRow}>
+        <Text {...paint(isCurrentRow ? "yellow" : undefined)} bold={isCurrentRow} dimColor={!isCurrentRow}>
           {padToWidth(label, labelW)}
         </Text>
         {cellSpans}
@@ -495,7 +427,7 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
         if (hasAnyStickyNode) {
           const hasSticky = stickyNodes.has(vb) || stickyNodes.has(vb + 1)
           if (hasSticky) {
-            detailSpans.push(<Text key={`sdetail-${vb}`} dimColor>{pad(stickyW - 1) + "\u2502"}</Text>)
+            detailSpans.push(<Text key={`sdetail-${vb}`} dimColor>{pad(stickyW - 1) + g.vLine}</Text>)
           } else {
             detailSpans.push(<Text key={`sdetail-${vb}`}>{pad(stickyW)}</Text>)
           }
@@ -510,7 +442,7 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
             const detail = getNodeDetailLine(node, PREVIEW_TEXT_W - 1)
             detailSpans.push(<Text key={col} dimColor>{padToWidth("   " + detail, colW)}</Text>)
           } else if (vb < maxBranch && connectorGaps[vb].has(col)) {
-            detailSpans.push(<Text key={col} dimColor>{"\u2502" + pad(colW - 1)}</Text>)
+            detailSpans.push(<Text key={col} dimColor>{g.vLine + pad(colW - 1)}</Text>)
           } else {
             detailSpans.push(<Text key={col}>{pad(colW)}</Text>)
           }
@@ -533,7 +465,7 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
       }
       for (let col = 0; col < numCols; col++) {
         if (connectorGaps[vb].has(col)) {
-          connSpans.push(<Text key={col} dimColor>{"\u2502" + pad(colW - 1)}</Text>)
+          connSpans.push(<Text key={col} dimColor>{g.vLine + pad(colW - 1)}</Text>)
         } else {
           connSpans.push(<Text key={col}>{pad(colW)}</Text>)
         }
@@ -550,12 +482,24 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
   const timeStickyPad = hasAnyStickyNode ? pad(stickyW) : ""
 
   return (
-    <Box flexDirection="column" borderStyle="single" borderColor="cyan" paddingX={1}>
-      <Text>
-        <Text color="magenta" bold>[{getZoomLabel(zoom)}] </Text>
-        {isPreview && <Text color="blue" bold>[PREVIEW] </Text>}
-        <Text color="green" bold>{"\u25CF"} LIVE </Text>
-        <Text dimColor>h/l:chrono shift+arrow:level j/k:row w:preview t:timeline d:details s:sessions f:follow q:quit</Text>
+    <Box flexDirection="column" borderStyle={g.border} borderColor={borderColor(focused ? "cyan" : "gray")} paddingX={1}>
+      <Text wrap={title ? "truncate" : "wrap"}>
+        {title && <Text bold={focused} dimColor={!focused}>{title} </Text>}
+        <Text {...paint("magenta")} bold>[{getZoomLabel(zoom)}] </Text>
+        {isPreview && <Text {...paint("blue")} bold>[PREVIEW] </Text>}
+        {relations && <Text {...paint("cyan")} bold>[RELATED] </Text>}
+        {heat && <Text {...paint("yellow")} bold>[TOKENS] </Text>}
+        {camera !== "center" && <Text {...paint("blue")} bold>[CAM {getCameraLabel(camera)}] </Text>}
+        {hidden.length > 0 && <Text {...paint("gray")} bold>[HIDING {hidden.join(", ").toUpperCase()}] </Text>}
+        {filter && <Text {...paint("yellow")} bold>[FILTER {filter}] </Text>}
+        {paused
+          ? <Text {...paint("yellow")} bold>{tr("PAUSED")} </Text>
+          : lifecycle && (
+            <Text {...paint(LIFECYCLE_COLORS[lifecycle])} bold>
+              {isAgentTurn(lifecycle) ? `${g.live} ` : ""}{tr(LIFECYCLE_LABELS[lifecycle])}{" "}
+            </Text>
+          )}
+        <Text dimColor>{helpLine()}</Text>
       </Text>
       <Text>{" "}</Text>
       <Text>
@@ -565,18 +509,6 @@ export function Timeline({ graph, currentLevel, cursorInLevel, zoom, cellMode, b
       </Text>
       <Text>{" "}</Text>
       {rows}
-      {cursorNode && peekLabel && (
-        <>
-          <Text dimColor>{pad(labelW) + "\u2500".repeat((hasAnyStickyNode ? stickyW : 0) + numCols * colW)}</Text>
-          <Text>
-            <Text bold color={peekLabel.color}>{" \u25B8 " + peekLabel.text}</Text>
-            {peekLabel.usage ? <Text dimColor>{" " + peekLabel.usage}</Text> : null}
-          </Text>
-          {peekLines.map((line, i) => (
-            <Text key={`peek-${i}`} dimColor>{"   " + line}</Text>
-          ))}
-        </>
-      )}
     </Box>
   )
 }
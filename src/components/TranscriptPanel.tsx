@@ -0,0 +1,53 @@
+import React from "react"
+import { Box, Text } from "ink"
+import type { TranscriptLine, TranscriptRole } from "../core/transcript"
+import { lineOfNode } from "../core/transcript"
+import { MarkdownSpans } from "./MarkdownSpans"
+import { glyphs } from "../ui/glyphs"
+import { paint, borderColor, type InkColor } from "../ui/theme"
+
+type Props = {
+  lines: TranscriptLine[]
+  currentNodeIdx: number | null
+  tailing: boolean // cursor is on the newest node — pin the view to the end
+  height: number
+}
+
+const ROLE_COLORS: Record<TranscriptRole, InkColor | undefined> = {
+  user: "cyan",
+  assistant: undefined,
+  tool: "yellow",
+  error: "red",
+  meta: "gray",
+}
+
+const CONTEXT_LINES = 2 // lines kept above the selected node
+
+// Readable chat transcript, scrolled so the timeline's selected node sits near
+// the top. While tailing, the end stays in view so a growing reply scrolls in.
+export function TranscriptPanel({ lines, currentNodeIdx, tailing, height }: Props) {
+  const innerHeight = Math.max(1, height - 3) // border top + bottom, title
+  const anchor = currentNodeIdx === null ? 0 : lineOfNode(lines, currentNodeIdx)
+  const maxStart = Math.max(0, lines.length - innerHeight)
+  const start = tailing ? maxStart : Math.min(maxStart, Math.max(0, anchor - CONTEXT_LINES))
+  const visible = lines.slice(start, start + innerHeight)
+
+  return (
+    <Box flexDirection="column" height={height} borderStyle={glyphs().border} borderColor={borderColor("gray")} paddingX={1}>
+      <Text>
+        <Text bold> Transcript </Text>
+        {tailing && <Text {...paint("green")}>{glyphs().live} tail </Text>}
+        <Text dimColor>{lines.length > innerHeight ? `[${start + 1}-${start + visible.length}/${lines.length}] ` : ""}T:close</Text>
+      </Text>
+      {lines.length === 0 && <Text dimColor>No messages yet</Text>}
+      {visible.map((line, i) => {
+        const selected = line.nodeIdx === currentNodeIdx
+        return (
+          <Text key={start + i} wrap="truncate" bold={selected} {...paint(ROLE_COLORS[line.role])}>
+            {selected ? glyphs().pointer : " "} {line.spans ? <MarkdownSpans spans={line.spans} /> : line.text}
+          </Text>
+        )
+      })}
+    </Box>
+  )
+}
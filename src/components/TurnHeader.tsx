@@ -0,0 +1,27 @@
+import React from "react"
+import { Box, Text } from "ink"
+import { truncateToWidth } from "../ui/width"
+import { formatBadges } from "../core/annotations"
+import { paint } from "../ui/theme"
+
+type Props = {
+  prompt: string
+  turn: number
+  badges?: string[] // the prompt node's
+  width: number
+}
+
+// One dim line over the timeline: what the visible tool calls are working on
+export function TurnHeader({ prompt, turn, badges, width }: Props) {
+  const label = `turn ${turn} `
+  const tags = badges?.length ? `${formatBadges(badges)} ` : ""
+  return (
+    <Box width={width}>
+      <Text wrap="truncate">
+        <Text dimColor>{label}</Text>
+        {tags && <Text {...paint("yellow")}>{tags}</Text>}
+        <Text dimColor italic>{truncateToWidth(prompt, Math.max(1, width - label.length - tags.length - 1))}</Text>
+      </Text>
+    </Box>
+  )
+}
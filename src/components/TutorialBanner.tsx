@@ -0,0 +1,27 @@
+import React from "react"
+import { Box, Text } from "ink"
+import { TUTORIAL_STEPS } from "../core/tutorial"
+import { paint } from "../ui/theme"
+import { tr } from "../ui/i18n"
+
+type Props = {
+  step: number
+  width: number
+}
+
+export const TUTORIAL_HEIGHT = 2
+
+// The current tutorial step above the status bar
+export function TutorialBanner({ step, width }: Props) {
+  const current = TUTORIAL_STEPS[step]
+  return (
+    <Box flexDirection="column" width={width}>
+      <Text wrap="truncate" {...paint("cyan")} bold>
+        {current ? tr("Tutorial {step}/{total}: {title}", { step: step + 1, total: TUTORIAL_STEPS.length, title: tr(current.title) }) : tr("Tutorial complete")}
+      </Text>
+      <Text wrap="truncate">
+        {current ? tr(current.prompt) : tr("Keep exploring this session, or press q to quit. vizier on its own opens your latest session.")}
+      </Text>
+    </Box>
+  )
+}
@@ -0,0 +1,41 @@
+import type { Node } from "./types"
+
+// A node is active while its tool hasn't produced a result yet
+export function isNodeActive(nodes: Node[], idx: number): boolean {
+  const node = nodes[idx]
+  if (node.nodeType.kind === "tool_call") return node.nodeType.output === null
+  if (node.nodeType.kind === "tool_use") {
+    return !nodes.slice(idx + 1).some(
+      n => n.parentId === node.id && n.nodeType.kind === "tool_result"
+    )
+  }
+  return false
+}
+
+export const DEFAULT_TOOL_THRESHOLD_S = 300
+
+// Threshold in ms for a tool: exact name, then the last path segment of
+// namespaced tools (mcp__github__search → search), then "*"
+export function toolThresholdMs(name: string, thresholds: Record<string, number> = {}): number {
+  const lower: Record<string, number> = {}
+  for (const [key, value] of Object.entries(thresholds)) lower[key.toLowerCase()] = value
+  const normalized = name.trim().toLowerCase()
+  const base = normalized.split(/[/:]|__/).filter(Boolean).pop() ?? normalized
+  const seconds = lower[normalized] ?? lower[base] ?? lower["*"] ?? DEFAULT_TOOL_THRESHOLD_S
+  return seconds * 1000
+}
+
+export type OverdueTool = { idx: number; name: string; elapsedMs: number }
+
+// Active tools running longer than their threshold, longest first
+export function findOverdueTools(nodes: Node[], now: number, thresholds?: Record<string, number>): OverdueTool[] {
+  const overdue: OverdueTool[] = []
+  nodes.forEach((node, idx) => {
+    const t = node.nodeType
+    if (t.kind !== "tool_call" && t.kind !== "tool_use") return
+    if (!isNodeActive(nodes, idx)) return
+    const elapsedMs = now - node.timestamp
+    if (elapsedMs > toolThresholdMs(t.name, thresholds)) overdue.push({ idx, name: t.name, elapsedMs })
+  })
+  return overdue.sort((a, b) => b.elapsedMs - a.elapsedMs)
+}
@@ -0,0 +1,74 @@
+import type { Graph, Node } from "./types"
+import { glyphs } from "../ui/glyphs"
+
+// Badges from a project's annotation hook: an external command that reads one
+// JSON node per line on stdin and answers, in any order and only for the
+// nodes it cares about, with lines like {"id": "...", "badges": ["JIRA-142"]}.
+// Enrichment without a plugin: ticket numbers, environment names, owners.
+
+export type Annotations = Record<string, string[]> // node id → badges
+
+export const MAX_BADGES = 4
+export const MAX_BADGE_LENGTH = 24
+
+// What the hook sees of a node: enough to match on, without usage or layout
+export function annotationRequest(node: Node): string {
+  const t = node.nodeType
+  const base = { id: node.id, kind: t.kind, timestamp: new Date(node.timestamp).toISOString(), agentId: node.agentId ?? null }
+  switch (t.kind) {
+    case "user":
+    case "assistant":
+    case "reasoning":
+      return JSON.stringify({ ...base, text: t.text })
+    case "tool_call":
+      return JSON.stringify({ ...base, name: t.name, input: t.input, output: t.output, isError: t.isError })
+    case "tool_use":
+      return JSON.stringify({ ...base, name: t.name, input: t.input })
+    default:
+      return JSON.stringify(base)
+  }
+}
+
+// A finished tool call is sent again, since its result is often what a badge is about
+export function annotationKey(node: Node): string {
+  const t = node.nodeType
+  return t.kind === "tool_call" && t.output !== null ? `${node.id}:done` : node.id
+}
+
+function cleanBadge(value: unknown): string | null {
+  if (typeof value !== "string") return null
+  const text = value.replace(/\s+/g, " ").trim()
+  if (!text) return null
+  return text.length > MAX_BADGE_LENGTH ? text.slice(0, MAX_BADGE_LENGTH - 1) + glyphs().ellipsis : text
+}
+
+// One reply line → the node's badges; an empty list clears them. Anything
+// that isn't a reply (hook logging, malformed JSON) is ignored.
+export function parseAnnotation(line: string): { id: string; badges: string[] } | null {
+  let reply: unknown
+  try {
+    reply = JSON.parse(line)
+  } catch {
+    return null
+  }
+  if (!reply || typeof reply !== "object") return null
+  const { id, badges } = reply as { id?: unknown; badges?: unknown }
+  if (typeof id !== "string" || !Array.isArray(badges)) return null
+  return { id, badges: badges.map(cleanBadge).filter((b): b is string => b !== null).slice(0, MAX_BADGES) }
+}
+
+export function withAnnotations(graph: Graph, annotations: Annotations): Graph {
+  if (Object.keys(annotations).length === 0) return graph
+  let changed = false
+  const nodes = graph.nodes.map(node => {
+    const badges = annotations[node.id]
+    if (!badges || badges.length === 0) return node
+    changed = true
+    return { ...node, badges }
+  })
+  return changed ? { ...graph, nodes } : graph
+}
+
+export function formatBadges(badges: string[] | undefined): string {
+  return (badges ?? []).map(b => `[${b}]`).join(" ")
+}
@@ -0,0 +1,49 @@
+import type { Node, Usage } from "./types"
+import type { ToolGroups } from "./groups"
+import { toolLabel } from "./groups"
+
+// Tokens spent feeding tool results back to the model, per tool. Each request
+// is charged for the tools whose results it consumed — the calls finished
+// since that agent's previous request — and its new prompt tokens (input plus
+// cache writes; cache reads are older context) are split between them by
+// output size. Requests are told apart by their usage object, which every
+// node parsed from one model response shares.
+
+export type ToolFeedback = { name: string; tokens: number; calls: number }
+
+function freshTokens(usage: Usage): number {
+  return (usage.input_tokens ?? 0) + (usage.cache_creation_input_tokens ?? 0)
+}
+
+export function attributeToolTokens(nodes: Node[], groups: ToolGroups = {}): ToolFeedback[] {
+  const byTool = new Map<string, ToolFeedback>()
+  const pending = new Map<string, { name: string; weight: number }[]>() // by agent
+  const seen = new Set<Usage>()
+
+  for (const node of nodes) {
+    const agent = node.agentId ?? ""
+    if (node.usage && !seen.has(node.usage)) {
+      seen.add(node.usage)
+      const consumed = pending.get(agent) ?? []
+      const total = consumed.reduce((sum, c) => sum + c.weight, 0)
+      const fresh = freshTokens(node.usage)
+      for (const c of consumed) {
+        const entry = byTool.get(c.name) ?? { name: c.name, tokens: 0, calls: 0 }
+        entry.tokens += total > 0 ? fresh * c.weight / total : fresh / consumed.length
+        entry.calls++
+        byTool.set(c.name, entry)
+      }
+      pending.delete(agent)
+    }
+    const t = node.nodeType
+    if (t.kind === "tool_call" && t.output !== null) {
+      const list = pending.get(agent) ?? []
+      list.push({ name: toolLabel(t.name, groups), weight: t.output.length })
+      pending.set(agent, list)
+    }
+  }
+
+  return [...byTool.values()]
+    .map(f => ({ ...f, tokens: Math.round(f.tokens) }))
+    .sort((a, b) => b.tokens - a.tokens || a.name.localeCompare(b.name))
+}
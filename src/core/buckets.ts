@@ -0,0 +1,66 @@
+import type { Node } from "./types"
+
+// Overview of a session too long to scan node by node: the time span is cut
+// into one bucket per column, each summarizing its nodes.
+
+// Bucketing kicks in once there are this many nodes per available column
+export const BUCKET_FACTOR = 4
+
+export type Bucket = {
+  start: number // time range [start, end)
+  end: number
+  first: number // indices of its earliest and latest node, -1 when the bucket is empty
+  last: number
+  count: number
+  tools: number
+  errors: number
+}
+
+export function needsBuckets(nodeCount: number, columns: number): boolean {
+  return nodeCount > columns * BUCKET_FACTOR
+}
+
+export function bucketNodes(nodes: Node[], columns: number): Bucket[] {
+  if (nodes.length === 0 || columns <= 0) return []
+  // A loop, not Math.min(...): long sessions are the point, and spreads overflow the stack
+  let from = Infinity
+  let to = -Infinity
+  for (const n of nodes) {
+    from = Math.min(from, n.timestamp)
+    to = Math.max(to, n.timestamp + 1)
+  }
+  const width = (to - from) / columns
+  const buckets: Bucket[] = Array.from({ length: columns }, (_, i) => ({
+    start: from + i * width,
+    end: from + (i + 1) * width,
+    first: -1,
+    last: -1,
+    count: 0,
+    tools: 0,
+    errors: 0,
+  }))
+  // By time, not index: filters and lane layouts don't keep nodes in time order
+  nodes.forEach((node, idx) => {
+    const b = buckets[Math.min(columns - 1, Math.floor((node.timestamp - from) / width))]
+    if (b.first < 0 || node.timestamp < nodes[b.first].timestamp) b.first = idx
+    if (b.last < 0 || node.timestamp >= nodes[b.last].timestamp) b.last = idx
+    b.count++
+    const t = node.nodeType
+    if (t.kind === "tool_call" || t.kind === "tool_use") b.tools++
+    if ((t.kind === "tool_call" || t.kind === "tool_result") && t.isError) b.errors++
+  })
+  return buckets
+}
+
+// The bucket holding a moment (a node's timestamp); the last one includes its end
+export function bucketOf(buckets: Bucket[], timestamp: number): number {
+  return buckets.findIndex((b, i) => b.start <= timestamp && (timestamp < b.end || i === buckets.length - 1 && timestamp <= b.end))
+}
+
+// The next non-empty bucket in a direction, or null at the end
+export function stepBucket(buckets: Bucket[], from: number, direction: 1 | -1): number | null {
+  for (let i = from + direction; i >= 0 && i < buckets.length; i += direction) {
+    if (buckets[i].count > 0) return i
+  }
+  return null
+}
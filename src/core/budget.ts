@@ -0,0 +1,26 @@
+import type { SessionStats } from "./types"
+import { totalTokens } from "./stats"
+
+// Per-session spending limits from config; either limit may be left out
+export type Budget = {
+  tokens?: number // input + output + cache tokens
+  cost?: number // dollars, for sources that report cost
+  bell?: boolean // ring the terminal bell when a limit is first crossed
+}
+
+export type BudgetOverrun = { kind: "tokens" | "cost"; used: number; limit: number }
+
+function isLimit(value: unknown): value is number {
+  return typeof value === "number" && Number.isFinite(value) && value > 0
+}
+
+// Limits that have been reached, tokens first
+export function checkBudget(stats: SessionStats, budget: Budget | undefined): BudgetOverrun[] {
+  if (!budget) return []
+  const overruns: BudgetOverrun[] = []
+  const tokens = totalTokens(stats)
+  if (isLimit(budget.tokens) && tokens >= budget.tokens) overruns.push({ kind: "tokens", used: tokens, limit: budget.tokens })
+  const cost = stats.totalCost ?? 0
+  if (isLimit(budget.cost) && cost >= budget.cost) overruns.push({ kind: "cost", used: cost, limit: budget.cost })
+  return overruns
+}
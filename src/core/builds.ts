@@ -0,0 +1,98 @@
+import type { Node } from "./types"
+import { shellCommand, sum, countLines } from "./testruns"
+import { glyphs } from "../ui/glyphs"
+
+// Build and lint commands in shell tool calls, with their outcome and the
+// error and warning counts the tool reported. Like test runs, these are found
+// by the command line, wherever the tool sits in it.
+
+export type BuildKind = "build" | "lint"
+
+export type BuildCheck = {
+  kind: BuildKind
+  tool: string // the command that matched, e.g. "cargo clippy"
+  ok: boolean // exited cleanly with no reported errors
+  errors: number | null // null when the output had no recognizable count
+  warnings: number | null
+}
+
+// Lint first: `cargo clippy` and `npm run lint` would otherwise read as builds
+const COMMANDS: [BuildKind, RegExp][] = [
+  ["lint", /\bcargo (?:clippy|fmt --check)\b/],
+  ["lint", /\b(?:npm|pnpm|yarn|bun)(?: run)? lint\b/],
+  ["lint", /\b(?:npx )?(?:eslint|biome (?:check|lint)|prettier --check)\b/],
+  ["lint", /\b(?:ruff(?: check)?|flake8|pylint|mypy)\b/],
+  ["lint", /\b(?:go vet|golangci-lint|shellcheck)\b/],
+  ["build", /\bcargo (?:build|check)\b/],
+  ["build", /\b(?:npm|pnpm|yarn|bun)(?: run)? build\b/],
+  ["build", /\b(?:npx )?tsc\b/],
+  ["build", /\b(?:go|dotnet) build\b/],
+  ["build", /\b(?:mvn|gradle|\.\/gradlew) (?:compile|build|package)\b/],
+  ["build", /\bcmake --build\b/],
+  ["build", /\bmake(?: (?:build|all))?(?=\s*(?:$|&&|;|\|))/],
+]
+
+export function buildCommand(command: string): { kind: BuildKind; tool: string } | null {
+  for (const [kind, pattern] of COMMANDS) {
+    const m = pattern.exec(command)
+    if (m) return { kind, tool: m[0] }
+  }
+  return null
+}
+
+export function isBuildNode(node: Node): boolean {
+  const command = shellCommand(node)
+  return command !== null && buildCommand(command) !== null
+}
+
+// Summaries first: eslint's "(E errors, W warnings)", tsc and ruff's "Found N
+// errors", cargo's "generated N warnings" and "due to N previous errors".
+// Without one, each "error:" / "warning:" diagnostic line counts once.
+export function parseDiagnostics(output: string): { errors: number | null; warnings: number | null } {
+  const eslint = /\((\d+) errors?, (\d+) warnings?\)/.exec(output)
+  if (eslint) return { errors: Number(eslint[1]), warnings: Number(eslint[2]) }
+  const found = /\bFound (\d+) errors?\b/.exec(output)
+  const previous = sum(output, /due to (\d+) previous errors?/g)
+  const generated = sum(output, /generated (\d+) warnings?/g)
+  return {
+    errors: found ? Number(found[1]) : previous ?? countLines(output, /\berror(?:\[\w+\]| TS\d+)?: /g),
+    warnings: generated ?? countLines(output, /\bwarning(?:\[\w+\])?: /g),
+  }
+}
+
+// Every timeline render asks for each visible call's badge; nodes are rebuilt
+// on each parse, so a check is worked out once per graph
+const checks = new WeakMap<Node, BuildCheck | null>()
+
+export function buildCheck(node: Node): BuildCheck | null {
+  const cached = checks.get(node)
+  if (cached !== undefined) return cached
+  const check = computeBuildCheck(node)
+  checks.set(node, check)
+  return check
+}
+
+function computeBuildCheck(node: Node): BuildCheck | null {
+  const t = node.nodeType
+  const command = shellCommand(node)
+  const match = command ? buildCommand(command) : null
+  if (!match || t.kind !== "tool_call" || t.output === null) return null
+  const { errors, warnings } = parseDiagnostics(t.output)
+  const ok = !t.isError && !errors
+  return { ...match, ok, errors: errors ?? (ok ? 0 : null), warnings }
+}
+
+// Compact, for timeline previews: "lint ✗ 3e 2w"
+export function buildBadge(check: BuildCheck): string {
+  const counts = [check.errors ? `${check.errors}e` : "", check.warnings ? `${check.warnings}w` : ""].filter(Boolean)
+  return [check.kind, check.ok ? glyphs().ok : glyphs().error, ...counts].join(" ")
+}
+
+export function formatBuildCounts(check: BuildCheck): string {
+  const plural = (n: number, word: string) => `${n} ${word}${n === 1 ? "" : "s"}`
+  const parts = [
+    check.errors ? plural(check.errors, "error") : "",
+    check.warnings ? plural(check.warnings, "warning") : "",
+  ].filter(Boolean)
+  return parts.length > 0 ? parts.join(", ") : check.ok ? "clean" : "failed"
+}
@@ -0,0 +1,35 @@
+// Horizontal windowing for the timeline: where the cursor sits on screen
+//   center   — cursor mid-screen (clamped at the ends)
+//   left     — cursor at the left edge, upcoming nodes to its right
+//   trailing — cursor at the right edge, so live activity stays in view
+export type CameraMode = "center" | "left" | "trailing"
+
+const CAMERA_ORDER: CameraMode[] = ["center", "left", "trailing"]
+
+export function isCameraMode(value: unknown): value is CameraMode {
+  return typeof value === "string" && (CAMERA_ORDER as string[]).includes(value)
+}
+
+export function nextCameraMode(mode: CameraMode): CameraMode {
+  return CAMERA_ORDER[(CAMERA_ORDER.indexOf(mode) + 1) % CAMERA_ORDER.length]
+}
+
+export function getCameraLabel(mode: CameraMode): string {
+  switch (mode) {
+    case "center": return "CENTER"
+    case "left": return "LEFT"
+    case "trailing": return "TRAIL"
+  }
+}
+
+// First visible column for a cursor at global position `cursor`
+export function getWindowStart(mode: CameraMode, cursor: number, total: number, perScreen: number): number {
+  const maxStart = Math.max(0, total - perScreen)
+  let start: number
+  switch (mode) {
+    case "center": start = cursor - Math.floor(perScreen / 2); break
+    case "left": start = cursor; break
+    case "trailing": start = cursor - perScreen + 1; break
+  }
+  return Math.max(0, Math.min(maxStart, start))
+}
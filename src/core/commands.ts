@@ -0,0 +1,75 @@
+import type { Node } from "./types"
+import type { GotoTarget } from "./goto"
+import { parseGotoTarget } from "./goto"
+import type { NodeFilter } from "./filter"
+import { parseFilter } from "./filter"
+
+// Startup commands (`--cmd ':filter error:true' --cmd ':goto turn:12'`), run
+// in order once the session is loaded, like vim's -c. A launch script of a
+// few of these sets up a review without any keys pressed.
+
+export const VIEWS = ["timeline", "list", "gantt"] as const
+export const LAYOUTS = ["details", "lanes", "files"] as const
+export const PANELS = ["details", "transcript", "mix", "tests", "tray", "keys"] as const
+
+export type StartupCommand =
+  | { kind: "filter"; filter: NodeFilter | null } // null clears
+  | { kind: "goto"; target: GotoTarget; spec: string }
+  | { kind: "view"; view: (typeof VIEWS)[number] }
+  | { kind: "layout"; zoom: (typeof LAYOUTS)[number] }
+  | { kind: "open"; panel: (typeof PANELS)[number] }
+  | { kind: "follow" }
+
+function oneOf<T extends string>(options: readonly T[], value: string): T | null {
+  return (options as readonly string[]).includes(value) ? value as T : null
+}
+
+// The leading ":" is optional
+export function parseCommand(text: string): StartupCommand | { error: string } {
+  const trimmed = text.trim().replace(/^:/, "")
+  const space = trimmed.search(/\s/)
+  const name = (space < 0 ? trimmed : trimmed.slice(0, space)).toLowerCase()
+  const arg = space < 0 ? "" : trimmed.slice(space + 1).trim()
+  switch (name) {
+    case "filter": {
+      if (!arg) return { kind: "filter", filter: null }
+      const filter = parseFilter(arg)
+      return "error" in filter ? { error: `:filter: ${filter.error}` } : { kind: "filter", filter }
+    }
+    case "goto": {
+      const target = parseGotoTarget(arg)
+      return target ? { kind: "goto", target, spec: arg } : { error: ":goto takes <uuid>, turn:N or time:HH:MM" }
+    }
+    case "view": {
+      const view = oneOf(VIEWS, arg)
+      return view ? { kind: "view", view } : { error: `:view takes ${VIEWS.join(", ")}` }
+    }
+    case "layout": {
+      const zoom = oneOf(LAYOUTS, arg)
+      return zoom ? { kind: "layout", zoom } : { error: `:layout takes ${LAYOUTS.join(", ")}` }
+    }
+    case "open": {
+      const panel = oneOf(PANELS, arg)
+      return panel ? { kind: "open", panel } : { error: `:open takes ${PANELS.join(", ")}` }
+    }
+    case "follow":
+      return { kind: "follow" }
+    default:
+      return { error: `unknown command: ${name ? `:${name}` : text} (try filter, goto, view, layout, open, follow)` }
+  }
+}
+
+// Where a target in the full session lands once filters and layouts hide
+// nodes: itself if shown, else the next shown node, else the last one before
+export function landingIndex(raw: Node[], shown: Node[], rawIdx: number): number | null {
+  const shownIdx = new Map(shown.map((n, i) => [n.id, i]))
+  for (let i = rawIdx; i < raw.length; i++) {
+    const idx = shownIdx.get(raw[i].id)
+    if (idx !== undefined) return idx
+  }
+  for (let i = rawIdx - 1; i >= 0; i--) {
+    const idx = shownIdx.get(raw[i].id)
+    if (idx !== undefined) return idx
+  }
+  return null
+}
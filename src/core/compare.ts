@@ -0,0 +1,111 @@
+import type { Graph, Node } from "./types"
+import { glyphs } from "../ui/glyphs"
+import { displayWidth, padToWidth, truncateToWidth } from "../ui/width"
+import type { SessionProvenance } from "./provenance"
+import { sourcesToJson } from "./provenance"
+
+// Tool failure rates side by side across sessions. Shell calls are split by
+// command, since "Bash" failing says little but "bun test" failing in every
+// session points at the environment.
+
+export type ToolComparison = {
+  key: string // tool name, or "Bash: <command>" for shell calls
+  calls: number
+  errors: number
+  sessions: number // sessions that used it
+  failingSessions: number // sessions where at least one call failed
+  history: ("ok" | "failed" | null)[] // per session, oldest first; null when unused
+}
+
+const SHELL_TOOLS = new Set(["bash", "shell"])
+
+// First two words of the command: "bun test", "git push", "npm run"
+function commandHead(input: string): string | null {
+  try {
+    const command = (JSON.parse(input) as { command?: unknown }).command
+    if (typeof command !== "string") return null
+    return command.trim().split(/\s+/).slice(0, 2).join(" ") || null
+  } catch {
+    return null
+  }
+}
+
+export function toolKey(node: Node): string | null {
+  const t = node.nodeType
+  if (t.kind !== "tool_call" || t.output === null) return null
+  if (!SHELL_TOOLS.has(t.name.toLowerCase())) return t.name
+  const head = commandHead(t.input)
+  return head ? `${t.name}: ${head}` : t.name
+}
+
+// Failing in at least two sessions and half of those that used it
+export function isConsistentFailure(c: ToolComparison): boolean {
+  return c.failingSessions >= 2 && c.failingSessions * 2 >= c.sessions
+}
+
+// graphs oldest first
+export function compareTools(graphs: Graph[]): ToolComparison[] {
+  const rows = new Map<string, ToolComparison>()
+  graphs.forEach((graph, s) => {
+    for (const node of graph.nodes) {
+      const key = toolKey(node)
+      if (!key || node.nodeType.kind !== "tool_call") continue
+      let row = rows.get(key)
+      if (!row) {
+        row = { key, calls: 0, errors: 0, sessions: 0, failingSessions: 0, history: graphs.map(() => null) }
+        rows.set(key, row)
+      }
+      row.calls++
+      if (row.history[s] === null) {
+        row.history[s] = "ok"
+        row.sessions++
+      }
+      if (node.nodeType.isError) {
+        row.errors++
+        if (row.history[s] !== "failed") row.failingSessions++
+        row.history[s] = "failed"
+      }
+    }
+  })
+  return [...rows.values()].sort((a, b) =>
+    b.failingSessions - a.failingSessions || b.errors / b.calls - a.errors / a.calls || b.calls - a.calls || a.key.localeCompare(b.key))
+}
+
+export function toolComparisonToJson(rows: ToolComparison[], sessionCount: number, sources: SessionProvenance[] = []): string {
+  return JSON.stringify({
+    schemaVersion: 1,
+    kind: "compare-tools",
+    sessions: sessionCount,
+    tools: rows.map(r => ({
+      tool: r.key,
+      calls: r.calls,
+      errors: r.errors,
+      failureRate: r.errors / r.calls,
+      sessions: r.sessions,
+      failingSessions: r.failingSessions,
+      consistent: isConsistentFailure(r),
+      history: r.history,
+    })),
+    sources: sourcesToJson(sources),
+  }, null, 2) + "\n"
+}
+
+function historyStrip(history: ToolComparison["history"]): string {
+  const g = glyphs()
+  return history.map(h => h === null ? g.toolSep : h === "ok" ? g.ok : g.error).join("")
+}
+
+export function formatToolComparison(rows: ToolComparison[], sessionCount: number): string {
+  if (rows.length === 0) return `No finished tool calls in the last ${sessionCount} session${sessionCount === 1 ? "" : "s"}\n`
+  const keyWidth = Math.min(40, Math.max(4, ...rows.map(r => displayWidth(r.key))))
+  const header = `${padToWidth("Tool", keyWidth)}  calls  errors   rate  failing  sessions (oldest first)`
+  const out = [`Tool failures across the last ${sessionCount} session${sessionCount === 1 ? "" : "s"}`, "", header]
+  for (const r of rows) {
+    const key = padToWidth(truncateToWidth(r.key, keyWidth), keyWidth)
+    const rate = `${Math.round(r.errors / r.calls * 100)}%`
+    const failing = `${r.failingSessions}/${r.sessions}`
+    const flag = isConsistentFailure(r) ? `  ${glyphs().warning} consistent` : ""
+    out.push(`${key}  ${String(r.calls).padStart(5)}  ${String(r.errors).padStart(6)}  ${rate.padStart(5)}  ${failing.padStart(7)}  ${historyStrip(r.history)}${flag}`)
+  }
+  return out.join("\n") + "\n"
+}
@@ -0,0 +1,29 @@
+import type { Node } from "./types"
+import { truncateToWidth } from "../ui/width"
+
+// Plain-language, single-line description of a node — no symbols or box
+// drawing, so it reads naturally through a screen reader
+export function describeNode(node: Node, maxLen = 200): string {
+  const clip = (s: string) => {
+    const clean = s.replace(/\s+/g, " ").trim()
+    return truncateToWidth(clean, maxLen, "...")
+  }
+  const t = node.nodeType
+  const who = node.agentId ? "Agent " : ""
+  switch (t.kind) {
+    case "user": return `${who}User: ${clip(t.text)}`
+    case "assistant": return `${who}Assistant: ${clip(t.text)}`
+    case "tool_use": return `${who}Tool ${t.name}, pending: ${clip(t.input)}`
+    case "tool_result": return `${who}Tool result, ${t.isError ? "error" : "ok"}: ${clip(t.output)}`
+    case "tool_call": {
+      const status = t.output === null ? "running" : t.isError ? "error" : "ok"
+      const repeats = node.repeatCount && node.repeatCount > 1 ? `, repeated ${node.repeatCount} times` : ""
+      return `${who}Tool ${t.name}, ${status}${repeats}: ${clip(t.input)}`
+    }
+    case "agent_start": return `Agent started: ${t.agentType}`
+    case "agent_end": return "Agent finished"
+    case "progress": return `${who}Progress: ${clip(t.text)}`
+    case "reasoning": return `${who}Reasoning: ${clip(t.text)}`
+    case "patch": return `Patch: ${t.files.length} files, ${clip(t.files.join(", "))}`
+  }
+}
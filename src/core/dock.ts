@@ -0,0 +1,30 @@
+// Where the focus pane (expanded view of the cursor node) is docked
+//   bottom — full-width strip under the timeline, resized by line count
+//   right  — column beside the timeline, resized by width
+//   off    — hidden
+export type FocusDock = "bottom" | "right" | "off"
+
+const DOCK_ORDER: FocusDock[] = ["bottom", "right", "off"]
+
+export const DEFAULT_FOCUS_LINES = 2
+export const DEFAULT_FOCUS_WIDTH = 48
+const MIN_FOCUS_WIDTH = 24
+const MIN_TIMELINE_WIDTH = 40 // what a right-docked pane must leave for the timeline
+
+export function isFocusDock(value: unknown): value is FocusDock {
+  return typeof value === "string" && (DOCK_ORDER as string[]).includes(value)
+}
+
+export function nextFocusDock(dock: FocusDock): FocusDock {
+  return DOCK_ORDER[(DOCK_ORDER.indexOf(dock) + 1) % DOCK_ORDER.length]
+}
+
+// Body lines of a bottom-docked pane: at least one, at most half the screen
+export function clampFocusLines(lines: number, termHeight: number): number {
+  return Math.max(1, Math.min(lines, Math.floor(termHeight / 2)))
+}
+
+// Width of a right-docked pane; narrow terminals get the minimum
+export function clampFocusWidth(width: number, termWidth: number): number {
+  return Math.max(MIN_FOCUS_WIDTH, Math.min(width, termWidth - MIN_TIMELINE_WIDTH))
+}
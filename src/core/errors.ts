@@ -0,0 +1,95 @@
+import type { Node } from "./types"
+import { getTurnBounds } from "./turns"
+import { formatClock } from "./time"
+import type { Provenance } from "./provenance"
+import { provenanceToJson } from "./provenance"
+
+// Post-mortem view of failed tool calls: what was run, what came back, and
+// whether the agent's next call to the same tool (same agent, same turn) worked
+
+export type RetryOutcome = "succeeded" | "failed" | "pending" | "none"
+
+export type ToolError = {
+  idx: number
+  name: string
+  input: string
+  output: string
+  retry: RetryOutcome
+  retryIdx: number | null
+}
+
+function nextSameTool(nodes: Node[], idx: number, name: string): number | null {
+  const { end } = getTurnBounds(nodes, idx)
+  const agentId = nodes[idx].agentId
+  for (let i = idx + 1; i < end; i++) {
+    const t = nodes[i].nodeType
+    if (t.kind === "tool_call" && t.name === name && nodes[i].agentId === agentId) return i
+  }
+  return null
+}
+
+export function analyzeErrors(nodes: Node[]): ToolError[] {
+  const errors: ToolError[] = []
+  nodes.forEach((node, idx) => {
+    const t = node.nodeType
+    if (t.kind !== "tool_call" || !t.isError) return
+    const retryIdx = nextSameTool(nodes, idx, t.name)
+    let retry: RetryOutcome = "none"
+    if (retryIdx !== null) {
+      const r = nodes[retryIdx].nodeType
+      if (r.kind === "tool_call") retry = r.output === null ? "pending" : r.isError ? "failed" : "succeeded"
+    }
+    errors.push({ idx, name: t.name, input: t.input, output: t.output ?? "", retry, retryIdx })
+  })
+  return errors
+}
+
+const RETRY_LABELS: Record<RetryOutcome, string> = {
+  succeeded: "retry succeeded",
+  failed: "retry failed",
+  pending: "retry running",
+  none: "not retried",
+}
+
+function indentLines(text: string, maxLines: number): string {
+  const lines = text.trim().split("\n")
+  const shown = lines.slice(0, maxLines).map(l => `      ${l}`)
+  if (lines.length > maxLines) shown.push(`      ... (${lines.length - maxLines} more lines)`)
+  return shown.join("\n")
+}
+
+// Stable shape for scripts: bump schemaVersion on any breaking change
+export function errorReportToJson(nodes: Node[], errors: ToolError[], sessionId: string, provenance?: Provenance): string {
+  return JSON.stringify({
+    schemaVersion: 1,
+    kind: "errors",
+    sessionId,
+    errors: errors.map(e => ({
+      index: e.idx,
+      time: new Date(nodes[e.idx].timestamp).toISOString(),
+      tool: e.name,
+      agentId: nodes[e.idx].agentId ?? null,
+      input: e.input,
+      output: e.output,
+      retry: e.retry,
+      retryIndex: e.retryIdx,
+    })),
+    provenance: provenance ? provenanceToJson(provenance) : null,
+  }, null, 2) + "\n"
+}
+
+export function formatErrorReport(nodes: Node[], errors: ToolError[], sessionId: string): string {
+  if (errors.length === 0) return `No failed tool calls in ${sessionId}\n`
+  const out = [`${errors.length} failed tool call${errors.length === 1 ? "" : "s"} in ${sessionId}`, ""]
+  errors.forEach((e, i) => {
+    const node = nodes[e.idx]
+    const agent = node.agentId ? ` [agent ${node.agentId.slice(0, 8)}]` : ""
+    out.push(`[${i + 1}] ${formatClock(node.timestamp)} ${e.name}${agent} (${RETRY_LABELS[e.retry]})`)
+    out.push("    input:")
+    out.push(indentLines(e.input, 5))
+    out.push("    error:")
+    out.push(indentLines(e.output || "(empty)", 10))
+    out.push("")
+  })
+  return out.join("\n")
+}
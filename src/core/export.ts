@@ -0,0 +1,173 @@
+import type { Node } from "./types"
+import type { TurnBounds } from "./turns"
+import { turnPrompt } from "./turns"
+import type { Provenance } from "./provenance"
+import { provenanceMarkdown, provenanceLine, provenanceToJson } from "./provenance"
+import { formatClock } from "./time"
+
+// Paste-sized exports of a single turn: the prompt, every reply, tool call
+// and agent step up to the next prompt
+
+// provenance: the transcript files the session was read from, when known
+export type TurnExportMeta = { sessionId: string; turn: number; provenance?: Provenance }
+
+const MAX_OUTPUT_CHARS = 2000
+
+// Fence longer than any backtick run inside the text, so content can't close it early
+function fence(text: string, lang = ""): string {
+  const longest = Math.max(0, ...(text.match(/`+/g) ?? []).map(m => m.length))
+  const ticks = "`".repeat(Math.max(3, longest + 1))
+  return `${ticks}${lang}\n${text}\n${ticks}`
+}
+
+function clipOutput(text: string): string {
+  if (text.length <= MAX_OUTPUT_CHARS) return text
+  return `${text.slice(0, MAX_OUTPUT_CHARS)}\n... (${text.length - MAX_OUTPUT_CHARS} more chars)`
+}
+
+function formatTime(ts: number): string {
+  return `${new Date(ts).toISOString().slice(0, 10)} ${formatClock(ts, { utc: true })}`
+}
+
+function nodeToMarkdown(node: Node): string | null {
+  const t = node.nodeType
+  const who = node.agentId ? "Agent " : ""
+  switch (t.kind) {
+    case "user": return `## ${who}User\n\n${t.text}`
+    case "assistant": return `## ${who}Assistant\n\n${t.text}`
+    case "reasoning": return t.text.split("\n").map(l => `> ${l}`).join("\n")
+    case "tool_use": return `### ${who}Tool: ${t.name}\n\n${fence(t.input, "json")}`
+    case "tool_result": return `### ${who}Tool result (${t.isError ? "error" : "ok"})\n\n${fence(clipOutput(t.output))}`
+    case "tool_call": {
+      const status = t.output === null ? "running" : t.isError ? "error" : "ok"
+      const parts = [`### ${who}Tool: ${t.name} (${status})`, fence(t.input, "json")]
+      if (t.output !== null) parts.push(fence(clipOutput(t.output)))
+      return parts.join("\n\n")
+    }
+    case "agent_start": return `_Agent started: ${t.agentType}_`
+    case "agent_end": return "_Agent finished_"
+    case "patch": return `_Patch ${t.hash.slice(0, 8)}: ${t.files.join(", ")}_`
+    case "progress": return null
+  }
+}
+
+export function turnToMarkdown(nodes: Node[], bounds: TurnBounds, meta: TurnExportMeta): string {
+  const turnNodes = nodes.slice(bounds.start, bounds.end)
+  const header = [`# Turn ${meta.turn}`, ""]
+  header.push(`Session: ${meta.sessionId}`)
+  if (turnNodes.length > 0) {
+    header.push(`Time: ${formatTime(turnNodes[0].timestamp)} - ${formatTime(turnNodes[turnNodes.length - 1].timestamp)}`)
+  }
+  const body = turnNodes.map(nodeToMarkdown).filter((s): s is string => s !== null)
+  if (meta.provenance) body.push(provenanceMarkdown(meta.provenance))
+  return [header.join("\n"), ...body].join("\n\n") + "\n"
+}
+
+export function turnToJson(nodes: Node[], bounds: TurnBounds, meta: TurnExportMeta): string {
+  const { provenance, ...rest } = meta
+  return JSON.stringify({
+    ...rest,
+    ...(provenance ? { provenance: provenanceToJson(provenance) } : {}),
+    nodes: nodes.slice(bounds.start, bounds.end),
+  }, null, 2) + "\n"
+}
+
+export type PinExportMeta = { sessionId: string; exportedAt: number; provenance?: Provenance }
+
+function pinTitle(node: Node): string {
+  const t = node.nodeType
+  switch (t.kind) {
+    case "user": return "User prompt"
+    case "assistant": return "Assistant reply"
+    case "reasoning": return "Reasoning"
+    case "tool_use": return `Tool: ${t.name} (pending)`
+    case "tool_result": return `Tool result (${t.isError ? "error" : "ok"})`
+    case "tool_call": return `Tool: ${t.name} (${t.output === null ? "running" : t.isError ? "error" : "ok"})`
+    case "agent_start": return `Agent started: ${t.agentType}`
+    case "agent_end": return "Agent finished"
+    case "patch": return `Patch ${t.hash.slice(0, 8)}`
+    case "progress": return "Progress"
+  }
+}
+
+function pinBody(node: Node): string[] {
+  const t = node.nodeType
+  switch (t.kind) {
+    case "user": case "assistant": case "reasoning": case "progress": return [t.text]
+    case "tool_use": return ["Input:", fence(t.input, "json")]
+    case "tool_result": return ["Output:", fence(clipOutput(t.output))]
+    case "tool_call": return t.output === null
+      ? ["Input:", fence(t.input, "json")]
+      : ["Input:", fence(t.input, "json"), "Output:", fence(clipOutput(t.output))]
+    case "agent_start": case "agent_end": return [`Agent: ${t.agentId}`]
+    case "patch": return [t.files.map(f => `- ${f}`).join("\n")]
+  }
+}
+
+function pinMetadata(nodes: Node[], node: Node): string {
+  const idx = nodes.indexOf(node)
+  const turn = idx >= 0 ? turnPrompt(nodes, idx)?.turn : undefined
+  const lines = [`- Time: ${formatTime(node.timestamp)}`, `- Node: ${node.id}`]
+  if (turn !== undefined) lines.push(`- Turn: ${turn}`)
+  if (node.agentId) lines.push(`- Agent: ${node.agentId}`)
+  if (node.model) lines.push(`- Model: ${node.model}`)
+  const u = node.usage
+  if (u) lines.push(`- Tokens: in:${u.input_tokens ?? 0} out:${u.output_tokens ?? 0} cache_read:${u.cache_read_input_tokens ?? 0}`)
+  if (node.repeatCount && node.repeatCount > 1) lines.push(`- Repeated: ${node.repeatCount} times`)
+  return lines.join("\n")
+}
+
+// Post-mortem notes from the comparison tray: every pinned node, oldest pin
+// first, with where it sat in the session and its full content
+export function pinsToMarkdown(nodes: Node[], pinned: Node[], meta: PinExportMeta): string {
+  const header = ["# Pinned nodes", "", `Session: ${meta.sessionId}`, `Exported: ${formatTime(meta.exportedAt)}`].join("\n")
+  const sections = pinned.map((node, i) =>
+    [`## ${i + 1}. ${pinTitle(node)}`, pinMetadata(nodes, node), ...pinBody(node)].join("\n\n"))
+  if (meta.provenance) sections.push(provenanceMarkdown(meta.provenance))
+  return [header, ...sections].join("\n\n") + "\n"
+}
+
+// Fork: the conversation up to a node, for seeding a fresh session that tries
+// a different instruction from there. Only the main session: a subagent's
+// work reaches the parent as its Task result, which is kept.
+
+export type ForkMeta = { sessionId: string; exportedAt: number; provenance?: Provenance }
+
+function forkNodes(nodes: Node[], idx: number): Node[] {
+  return nodes.slice(0, idx + 1).filter(n => {
+    if (n.agentId) return false
+    const kind = n.nodeType.kind
+    return kind === "user" || kind === "assistant" || kind === "tool_call"
+  })
+}
+
+export function forkToMarkdown(nodes: Node[], idx: number, meta: ForkMeta): string {
+  const target = nodes[idx]
+  const header = [
+    "# Earlier conversation",
+    "",
+    `This is session ${meta.sessionId} up to node ${target?.id ?? "?"}${target ? `, ${formatTime(target.timestamp)}` : ""}.`,
+    "Treat it as the history so far and don't repeat its steps. My next instruction follows it.",
+    ...(meta.provenance ? ["", provenanceLine(meta.provenance)] : []),
+  ].join("\n")
+  const body = forkNodes(nodes, idx).map(nodeToMarkdown).filter((s): s is string => s !== null)
+  return [header, ...body, "---\n\n## Next instruction\n"].join("\n\n")
+}
+
+export function forkToJson(nodes: Node[], idx: number, meta: ForkMeta): string {
+  const messages = forkNodes(nodes, idx).flatMap(n => {
+    const t = n.nodeType
+    if (t.kind === "tool_call") return [{ role: "tool", name: t.name, input: t.input, output: t.output, isError: t.isError }]
+    if (t.kind === "user" || t.kind === "assistant") return [{ role: t.kind, text: t.text }]
+    return []
+  })
+  return JSON.stringify({
+    schemaVersion: 1,
+    kind: "fork",
+    sessionId: meta.sessionId,
+    nodeId: nodes[idx]?.id ?? null,
+    exportedAt: new Date(meta.exportedAt).toISOString(),
+    ...(meta.provenance ? { provenance: provenanceToJson(meta.provenance) } : {}),
+    messages,
+  }, null, 2) + "\n"
+}
@@ -0,0 +1,59 @@
+import type { Node } from "./types"
+import { describeNode } from "./describe"
+
+// Recent activity across every watched project, newest first, for people
+// running agents in several repos at once. Only what's worth a glance makes
+// a line: prompts, replies, finished tool calls and subagents starting.
+
+export const FEED_WINDOW_MS = 30 * 60_000
+export const FEED_REFRESH_MS = 2000 // for sources without watchable directories
+export const FEED_HEIGHT = 8 // header plus entries
+
+export type FeedItem = {
+  project: string // directory name
+  sessionId: string
+  nodeId: string
+  timestamp: number
+  text: string
+  error: boolean
+}
+
+function isFeedWorthy(node: Node): boolean {
+  const t = node.nodeType
+  switch (t.kind) {
+    case "user":
+    case "assistant":
+    case "agent_start":
+      return true
+    case "tool_call":
+      return t.output !== null
+    default:
+      return false
+  }
+}
+
+// A tool call counts when its result arrived, which is when it became news
+function feedTime(node: Node): number {
+  return node.nodeType.kind === "tool_call" ? node.endTimestamp ?? node.timestamp : node.timestamp
+}
+
+export function feedItems(project: string, sessionId: string, nodes: Node[], since: number, maxLen = 120): FeedItem[] {
+  const items: FeedItem[] = []
+  for (const node of nodes) {
+    if (!isFeedWorthy(node) || feedTime(node) < since) continue
+    const t = node.nodeType
+    items.push({
+      project,
+      sessionId,
+      nodeId: node.id,
+      timestamp: feedTime(node),
+      text: describeNode(node, maxLen),
+      error: t.kind === "tool_call" && t.isError,
+    })
+  }
+  return items
+}
+
+export function mergeFeed(lists: FeedItem[][], limit: number): FeedItem[] {
+  return lists.flat().sort((a, b) => b.timestamp - a.timestamp).slice(0, limit)
+}
@@ -0,0 +1,59 @@
+import type { Graph, Node } from "./types"
+import { isTurnStart } from "./turns"
+import { toolLane } from "./lanes"
+
+// File swimlanes: a timeline row per file the session modified, most-edited
+// first, so a refactor reads as "which files were worked on when". Reads of
+// those files share their row; everything else touching a file goes to a
+// final "other" row, and prompts stay on row 0 for context.
+
+export const FILE_LANE_LIMIT = 8
+export const OTHER_FILES = "other"
+
+const PATH_KEYS = ["file_path", "path", "notebook_path", "filePath"]
+
+export function touchedFile(node: Node): string | null {
+  const t = node.nodeType
+  if (t.kind !== "tool_call" && t.kind !== "tool_use") return null
+  try {
+    const input = JSON.parse(t.input) as Record<string, unknown>
+    for (const key of PATH_KEYS) {
+      if (typeof input?.[key] === "string" && input[key]) return input[key] as string
+    }
+  } catch { /* not JSON */ }
+  return null
+}
+
+function modifies(node: Node): boolean {
+  const t = node.nodeType
+  return (t.kind === "tool_call" || t.kind === "tool_use") && toolLane(t.name) === "Write"
+}
+
+// Files by modification count, ties broken by first modification
+export function rankModifiedFiles(nodes: Node[]): string[] {
+  const edits = new Map<string, number>()
+  for (const node of nodes) {
+    const file = modifies(node) ? touchedFile(node) : null
+    if (file) edits.set(file, (edits.get(file) ?? 0) + 1)
+  }
+  return [...edits.entries()].sort((a, b) => b[1] - a[1]).map(([file]) => file)
+}
+
+// Sets fileLane on every node (-1 when it has no row) and the row labels
+export function applyFileLanes(graph: Graph, limit = FILE_LANE_LIMIT): Graph {
+  const files = rankModifiedFiles(graph.nodes).slice(0, limit)
+  const row = new Map(files.map((f, i) => [f, i + 1]))
+  const otherRow = files.length + 1
+  let hasOther = false
+  const nodes = graph.nodes.map(node => {
+    const file = touchedFile(node)
+    let fileLane = -1
+    if (isTurnStart(node)) fileLane = 0
+    else if (file) {
+      fileLane = row.get(file) ?? otherRow
+      if (fileLane === otherRow) hasOther = true
+    }
+    return { ...node, fileLane }
+  })
+  return { ...graph, nodes, fileLanes: hasOther ? [...files, OTHER_FILES] : files }
+}
@@ -0,0 +1,189 @@
+import type { Node } from "./types"
+import { isBuildNode } from "./builds"
+import { touchedFile } from "./files"
+import { minuteOfDay } from "./time"
+
+// Node filter expressions, e.g. `type:tool name:Bash error:true after:14:00
+// agent:researcher build:true`. Terms are ANDed; a leading "-" negates one; a bare word
+// matches node text. Double quotes keep spaces in a value or a phrase
+// ("not found", file:"my notes.md"); inside them \" is a quote and \\ a
+// backslash. Parsing keeps the text so the header can show it.
+
+export type FilterTerm =
+  | { key: "type"; value: string; negate: boolean }
+  | { key: "name"; value: RegExp; negate: boolean }
+  | { key: "error" | "build"; value: boolean; negate: boolean }
+  | { key: "after" | "before"; value: number; negate: boolean } // minutes since local midnight
+  | { key: "agent"; value: string; negate: boolean }
+  | { key: "file"; value: string; negate: boolean }
+  | { key: "text"; value: string; negate: boolean }
+
+export type NodeFilter = { text: string; terms: FilterTerm[] }
+
+// Agent types by agentId, for agent:<type>; "main" is the main session
+export type FilterContext = { agentTypes: Map<string, string> }
+
+const TYPES: Record<string, Node["nodeType"]["kind"][]> = {
+  user: ["user"],
+  assistant: ["assistant"],
+  asst: ["assistant"],
+  tool: ["tool_call", "tool_use"],
+  result: ["tool_result"],
+  agent: ["agent_start", "agent_end"],
+  progress: ["progress"],
+  reasoning: ["reasoning"],
+  patch: ["patch"],
+}
+
+// "Bash", "mcp__github__*": case-insensitive, * matches anything
+function namePattern(glob: string): RegExp {
+  const escaped = glob.split("*").map(p => p.replace(/[.+?^${}()|[\]\\]/g, "\\$&")).join(".*")
+  return new RegExp(`^${escaped}$`, "i")
+}
+
+function unquote(text: string): string {
+  if (text.length < 2 || !text.startsWith('"') || !text.endsWith('"')) return text
+  return text.slice(1, -1).replace(/\\(["\\])/g, "$1")
+}
+
+function parseTerm(word: string): FilterTerm | string {
+  const negate = word.startsWith("-") && word.length > 1
+  const body = negate ? word.slice(1) : word
+  const colon = body.indexOf(":")
+  if (colon <= 0 || body.startsWith('"')) return { key: "text", value: unquote(body).toLowerCase(), negate }
+  const key = body.slice(0, colon).toLowerCase()
+  const value = unquote(body.slice(colon + 1))
+  if (!value) return `missing value for ${key}:`
+  switch (key) {
+    case "type":
+      return TYPES[value.toLowerCase()] ? { key, value: value.toLowerCase(), negate } : `unknown type: ${value} (try ${Object.keys(TYPES).join(", ")})`
+    case "name":
+    case "tool":
+      return { key: "name", value: namePattern(value), negate }
+    case "error":
+    case "build":
+      if (value !== "true" && value !== "false") return `${key}: takes true or false`
+      return { key, value: value === "true", negate }
+    case "after":
+    case "before": {
+      const m = /^(\d{1,2}):(\d{2})$/.exec(value)
+      if (!m || Number(m[1]) > 23 || Number(m[2]) > 59) return `${key}: takes a time like 14:00`
+      return { key, value: Number(m[1]) * 60 + Number(m[2]), negate }
+    }
+    case "agent":
+      return { key, value: value.toLowerCase(), negate }
+    case "file":
+      return { key, value, negate }
+    default:
+      return `unknown filter: ${key}: (try type, name, error, build, after, before, agent, file)`
+  }
+}
+
+// A filter, or a message saying what's wrong with the expression
+export function parseFilter(text: string): NodeFilter | { error: string } {
+  const terms: FilterTerm[] = []
+  for (const word of text.match(/-?(?:\w+:)?"(?:[^"\\]|\\.)*"|\S+/g) ?? []) {
+    const term = parseTerm(word)
+    if (typeof term === "string") return { error: term }
+    terms.push(term)
+  }
+  return { text: text.trim(), terms }
+}
+
+// Subagent types come from agent_start nodes or the spawning Task call's input
+export function filterContext(nodes: Node[]): FilterContext {
+  const agentTypes = new Map<string, string>()
+  const byId = new Map(nodes.map(n => [n.id, n]))
+  for (const node of nodes) {
+    const t = node.nodeType
+    if (t.kind === "agent_start") agentTypes.set(t.agentId, t.agentType)
+    if (!node.agentId || agentTypes.has(node.agentId) || !node.parentId) continue
+    const task = byId.get(node.parentId)?.nodeType
+    if (task?.kind !== "tool_call" && task?.kind !== "tool_use") continue
+    try {
+      const type = (JSON.parse(task.input) as { subagent_type?: unknown }).subagent_type
+      if (typeof type === "string") agentTypes.set(node.agentId, type)
+    } catch { /* not JSON */ }
+  }
+  return { agentTypes }
+}
+
+function nodeText(node: Node): string {
+  const t = node.nodeType
+  switch (t.kind) {
+    case "user": case "assistant": case "progress": case "reasoning": return t.text
+    case "tool_use": return `${t.name} ${t.input}`
+    case "tool_call": return `${t.name} ${t.input} ${t.output ?? ""}`
+    case "tool_result": return t.output
+    case "agent_start": return t.agentType
+    case "agent_end": return ""
+    case "patch": return t.files.join(" ")
+  }
+}
+
+function termMatches(term: FilterTerm, node: Node, ctx: FilterContext): boolean {
+  const t = node.nodeType
+  switch (term.key) {
+    case "type": return TYPES[term.value].includes(t.kind)
+    case "name": return (t.kind === "tool_call" || t.kind === "tool_use") && term.value.test(t.name)
+    case "error": return ((t.kind === "tool_call" || t.kind === "tool_result") && t.isError) === term.value
+    case "build": return isBuildNode(node) === term.value
+    case "after": return minuteOfDay(node.timestamp) >= term.value
+    case "before": return minuteOfDay(node.timestamp) < term.value
+    case "agent": {
+      if (!node.agentId) return term.value === "main"
+      const type = ctx.agentTypes.get(node.agentId)?.toLowerCase() ?? ""
+      return type.includes(term.value) || node.agentId.toLowerCase().startsWith(term.value)
+    }
+    case "file": {
+      const file = touchedFile(node)
+      return file !== null && (file === term.value || file.endsWith(`/${term.value}`))
+    }
+    case "text": return nodeText(node).toLowerCase().includes(term.value)
+  }
+}
+
+export function matchesFilter(node: Node, filter: NodeFilter, ctx: FilterContext): boolean {
+  return filter.terms.every(term => termMatches(term, node, ctx) !== term.negate)
+}
+
+const ERROR_SNIPPET_CHARS = 60
+
+function quote(value: string): string {
+  return `"${value.replace(/["\\]/g, "\\$&")}"`
+}
+
+function quoted(value: string): string {
+  return /[\s"]/.test(value) ? quote(value) : value
+}
+
+// First line of a failure, as a phrase
+function errorSnippet(node: Node): string | null {
+  const t = node.nodeType
+  if ((t.kind !== "tool_call" && t.kind !== "tool_result") || !t.isError || !t.output) return null
+  const line = t.output.split("\n").map(l => l.trim()).find(Boolean) ?? ""
+  const snippet = line.slice(0, ERROR_SNIPPET_CHARS).trim()
+  return snippet || null
+}
+
+// "Filter like this": expressions matching nodes like the given one, in the
+// order * cycles through them: same tool, same file, same agent, same error
+export function quickFilters(node: Node): string[] {
+  const t = node.nodeType
+  const out: string[] = []
+  if (t.kind === "tool_call" || t.kind === "tool_use") out.push(`name:${quoted(t.name)}`)
+  const file = touchedFile(node)
+  if (file) out.push(`file:${quoted(file)}`)
+  out.push(`agent:${node.agentId ?? "main"}`)
+  const error = errorSnippet(node)
+  if (error) out.push(`error:true ${quote(error)}`)
+  return out
+}
+
+// The one after the active filter when it's one of them, the first otherwise;
+// null past the last, which clears the filter
+export function nextQuickFilter(node: Node, active?: string): string | null {
+  const options = quickFilters(node)
+  const i = active === undefined ? -1 : options.indexOf(active)
+  return options[i + 1] ?? null
+}
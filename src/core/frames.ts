@@ -0,0 +1,54 @@
+import type { Graph } from "./types"
+import { nodeStats } from "./stats"
+
+// A "frame" is the session as it stood after its first N nodes; "last" is the
+// whole session. Used to render past states deterministically.
+export type Frame = number | "last"
+
+export function parseFrame(value: string): Frame | null {
+  if (value === "last") return "last"
+  const n = Number(value)
+  return Number.isInteger(n) && n >= 0 ? n : null
+}
+
+export function graphAtFrame(graph: Graph, frame: Frame): Graph {
+  if (frame === "last" || frame >= graph.nodes.length) return graph
+  const nodes = graph.nodes.slice(0, frame)
+  const ids = new Set(nodes.map(n => n.id))
+  const edges = graph.edges.filter(e => ids.has(e.from) && ids.has(e.to))
+  return { nodes, edges, stats: nodeStats(nodes) }
+}
+
+// The session as it stood at `time` (epoch ms): later nodes are gone and tool
+// calls whose results hadn't arrived yet are pending again, so the view shows
+// what the agent knew when it made a decision
+export function graphAtTime(graph: Graph, time: number): Graph {
+  const nodes = graph.nodes.filter(n => n.timestamp <= time).map(n => {
+    const t = n.nodeType
+    if (t.kind !== "tool_call" || n.endTimestamp === undefined || n.endTimestamp <= time) return n
+    return { ...n, endTimestamp: undefined, nodeType: { ...t, output: null, isError: false } }
+  })
+  if (nodes.length === graph.nodes.length && nodes.every((n, i) => n === graph.nodes[i])) return graph
+  const ids = new Set(nodes.map(n => n.id))
+  const edges = graph.edges.filter(e => ids.has(e.from) && ids.has(e.to))
+  return { nodes, edges, stats: nodeStats(nodes), skipped: graph.skipped }
+}
+
+// Every moment the picture changes — a node appearing or a tool returning — ascending
+export function eventTimes(graph: Graph): number[] {
+  const times = new Set<number>()
+  for (const n of graph.nodes) {
+    times.add(n.timestamp)
+    if (n.endTimestamp !== undefined) times.add(n.endTimestamp)
+  }
+  return [...times].sort((a, b) => a - b)
+}
+
+// `steps` events later (or earlier when negative) than `time`, stopping at the ends
+export function stepTime(times: number[], time: number, steps: number): number {
+  if (times.length === 0) return time
+  let idx = times.findIndex(t => t >= time)
+  if (idx < 0) idx = times.length
+  else if (times[idx] !== time && steps > 0) idx-- // between events: the first step lands on the next one
+  return times[Math.max(0, Math.min(times.length - 1, idx + steps))]
+}
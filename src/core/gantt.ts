@@ -0,0 +1,90 @@
+import type { Node } from "./types"
+
+// Gantt bars: every tool call and subagent as a span from start to end, one
+// per row in start order, so overlapping work and the long poles of a
+// session line up against a shared time axis.
+
+export type GanttBar = {
+  nodeIdx: number // tool call, or the subagent's first node
+  kind: "tool" | "agent"
+  label: string
+  start: number
+  end: number
+  running: boolean
+  isError: boolean
+  depth: number // 0 main session, otherwise the agent's branch level
+}
+
+function taskLabel(call: Node | undefined): string | null {
+  if (call?.nodeType.kind !== "tool_call") return null
+  try {
+    const input = JSON.parse(call.nodeType.input) as { description?: unknown; subagent_type?: unknown }
+    if (typeof input.description === "string") return input.description
+    if (typeof input.subagent_type === "string") return input.subagent_type
+  } catch { /* not JSON */ }
+  return null
+}
+
+// Running tools and agents extend to now
+export function buildGanttBars(nodes: Node[], now: number): GanttBar[] {
+  const bars: GanttBar[] = []
+  const agents = new Map<string, GanttBar>()
+  const byId = new Map(nodes.map(n => [n.id, n]))
+  nodes.forEach((node, idx) => {
+    const t = node.nodeType
+    const end = t.kind === "tool_call" ? node.endTimestamp ?? (t.output === null ? now : node.timestamp) : node.timestamp
+    if (node.agentId) {
+      const agent = agents.get(node.agentId)
+      if (!agent) {
+        // The Task call that spawned the agent says whether it's still going
+        const task = node.parentId ? byId.get(node.parentId) : undefined
+        const call = task?.nodeType.kind === "tool_call" ? task.nodeType : null
+        const bar: GanttBar = {
+          nodeIdx: idx,
+          kind: "agent",
+          label: `agent: ${taskLabel(task) ?? node.agentId.slice(0, 8)}`,
+          start: node.timestamp,
+          end: call?.output === null ? now : end,
+          running: call?.output === null,
+          isError: call?.isError ?? false,
+          depth: node.branchLevel,
+        }
+        agents.set(node.agentId, bar)
+        bars.push(bar)
+      } else {
+        agent.end = Math.max(agent.end, end)
+      }
+    }
+    if (t.kind !== "tool_call") return
+    bars.push({
+      nodeIdx: idx,
+      kind: "tool",
+      label: t.name,
+      start: node.timestamp,
+      end,
+      running: t.output === null,
+      isError: t.isError,
+      depth: node.branchLevel,
+    })
+  })
+  return bars.sort((a, b) => a.start - b.start || a.nodeIdx - b.nodeIdx)
+}
+
+// The bar to keep in view for the cursor: its own, or the latest one before it
+export function barForNode(bars: GanttBar[], nodeIdx: number): number {
+  const own = bars.findIndex(b => b.nodeIdx === nodeIdx)
+  if (own >= 0) return own
+  let best = -1
+  bars.forEach((b, i) => {
+    if (b.nodeIdx < nodeIdx && (best < 0 || b.nodeIdx > bars[best].nodeIdx)) best = i
+  })
+  return best
+}
+
+// Columns a bar covers on a width-wide axis over [from, to]; at least one
+export function barColumns(bar: GanttBar, from: number, to: number, width: number): { start: number; length: number } {
+  const span = Math.max(1, to - from)
+  const start = Math.min(width - 1, Math.max(0, Math.floor((bar.start - from) / span * width)))
+  const end = Math.min(width, Math.max(start + 1, Math.ceil((bar.end - from) / span * width)))
+  return { start, length: end - start }
+}
@@ -0,0 +1,52 @@
+import type { Node } from "./types"
+import { minuteOfDay } from "./time"
+
+// Deep-link targets: a node id (or unique prefix), the Nth user turn, or a wall-clock time
+export type GotoTarget =
+  | { kind: "id"; id: string }
+  | { kind: "turn"; turn: number }
+  | { kind: "time"; minutes: number } // minutes since local midnight
+
+export function parseGotoTarget(spec: string): GotoTarget | null {
+  const s = spec.trim()
+  if (!s) return null
+  const turn = s.match(/^turn:(\d+)$/i)
+  if (turn) {
+    const n = Number(turn[1])
+    return n >= 1 ? { kind: "turn", turn: n } : null
+  }
+  const time = s.match(/^time:(\d{1,2}):(\d{2})$/i)
+  if (time) {
+    const h = Number(time[1])
+    const m = Number(time[2])
+    if (h > 23 || m > 59) return null
+    return { kind: "time", minutes: h * 60 + m }
+  }
+  if (/^(turn|time):/i.test(s)) return null
+  return { kind: "id", id: s }
+}
+
+// Returns the index into nodes, or null when nothing matches
+export function resolveGotoTarget(nodes: Node[], target: GotoTarget): number | null {
+  switch (target.kind) {
+    case "id": {
+      const exact = nodes.findIndex(n => n.id === target.id)
+      if (exact >= 0) return exact
+      const prefixed = nodes.findIndex(n => n.id.startsWith(target.id))
+      return prefixed >= 0 ? prefixed : null
+    }
+    case "turn": {
+      let count = 0
+      for (let i = 0; i < nodes.length; i++) {
+        const n = nodes[i]
+        if (n.branchLevel === 0 && n.nodeType.kind === "user" && ++count === target.turn) return i
+      }
+      return null
+    }
+    case "time": {
+      // First node at or after the requested minute
+      const idx = nodes.findIndex(n => minuteOfDay(n.timestamp) >= target.minutes)
+      return idx >= 0 ? idx : null
+    }
+  }
+}
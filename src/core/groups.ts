@@ -0,0 +1,24 @@
+// Tool categories from config: { "search": ["Read", "Grep", "Glob"] }. Names
+// match case-insensitively; a trailing * matches a prefix, so "mcp__github__*"
+// takes every tool of one MCP server. Ungrouped tools keep their own name.
+
+export type ToolGroups = Record<string, string[]>
+
+function matches(pattern: string, name: string): boolean {
+  const p = pattern.toLowerCase()
+  const n = name.toLowerCase()
+  return p.endsWith("*") ? n.startsWith(p.slice(0, -1)) : n === p
+}
+
+// First group listing the tool, in config order
+export function toolGroupOf(name: string, groups: ToolGroups): string | null {
+  for (const [group, tools] of Object.entries(groups)) {
+    if (Array.isArray(tools) && tools.some(t => typeof t === "string" && matches(t, name))) return group
+  }
+  return null
+}
+
+// The name stats are aggregated under
+export function toolLabel(name: string, groups: ToolGroups): string {
+  return toolGroupOf(name, groups) ?? name
+}
@@ -0,0 +1,28 @@
+import type { Node } from "./types"
+
+// Token-weighted coloring: assistant nodes bucketed by output tokens against
+// the session's own distribution, so a verbose reply stands out whatever the
+// model's usual length is.
+
+export type HeatLevel = 0 | 1 | 2 | 3 // below median, above p50, above p75, above p90
+
+const THRESHOLDS = [0.5, 0.75, 0.9]
+
+function quantile(sorted: number[], q: number): number {
+  return sorted[Math.floor(q * (sorted.length - 1))]
+}
+
+function outputTokens(node: Node): number | null {
+  if (node.nodeType.kind !== "assistant") return null
+  return node.usage?.output_tokens ?? null
+}
+
+// One entry per node; null for nodes that aren't assistant replies with usage.
+// Strict comparisons keep a flat distribution at level 0 instead of all hot.
+export function tokenHeat(nodes: Node[]): (HeatLevel | null)[] {
+  const values = nodes.map(outputTokens)
+  const sorted = values.filter((v): v is number => v !== null).sort((a, b) => a - b)
+  if (sorted.length === 0) return values.map(() => null)
+  const cuts = THRESHOLDS.map(q => quantile(sorted, q))
+  return values.map(v => v === null ? null : cuts.filter(c => v > c).length as HeatLevel)
+}
@@ -0,0 +1,26 @@
+import type { Graph } from "./types"
+
+// String interning for low-cardinality, high-repetition values (tool names,
+// model ids). JSON.parse allocates a fresh string per occurrence; routing
+// them through the pool lets every node share one copy across reloads.
+const pool = new Map<string, string>()
+
+export function intern<T extends string | undefined>(value: T): T {
+  if (value === undefined) return value
+  const existing = pool.get(value)
+  if (existing !== undefined) return existing as T
+  pool.set(value, value)
+  return value
+}
+
+// A graph posted from another thread arrives with its own copy of every
+// string, whatever was interned over there, so the receiver pools them again
+export function internGraph(graph: Graph): Graph {
+  for (const node of graph.nodes) {
+    if (node.model) node.model = intern(node.model)
+    if (node.agentId) node.agentId = intern(node.agentId)
+    const t = node.nodeType
+    if (t.kind === "tool_call" || t.kind === "tool_use") t.name = intern(t.name)
+  }
+  return graph
+}
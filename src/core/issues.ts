@@ -0,0 +1,77 @@
+import type { Node, SessionStats } from "./types"
+import { isTurnStart } from "./turns"
+import { rankModifiedFiles } from "./files"
+import { formatTokens, totalTokens } from "./stats"
+import { formatDuration, formatClock } from "./time"
+import { describeNode } from "./describe"
+import { shortSessionId } from "./sessions"
+import type { Provenance } from "./provenance"
+import { provenanceLine } from "./provenance"
+
+// A comment-ready summary for an issue tracker: what the session was asked,
+// what it changed and where it failed, plus the issue links from the
+// session's note. Short enough to paste into a GitHub or Jira comment.
+
+export type IssueLink = { url: string; label: string }
+
+export type IssueCommentMeta = {
+  sessionId: string
+  note?: string // the session's note, where issue URLs are picked up
+  pinned?: Node[] // always listed as key nodes
+  provenance?: Provenance
+}
+
+const MAX_PROMPTS = 5
+const MAX_FAILURES = 5
+const MAX_FILES = 10
+const LINE_CHARS = 100
+
+// owner/repo#12 for GitHub issues and PRs, ABC-12 for Jira, the URL otherwise
+export function issueLabel(url: string): string {
+  const github = /github\.com\/([^/]+\/[^/]+)\/(?:issues|pull)\/(\d+)/.exec(url)
+  if (github) return `${github[1]}#${github[2]}`
+  const jira = /\/browse\/([A-Z][A-Z0-9]+-\d+)/.exec(url)
+  if (jira) return jira[1]
+  return url
+}
+
+export function findIssueLinks(text: string): IssueLink[] {
+  const urls = (text.match(/https?:\/\/[^\s<>()]+/g) ?? []).map(url => url.replace(/[.,;:!?'"]+$/, ""))
+  return [...new Set(urls)].map(url => ({ url, label: issueLabel(url) }))
+}
+
+// Notes without their URLs, which get their own section
+function noteText(note: string): string {
+  return note.replace(/https?:\/\/[^\s<>()]+/g, "").replace(/\s+/g, " ").trim()
+}
+
+export function issueComment(nodes: Node[], stats: SessionStats, meta: IssueCommentMeta): string {
+  const shortId = shortSessionId(meta.sessionId)
+  const out: string[] = []
+  const span = nodes.length > 0 ? ` over ${formatDuration(nodes[nodes.length - 1].timestamp - nodes[0].timestamp)}` : ""
+  out.push(`**Agent session \`${shortId}\`**${span}`)
+  const note = meta.note ? noteText(meta.note) : ""
+  if (note) out.push("", note)
+
+  const prompts = nodes.filter(isTurnStart)
+  const calls = nodes.filter(n => n.nodeType.kind === "tool_call")
+  const failed = calls.filter(n => n.nodeType.kind === "tool_call" && n.nodeType.isError)
+  const files = rankModifiedFiles(nodes)
+  out.push("")
+  out.push(`- Prompts: ${prompts.length}, tool calls: ${calls.length} (${failed.length} failed), tokens: ${formatTokens(totalTokens(stats))}`)
+  if (files.length > 0) {
+    const more = files.length > MAX_FILES ? `, and ${files.length - MAX_FILES} more` : ""
+    out.push(`- Files changed: ${files.slice(0, MAX_FILES).map(f => `\`${f}\``).join(", ")}${more}`)
+  }
+
+  const key = new Set<Node>([...prompts.slice(0, MAX_PROMPTS), ...failed.slice(-MAX_FAILURES), ...(meta.pinned ?? [])])
+  const last = [...nodes].reverse().find(n => n.nodeType.kind === "assistant" && !n.agentId)
+  if (last) key.add(last)
+  const lines = nodes.filter(n => key.has(n)).map(n => `- ${formatClock(n.timestamp, { seconds: false })} ${describeNode(n, LINE_CHARS)}`)
+  if (lines.length > 0) out.push("", "**Key steps**", "", ...lines)
+
+  const links = meta.note ? findIssueLinks(meta.note) : []
+  if (links.length > 0) out.push("", "**Links**", "", ...links.map(l => `- [${l.label}](${l.url})`))
+  if (meta.provenance) out.push("", `<sub>${provenanceLine(meta.provenance)}</sub>`)
+  return out.join("\n") + "\n"
+}
@@ -0,0 +1,34 @@
+// Vim-style jump list: where the cursor was before each long jump, by node id
+// so entries survive the graph being re-read. `pos` is the entry the cursor
+// is on while stepping through history, or entries.length when it isn't.
+
+export const MAX_JUMPS = 100
+
+export type JumpList = { entries: string[]; pos: number }
+
+export const EMPTY_JUMPS: JumpList = { entries: [], pos: 0 }
+
+// Record the position being jumped away from; drops anything ahead of it
+export function recordJump(list: JumpList, fromId: string): JumpList {
+  const entries = list.entries.slice(0, list.pos)
+  if (entries[entries.length - 1] !== fromId) entries.push(fromId)
+  const kept = entries.slice(-MAX_JUMPS)
+  return { entries: kept, pos: kept.length }
+}
+
+// Ctrl-o. Leaving the newest position keeps it as an entry so forward can return to it.
+export function jumpBack(list: JumpList, currentId: string): { list: JumpList; target: string } | null {
+  let { entries, pos } = list
+  if (pos >= entries.length) {
+    if (entries[entries.length - 1] !== currentId) entries = [...entries, currentId]
+    pos = entries.length - 1
+  }
+  if (pos === 0) return null
+  return { list: { entries, pos: pos - 1 }, target: entries[pos - 1] }
+}
+
+// Ctrl-n
+export function jumpForward(list: JumpList): { list: JumpList; target: string } | null {
+  if (list.pos >= list.entries.length - 1) return null
+  return { list: { entries: list.entries, pos: list.pos + 1 }, target: list.entries[list.pos + 1] }
+}
@@ -0,0 +1,171 @@
+// Every key binding of the TUI in one table, so the help line, the
+// cheatsheet and its exports are generated from the same data. Keys are
+// display names: single characters, or "^o", "enter", "shift+arrow" and the like.
+// Actions, hints and sections are English here and translated as they're shown.
+
+import { tr } from "../ui/i18n"
+import { displayWidth, padToWidth } from "../ui/width"
+
+export const KEY_SECTIONS = ["Navigation", "Views", "Panels", "Sessions", "Session list", "Live", "Export", "General"] as const
+export type KeySection = (typeof KEY_SECTIONS)[number]
+
+export type Binding = {
+  keys: string[]
+  action: string
+  section: KeySection
+  hint?: string // label on the timeline help line; bindings without one are cheatsheet-only
+}
+
+// In help-line order
+export const KEYMAP: Binding[] = [
+  { keys: ["h", "l"], action: "Previous / next node in time (or ←/→)", section: "Navigation", hint: "chrono" },
+  { keys: ["]t", "[t"], action: "Next / previous node with the same tool", section: "Navigation", hint: "same tool" },
+  { keys: ["p", "c"], action: "Parent / first child of the node", section: "Navigation", hint: "parent/child" },
+  { keys: ["O"], action: "Subagent's conclusion / the Task result the parent got", section: "Navigation" },
+  { keys: ["b+N"], action: "Jump to breadcrumb N (details open)", section: "Navigation", hint: "breadcrumb" },
+  { keys: ["^o", "^n"], action: "Back / forward through the jump list (not ^i, which is Tab)", section: "Navigation", hint: "jump back/fwd" },
+  { keys: ["/"], action: "Filter nodes by an expression", section: "Views", hint: "filter" },
+  { keys: ["*"], action: "Filter like the cursor's node: same tool, file, agent, error (again: next, then off)", section: "Views", hint: "like this" },
+  { keys: ["r"], action: "Highlight nodes related to the cursor", section: "Views", hint: "related" },
+  { keys: ["H"], action: "Color nodes by token cost", section: "Views", hint: "token colors" },
+  { keys: ["shift+arrow"], action: "Move within the current row", section: "Navigation", hint: "level" },
+  { keys: ["j", "k"], action: "Row down / up (or ↓/↑)", section: "Navigation", hint: "row" },
+  { keys: ["g", "G"], action: "First / last node of the row; 12G goes to the 12th", section: "Navigation" },
+  { keys: ["0-9"], action: "Count for the next motion (10l, 5k)", section: "Navigation" },
+  { keys: ["w"], action: "Symbol / preview cells", section: "Views", hint: "preview" },
+  { keys: ["v"], action: "List view", section: "Views", hint: "list" },
+  { keys: ["V"], action: "Gantt view", section: "Views", hint: "gantt" },
+  { keys: ["z"], action: "Zoom out to prompts and replies; buckets on a long session (z again: in)", section: "Views", hint: "zoom out" },
+  { keys: ["M"], action: "Node mix chart", section: "Panels", hint: "mix" },
+  { keys: ["U"], action: "Test run strip", section: "Panels", hint: "tests" },
+  { keys: ["W"], action: "Activity feed across watched projects", section: "Panels", hint: "feed" },
+  { keys: ["R"], action: "Reading mode (^d/^u page, g/G ends)", section: "Views", hint: "reading" },
+  { keys: ["m"], action: "Cycle the camera: center, left, trailing", section: "Views", hint: "camera" },
+  { keys: ["L"], action: "Lane-per-tool layout", section: "Views", hint: "lanes" },
+  { keys: ["F"], action: "File swimlanes", section: "Views", hint: "files" },
+  { keys: ["enter"], action: "Scroll the focus pane (Esc leaves)", section: "Panels", hint: "focus" },
+  { keys: ["D"], action: "Dock the focus pane bottom, right or off", section: "Panels", hint: "focus dock" },
+  { keys: ["+", "-"], action: "Grow / shrink the focus pane", section: "Panels", hint: "resize" },
+  { keys: ["t"], action: "Show / hide the timeline", section: "Panels", hint: "timeline" },
+  { keys: ["d"], action: "Show / hide details", section: "Panels", hint: "details" },
+  { keys: ["J", "K"], action: "Scroll details", section: "Panels" },
+  { keys: ["T"], action: "Show / hide the transcript", section: "Panels", hint: "transcript" },
+  { keys: ["!"], action: "Lines skipped while loading", section: "Panels" },
+  { keys: ["X"], action: "Load a --summarize summary in full", section: "Sessions" },
+  { keys: ["e", "E"], action: "Export the turn as markdown / JSON", section: "Export", hint: "export turn" },
+  { keys: ["I"], action: "Copy and save an issue comment", section: "Export", hint: "issue comment" },
+  { keys: ["N"], action: "Save the conversation up to here to seed a new session (fork)", section: "Export" },
+  { keys: ["S"], action: "Mark a range start, S again at its end: save its shell commands", section: "Export" },
+  { keys: ["y", "Y"], action: "Copy / save a code block from the reply (then its number)", section: "Export" },
+  { keys: ["a", "A"], action: "Pin the node / show the pin tray (e exports pins)", section: "Panels", hint: "pin/tray" },
+  { keys: ["B"], action: "Scrub back to the cursor's moment (B again: the present)", section: "Views", hint: "scrub" },
+  { keys: [",", "."], action: "Scrub one event back / forward (takes a count)", section: "Views" },
+  { keys: ["space"], action: "Pause / resume live updates", section: "Live", hint: "pause" },
+  { keys: ["P"], action: "Hide progress events", section: "Views", hint: "progress" },
+  { keys: ["C"], action: "Hide successful tool results", section: "Views", hint: "compact" },
+  { keys: ["s"], action: "Session list", section: "Sessions", hint: "sessions" },
+  { keys: ["tab"], action: "Next recent session (shift+tab: previous)", section: "Sessions", hint: "next session" },
+  { keys: ["|"], action: "Close the split view", section: "Sessions", hint: "split" },
+  { keys: ["^w"], action: "Switch split pane", section: "Sessions", hint: "pane" },
+  { keys: ["o"], action: "Cycle the sort order", section: "Session list" },
+  { keys: ["n"], action: "Edit the session's note", section: "Session list" },
+  { keys: ["/"], action: "Search sessions", section: "Session list" },
+  { keys: ["enter"], action: "Open the session", section: "Session list" },
+  { keys: ["|"], action: "Open the session beside this one", section: "Session list" },
+  { keys: ["f"], action: "Follow the newest node", section: "Live", hint: "follow" },
+  { keys: ["i"], action: "Send a message (OpenCode server only)", section: "Live" },
+  { keys: ["x"], action: "Abort the session (OpenCode server only)", section: "Live" },
+  { keys: ["?"], action: "This cheatsheet (e/E export it as markdown / text)", section: "General", hint: "keys" },
+  { keys: ["q"], action: "Quit", section: "General", hint: "quit" },
+]
+
+// Your key → the built-in key it acts as, from config.json
+export type KeyRemaps = Record<string, string>
+
+// Display name → the input Ink reports; null for keys that aren't plain characters
+function inputOf(key: string): string | null {
+  if (key === "space") return " "
+  return key.length === 1 ? key : null
+}
+
+const REMAPPABLE = new Set(KEYMAP.flatMap(b => b.keys.map(inputOf)).filter((k): k is string => k !== null))
+
+// Remaps for single characters onto keys the TUI binds; anything else is reported
+export function parseKeyRemaps(raw: Record<string, string> | undefined): { remaps: KeyRemaps; errors: string[] } {
+  const remaps: KeyRemaps = {}
+  const errors: string[] = []
+  for (const [from, to] of Object.entries(raw ?? {})) {
+    const key = inputOf(from)
+    const target = typeof to === "string" ? inputOf(to) : null
+    if (key === null) errors.push(`keys: "${from}" is not a single key`)
+    else if (target === null || !REMAPPABLE.has(target)) errors.push(`keys: "${from}" maps to "${to}", which is not a remappable key`)
+    else remaps[key] = target
+  }
+  return { remaps, errors }
+}
+
+let activeRemaps: KeyRemaps = {}
+
+export function setKeyRemaps(remaps: KeyRemaps): void {
+  activeRemaps = remaps
+}
+
+// The built-in key a press stands for
+export function remapKey(input: string, remaps: KeyRemaps = activeRemaps): string {
+  return remaps[input] ?? input
+}
+
+function displayOf(input: string): string {
+  return input === " " ? "space" : input
+}
+
+// The keymap as the user has it: a remapped key moves to the binding it now
+// triggers, and a built-in key taken over by a remap leaves its old one
+export function activeBindings(remaps: KeyRemaps = activeRemaps): Binding[] {
+  const out: Binding[] = []
+  for (const binding of KEYMAP) {
+    const keys = binding.keys.flatMap(key => {
+      const input = inputOf(key)
+      if (input === null) return [key]
+      const shadowed = remaps[input] !== undefined && remaps[input] !== input
+      const aliases = Object.keys(remaps).filter(from => from !== input && remaps[from] === input).map(displayOf)
+      return [...(shadowed ? [] : [key]), ...aliases]
+    })
+    if (keys.length > 0) out.push({ ...binding, keys })
+  }
+  return out
+}
+
+export function helpLine(bindings = activeBindings()): string {
+  return bindings.filter(b => b.hint).map(b => `${b.keys.join("/")}:${tr(b.hint as string)}`).join(" ")
+}
+
+function bySection(bindings: Binding[]): [KeySection, Binding[]][] {
+  return KEY_SECTIONS
+    .map((section): [KeySection, Binding[]] => [section, bindings.filter(b => b.section === section)])
+    .filter(([, list]) => list.length > 0)
+}
+
+export function cheatsheetLines(bindings = activeBindings()): string[] {
+  const width = Math.max(...bindings.map(b => displayWidth(b.keys.join("/"))))
+  const lines: string[] = []
+  for (const [section, list] of bySection(bindings)) {
+    if (lines.length > 0) lines.push("")
+    lines.push(tr(section))
+    for (const b of list) lines.push(`  ${padToWidth(b.keys.join("/"), width)}  ${tr(b.action)}`)
+  }
+  return lines
+}
+
+export function cheatsheetText(bindings = activeBindings()): string {
+  return cheatsheetLines(bindings).join("\n") + "\n"
+}
+
+export function cheatsheetMarkdown(bindings = activeBindings()): string {
+  const out = [`# ${tr("vizier keys")}`]
+  for (const [section, list] of bySection(bindings)) {
+    out.push("", `## ${tr(section)}`, "", `| ${tr("Key")} | ${tr("Action")} |`, "| --- | --- |")
+    for (const b of list) out.push(`| ${b.keys.map(k => `\`${k.replace(/\|/g, "\\|")}\``).join(" / ")} | ${tr(b.action)} |`)
+  }
+  return out.join("\n") + "\n"
+}
@@ -0,0 +1,46 @@
+import type { Node } from "./types"
+import type { ToolGroups } from "./groups"
+import { toolGroupOf } from "./groups"
+
+// Lane-per-tool layout: one timeline row per kind of work, so a glance shows
+// whether a session was mostly reading, editing or running commands.
+
+export const LANES = ["User", "Asst", "Search", "Write", "Exec", "Other", "Agents"] as const
+export type Lane = (typeof LANES)[number]
+
+// Used when the config's toolGroups don't place a tool in search, write or exec
+export const DEFAULT_LANE_GROUPS: ToolGroups = {
+  search: ["Read", "Grep", "Glob", "LS", "List", "WebSearch", "WebFetch", "NotebookRead"],
+  write: ["Edit", "Write", "MultiEdit", "NotebookEdit", "Patch", "apply_patch"],
+  exec: ["Bash", "BashOutput", "KillShell", "Shell"],
+}
+
+const TOOL_LANES: Record<string, Lane> = { search: "Search", write: "Write", exec: "Exec" }
+
+let configuredGroups: ToolGroups = {}
+
+// Groups from config.json, consulted before the defaults
+export function setLaneGroups(groups: ToolGroups | undefined): void {
+  configuredGroups = groups ?? {}
+}
+
+export function toolLane(name: string, groups: ToolGroups = configuredGroups): Lane {
+  const configured = toolGroupOf(name, groups)?.toLowerCase()
+  if (configured && TOOL_LANES[configured]) return TOOL_LANES[configured]
+  return TOOL_LANES[toolGroupOf(name, DEFAULT_LANE_GROUPS) ?? ""] ?? "Other"
+}
+
+export function nodeLane(node: Node): Lane {
+  const t = node.nodeType
+  if (node.branchLevel > 0 || t.kind === "agent_start" || t.kind === "agent_end") return "Agents"
+  switch (t.kind) {
+    case "user": return "User"
+    case "assistant": case "reasoning": case "patch": return "Asst"
+    case "tool_call": case "tool_use": return t.name === "Task" ? "Agents" : toolLane(t.name)
+    default: return "Other"
+  }
+}
+
+export function laneIndex(node: Node): number {
+  return LANES.indexOf(nodeLane(node))
+}
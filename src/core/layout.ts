@@ -0,0 +1,99 @@
+import type { Graph } from "./types"
+import type { ZoomLevel, CellMode } from "./zoom"
+import { filterByZoom, getVisualBranch, applyVisibility, SHOW_ALL } from "./zoom"
+import { applyFileLanes } from "./files"
+
+// Timeline geometry, shared by the Timeline component and `vizier layout-check`
+
+// Column widths per cell mode
+// Symbol: "──WXYZ" = 6 chars (supports tool + spaced status, symbol padded to 4 cols)
+// Preview: "──WXYZ preview text    " = 25 chars (same ── prefix, symbol, then padded text)
+export const COL_W_SYMBOL = 6
+export const COL_W_PREVIEW = 25
+
+// Sticky column widths per mode
+const STICKY_W_SYMBOL = 5           // "●x  │"
+const STICKY_W_PREVIEW = COL_W_PREVIEW  // full width
+
+export const FILE_LABEL_W = 16
+export const MAX_BRANCH = 14 // 3 main rows + up to ~5 parallel agents × 2 rows
+const FRAME_W = 4 // border and padding
+
+export function getColW(mode: CellMode): number {
+  return mode === "preview" ? COL_W_PREVIEW : COL_W_SYMBOL
+}
+
+export function getStickyW(mode: CellMode): number {
+  return mode === "preview" ? STICKY_W_PREVIEW : STICKY_W_SYMBOL
+}
+
+export function getLabelW(zoom: ZoomLevel): number {
+  return zoom === "files" ? FILE_LABEL_W : zoom === "lanes" ? 7 : 5 // "Search "
+}
+
+export function columnsPerScreen(zoom: ZoomLevel, mode: CellMode, termWidth: number): number {
+  return Math.max(1, Math.floor((termWidth - getLabelW(zoom) - FRAME_W - getStickyW(mode)) / getColW(mode)))
+}
+
+// The layouts the timeline can switch between: default, L and F
+export const LAYOUT_ZOOMS: ZoomLevel[] = ["details", "lanes", "files"]
+
+export type LayoutNeed = {
+  zoom: ZoomLevel
+  cellMode: CellMode
+  rows: number // rows the session's nodes reach; past MAX_BRANCH + 1 they're cut off
+  columns: number // one per node shown at this zoom
+  fullWidth: number // terminal width that fits every column at once
+  perScreen: number // columns that fit at the checked width
+  screens: number
+}
+
+export function layoutNeeds(graph: Graph, width: number): LayoutNeed[] {
+  const needs: LayoutNeed[] = []
+  const visible = applyVisibility(graph, SHOW_ALL)
+  for (const zoom of LAYOUT_ZOOMS) {
+    const laid = zoom === "files" ? applyFileLanes(visible) : visible
+    const indices = filterByZoom(laid.nodes, zoom)
+    let maxBranch = 0
+    for (const idx of indices) maxBranch = Math.max(maxBranch, getVisualBranch(laid.nodes[idx], zoom))
+    for (const cellMode of ["symbol", "preview"] as CellMode[]) {
+      const perScreen = columnsPerScreen(zoom, cellMode, width)
+      needs.push({
+        zoom,
+        cellMode,
+        rows: indices.length > 0 ? maxBranch + 1 : 0,
+        columns: indices.length,
+        fullWidth: getLabelW(zoom) + FRAME_W + getStickyW(cellMode) + indices.length * getColW(cellMode),
+        perScreen,
+        screens: Math.max(1, Math.ceil(indices.length / perScreen)),
+      })
+    }
+  }
+  return needs
+}
+
+function zoomName(zoom: ZoomLevel): string {
+  return zoom === "lanes" ? "lanes (L)" : zoom === "files" ? "files (F)" : zoom
+}
+
+export function formatLayoutNeeds(needs: LayoutNeed[], sessionId: string, nodeCount: number, width: number): string {
+  const header = ["Layout", "Cells", "Rows", "Columns", "Full width", "Per screen", "Screens"]
+  const rows = needs.map(n => [
+    zoomName(n.zoom),
+    n.cellMode,
+    n.rows > MAX_BRANCH + 1 ? `${n.rows}*` : String(n.rows),
+    String(n.columns),
+    String(n.fullWidth),
+    String(n.perScreen),
+    String(n.screens),
+  ])
+  const widths = header.map((h, i) => Math.max(h.length, ...rows.map(r => r[i].length)))
+  const line = (cells: string[]) => cells.map((c, i) => i < 2 ? c.padEnd(widths[i]) : c.padStart(widths[i])).join("  ").trimEnd()
+  const out = [`Layout of ${sessionId} (${nodeCount} nodes) at width ${width}`, "", line(header), ...rows.map(line)]
+  if (needs.some(n => n.rows > MAX_BRANCH + 1)) out.push("", `* rows past ${MAX_BRANCH + 1} are not drawn; hide progress (P) or use the lane layout (L)`)
+  return out.join("\n") + "\n"
+}
+
+export function layoutNeedsToJson(needs: LayoutNeed[], sessionId: string, width: number): string {
+  return JSON.stringify({ schemaVersion: 1, kind: "layout-check", sessionId, width, layouts: needs }, null, 2) + "\n"
+}
@@ -0,0 +1,79 @@
+import type { Node, SessionInfo } from "./types"
+import { ENDED_AFTER_MS, isAwaitingUser, lastActivity } from "./state"
+import { getTurnBounds } from "./turns"
+import { analyzeErrors } from "./errors"
+
+// Where a session is in its life, derived from its recent events. Every
+// surface that reports a session's state — status bar, picker, `status`,
+// webhooks and exit codes — goes through this one set of states.
+//
+//   starting ──▶ streaming ◀──▶ tool-running
+//                    │  ▲
+//      quiet 2m ─────┤  └── new events
+//                    ▼
+//                  idle          (the agent's turn, but nothing is happening)
+//
+//   hand-back ──▶ waiting-for-user, or error when the latest turn has a
+//   tool failure that was never retried successfully
+//   quiet 30m ──▶ ended (or error, by the same rule)
+
+export type Lifecycle = "starting" | "streaming" | "tool-running" | "waiting-for-user" | "idle" | "ended" | "error"
+
+// The agent's turn with no new events for this long reads as stalled
+export const IDLE_AFTER_MS = 2 * 60_000
+
+export const LIFECYCLE_LABELS: Record<Lifecycle, string> = {
+  starting: "STARTING",
+  streaming: "LIVE",
+  "tool-running": "RUNNING",
+  "waiting-for-user": "WAITING",
+  idle: "IDLE",
+  ended: "ENDED",
+  error: "ERROR",
+}
+
+// Still the agent's turn: it may yet produce output or recover from a failure
+export function isAgentTurn(state: Lifecycle): boolean {
+  return state === "starting" || state === "streaming" || state === "tool-running" || state === "idle"
+}
+
+// A failed tool call in the latest turn that was never retried successfully
+export function latestTurnFailed(nodes: Node[]): boolean {
+  if (nodes.length === 0) return false
+  const turn = getTurnBounds(nodes, nodes.length - 1)
+  return analyzeErrors(nodes).some(e => e.idx >= turn.start && e.retry !== "succeeded")
+}
+
+// The newest tool call still waiting for its result
+export function pendingTool(nodes: Node[]): number | null {
+  for (let i = nodes.length - 1; i >= 0; i--) {
+    const t = nodes[i].nodeType
+    if (t.kind === "tool_call" && t.output === null) return i
+  }
+  return null
+}
+
+// idleAfterMs overrides how long a quiet agent turn takes to read as idle
+export function sessionLifecycle(nodes: Node[], now: number, idleAfterMs = IDLE_AFTER_MS): Lifecycle {
+  const last = lastActivity(nodes)
+  if (last === null) return "starting"
+  const quietMs = now - last
+  const handedBack = isAwaitingUser(nodes)
+  if (handedBack || quietMs > ENDED_AFTER_MS) {
+    if (latestTurnFailed(nodes)) return "error"
+    return quietMs > ENDED_AFTER_MS ? "ended" : "waiting-for-user"
+  }
+  if (pendingTool(nodes) !== null) return "tool-running"
+  if (nodes.every(n => n.nodeType.kind === "user")) return "starting"
+  return quietMs > idleAfterMs ? "idle" : "streaming"
+}
+
+// The picker only has the session list's summary: no pending tools or
+// failures, so those read as streaming and waiting
+export function lifecycleOfInfo(info: SessionInfo, now: number): Lifecycle {
+  if (info.nodeCount === 0) return "starting"
+  const quietMs = now - info.timestamp
+  if (quietMs > ENDED_AFTER_MS) return "ended"
+  if (info.waitingForUser) return "waiting-for-user"
+  return quietMs > IDLE_AFTER_MS ? "idle" : "streaming"
+}
@@ -0,0 +1,69 @@
+import type { Node } from "./types"
+import { normalizeInput } from "./repeats"
+
+// Live loop detection: an agent whose most recent tool calls are the same
+// short cycle (e.g. Edit → Bash → Edit → Bash …) repeated several times
+
+export type LoopWarning = {
+  agentId?: string
+  tools: string[] // one cycle, in call order
+  repeats: number
+}
+
+export const LOOP_MIN_REPEATS = 3
+const LOOP_WINDOW = 40 // most recent tool calls inspected per actor
+const MAX_CYCLE = 6
+
+function signature(node: Node): string | null {
+  const t = node.nodeType
+  return t.kind === "tool_call" ? `${t.name}\u0000${normalizeInput(t.input)}` : null
+}
+
+// How many times the last `len` entries repeat back-to-back at the end of sigs
+function tailRepeats(sigs: string[], len: number): number {
+  let repeats = 1
+  for (let start = sigs.length - 2 * len; start >= 0; start -= len) {
+    for (let i = 0; i < len; i++) {
+      if (sigs[start + i] !== sigs[sigs.length - len + i]) return repeats
+    }
+    repeats++
+  }
+  return repeats
+}
+
+export function detectLoop(nodes: Node[], minRepeats = LOOP_MIN_REPEATS): LoopWarning | null {
+  const byActor = new Map<string, Node[]>()
+  for (let i = nodes.length - 1; i >= 0; i--) {
+    const node = nodes[i]
+    if (node.nodeType.kind !== "tool_call") continue
+    const actor = node.agentId ?? ""
+    const list = byActor.get(actor) ?? []
+    if (list.length < LOOP_WINDOW) list.push(node)
+    byActor.set(actor, list)
+  }
+
+  let best: LoopWarning | null = null
+  for (const recent of byActor.values()) {
+    const calls = recent.reverse()
+    // Collapsed repeats (see repeats.ts) already are a cycle of one
+    const last = calls[calls.length - 1]
+    let found: LoopWarning | null = null
+    if ((last.repeatCount ?? 1) >= minRepeats && last.nodeType.kind === "tool_call") {
+      found = { agentId: last.agentId, tools: [last.nodeType.name], repeats: last.repeatCount! }
+    }
+    const sigs = calls.map(n => signature(n)!)
+    for (let len = 1; !found && len <= MAX_CYCLE && len * minRepeats <= sigs.length; len++) {
+      const repeats = tailRepeats(sigs, len)
+      if (repeats >= minRepeats) {
+        const cycle = calls.slice(calls.length - len)
+        found = {
+          agentId: last.agentId,
+          tools: cycle.map(n => n.nodeType.kind === "tool_call" ? n.nodeType.name : ""),
+          repeats,
+        }
+      }
+    }
+    if (found && (!best || found.repeats > best.repeats)) best = found
+  }
+  return best
+}
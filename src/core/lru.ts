@@ -0,0 +1,33 @@
+// Small least-recently-used map: get and set both count as use, and the
+// oldest entry is dropped once capacity is exceeded (Map keeps insertion order)
+export type Lru<K, V> = {
+  get(key: K): V | undefined
+  set(key: K, value: V): void
+  delete(key: K): void
+  size(): number
+}
+
+export function createLru<K, V>(capacity: number): Lru<K, V> {
+  const entries = new Map<K, V>()
+  return {
+    get(key) {
+      const value = entries.get(key)
+      if (value === undefined) return undefined
+      entries.delete(key)
+      entries.set(key, value)
+      return value
+    },
+    set(key, value) {
+      entries.delete(key)
+      entries.set(key, value)
+      for (const oldest of entries.keys()) {
+        if (entries.size <= capacity) break
+        entries.delete(oldest)
+      }
+    },
+    delete(key) {
+      entries.delete(key)
+    },
+    size: () => entries.size,
+  }
+}
@@ -0,0 +1,108 @@
+import { glyphs } from "../ui/glyphs"
+import { wrapRanges } from "../ui/width"
+
+// Just enough markdown for assistant replies in a terminal: headings, bullet
+// lists, **bold**, `inline code` and fenced code blocks. Markers are dropped
+// and the styling travels in spans; `text` is the plain rendered line.
+
+export type MdSpan = { text: string; bold?: boolean; code?: boolean }
+export type MdLine = { text: string; spans: MdSpan[] }
+
+const INLINE = /`([^`]+)`|\*\*([^*]+)\*\*|__([^_]+)__/g
+const FENCE = /^\s*(```|~~~)/
+const HEADING = /^#{1,6}\s+(.*)$/
+const BULLET = /^(\s*)[-*+]\s+(.*)$/
+
+function span(text: string, bold: boolean, code = false): MdSpan {
+  const s: MdSpan = { text }
+  if (bold) s.bold = true
+  if (code) s.code = true
+  return s
+}
+
+export function parseInline(text: string, bold = false): MdSpan[] {
+  const spans: MdSpan[] = []
+  let last = 0
+  for (const m of text.matchAll(INLINE)) {
+    const at = m.index ?? 0
+    if (at > last) spans.push(span(text.slice(last, at), bold))
+    if (m[1] !== undefined) spans.push(span(m[1], bold, true))
+    else spans.push(span(m[2] ?? m[3], true))
+    last = at + m[0].length
+  }
+  if (last < text.length || spans.length === 0) spans.push(span(text.slice(last), bold))
+  return spans
+}
+
+function sliceSpans(spans: MdSpan[], from: number, to: number): MdSpan[] {
+  const out: MdSpan[] = []
+  let pos = 0
+  for (const s of spans) {
+    const a = Math.max(from, pos)
+    const b = Math.min(to, pos + s.text.length)
+    if (a < b) out.push({ ...s, text: s.text.slice(a - pos, b - pos) })
+    pos += s.text.length
+  }
+  return out
+}
+
+function wrapSpans(spans: MdSpan[], width: number): MdLine[] {
+  const plain = spans.map(s => s.text).join("")
+  return wrapRanges(plain, width).map(([from, to]) => ({
+    text: plain.slice(from, to),
+    spans: sliceSpans(spans, from, to),
+  }))
+}
+
+// Render markdown into styled lines, wrapped to width (unwrapped by default)
+export function renderMarkdown(text: string, width = Infinity): MdLine[] {
+  const out: MdLine[] = []
+  let inFence = false
+  for (const line of text.split("\n")) {
+    if (FENCE.test(line)) { inFence = !inFence; continue }
+    if (inFence) {
+      out.push(...wrapSpans([span(line.trimEnd(), false, true)], width))
+      continue
+    }
+    const heading = HEADING.exec(line)
+    const bullet = BULLET.exec(line)
+    let spans: MdSpan[]
+    if (heading) spans = parseInline(heading[1], true)
+    else if (bullet) spans = [span(`${bullet[1]}${glyphs().bullet} `, false), ...parseInline(bullet[2])]
+    else spans = parseInline(line)
+    out.push(...wrapSpans(spans, width))
+  }
+  return out
+}
+
+export type CodeBlock = { lang: string; code: string }
+
+// Fenced code blocks in order of appearance; an unterminated fence runs to the end
+export function extractCodeBlocks(text: string): CodeBlock[] {
+  const blocks: CodeBlock[] = []
+  let current: { lang: string; lines: string[] } | null = null
+  for (const line of text.split("\n")) {
+    const fence = FENCE.exec(line)
+    if (fence && !current) {
+      current = { lang: line.trim().slice(fence[1].length).trim().split(/\s+/)[0] ?? "", lines: [] }
+    } else if (fence && current) {
+      blocks.push({ lang: current.lang, code: current.lines.join("\n") })
+      current = null
+    } else if (current) {
+      current.lines.push(line)
+    }
+  }
+  if (current) blocks.push({ lang: current.lang, code: current.lines.join("\n") })
+  return blocks
+}
+
+const LANG_EXTENSIONS: Record<string, string> = {
+  typescript: "ts", ts: "ts", tsx: "tsx", javascript: "js", js: "js", jsx: "jsx",
+  python: "py", py: "py", rust: "rs", rs: "rs", go: "go", json: "json",
+  bash: "sh", sh: "sh", shell: "sh", zsh: "sh", diff: "diff", patch: "diff",
+  markdown: "md", md: "md", yaml: "yml", yml: "yml", toml: "toml", sql: "sql",
+}
+
+export function codeBlockExtension(lang: string): string {
+  return LANG_EXTENSIONS[lang.toLowerCase()] ?? "txt"
+}
@@ -0,0 +1,55 @@
+import type { Node } from "./types"
+import type { Bucket } from "./buckets"
+import { toolLane } from "./lanes"
+
+// What a session spent its time on, bucket by bucket: messages, reads, edits,
+// commands and failures. Stacked over time the phases show up on their own —
+// exploring (reads), implementing (edits), then a test-fix loop (runs and
+// errors).
+
+export const MIX_KINDS = ["message", "read", "edit", "run", "error"] as const
+export type MixKind = (typeof MIX_KINDS)[number]
+export type NodeMix = Record<MixKind, number>
+
+// Results and progress are counted with the call they belong to, so they're left out
+export function mixKind(node: Node): MixKind | null {
+  const t = node.nodeType
+  if (t.kind === "tool_call" && t.isError) return "error"
+  switch (t.kind) {
+    case "user": case "assistant": return "message"
+    case "tool_call": case "tool_use": {
+      const lane = toolLane(t.name)
+      return lane === "Search" ? "read" : lane === "Write" ? "edit" : "run"
+    }
+    case "patch": return "edit"
+    default: return null
+  }
+}
+
+function emptyMix(): NodeMix {
+  return { message: 0, read: 0, edit: 0, run: 0, error: 0 }
+}
+
+export function bucketMix(nodes: Node[], buckets: Bucket[]): NodeMix[] {
+  return buckets.map(b => {
+    const mix = emptyMix()
+    if (b.first < 0) return mix
+    for (let i = b.first; i <= b.last; i++) {
+      const kind = mixKind(nodes[i])
+      if (kind) mix[kind]++
+    }
+    return mix
+  })
+}
+
+// One column of a stacked chart, bottom first: `height` cells split between
+// the kinds in proportion (largest remainder), null for an empty bucket
+export function stackColumn(mix: NodeMix, height: number): (MixKind | null)[] {
+  const total = MIX_KINDS.reduce((sum, k) => sum + mix[k], 0)
+  if (total === 0 || height <= 0) return Array(Math.max(0, height)).fill(null)
+  const exact = MIX_KINDS.map(k => mix[k] / total * height)
+  const cells = exact.map(Math.floor)
+  const order = MIX_KINDS.map((_, i) => i).sort((a, b) => (exact[b] - cells[b]) - (exact[a] - cells[a]) || a - b)
+  for (let left = height - cells.reduce((a, b) => a + b, 0), j = 0; left > 0; left--, j++) cells[order[j]]++
+  return MIX_KINDS.flatMap((k, i) => Array<MixKind>(cells[i]).fill(k))
+}
@@ -0,0 +1,122 @@
+import type { Node } from "./types"
+import { getTurnBounds, isTurnStart } from "./turns"
+import type { VisibilityMask } from "./zoom"
+import { isNodeVisible } from "./zoom"
+import { filterContext } from "./filter"
+
+function toolName(node: Node): string | null {
+  const t = node.nodeType
+  return t.kind === "tool_call" || t.kind === "tool_use" ? t.name : null
+}
+
+// Same tool name for tool nodes, same node kind otherwise
+export function isSameKind(a: Node, b: Node): boolean {
+  const name = toolName(a)
+  if (name !== null) return toolName(b) === name
+  return a.nodeType.kind === b.nodeType.kind
+}
+
+// Index of the `count`-th next/previous node like nodes[fromIdx], or null if none.
+// Stops at the last match when fewer than `count` exist. With a mask, nodes
+// it hides (P, C, a filter) are skipped.
+export function findSameKind(nodes: Node[], fromIdx: number, direction: 1 | -1, count = 1, mask?: VisibilityMask): number | null {
+  const origin = nodes[fromIdx]
+  if (!origin) return null
+  const ctx = mask?.filter ? filterContext(nodes) : undefined
+  let found: number | null = null
+  let remaining = count
+  for (let i = fromIdx + direction; i >= 0 && i < nodes.length; i += direction) {
+    if (!isSameKind(origin, nodes[i])) continue
+    if (mask && !isNodeVisible(nodes[i], mask, ctx)) continue
+    found = i
+    if (--remaining === 0) break
+  }
+  return found
+}
+
+// Graph traversal via parentId rather than timestamp proximity:
+// tool result → tool use, tool use → issuing assistant message, agent → spawning tool
+export function findParent(nodes: Node[], idx: number): number | null {
+  const parentId = nodes[idx]?.parentId
+  if (!parentId) return null
+  const parent = nodes.findIndex(n => n.id === parentId)
+  return parent >= 0 ? parent : null
+}
+
+// First node (chronologically) whose parent is nodes[idx] — e.g. an assistant
+// message's first tool call, or a Task call's first agent node
+export function findFirstChild(nodes: Node[], idx: number): number | null {
+  const id = nodes[idx]?.id
+  if (!id) return null
+  for (let i = 0; i < nodes.length; i++) {
+    if (i !== idx && nodes[i].parentId === id) return i
+  }
+  return null
+}
+
+// Lineage of a node for the breadcrumb: turn prompt → issuing assistant →
+// spawning tool / agent → node. Indices ascend, ending at idx.
+export function getBreadcrumb(nodes: Node[], idx: number): number[] {
+  const node = nodes[idx]
+  if (!node) return []
+  const crumbs = new Set<number>()
+  const { start } = getTurnBounds(nodes, idx)
+  if (isTurnStart(nodes[start])) crumbs.add(start)
+
+  const addIssuer = (i: number) => {
+    const parent = findParent(nodes, i)
+    if (parent !== null && nodes[parent].nodeType.kind === "assistant") crumbs.add(parent)
+  }
+
+  if (node.agentId) {
+    const first = nodes.findIndex(n => n.agentId === node.agentId)
+    const spawn = findParent(nodes, first)
+    if (spawn !== null) {
+      addIssuer(spawn)
+      crumbs.add(spawn)
+    }
+    crumbs.add(first)
+    if (node.nodeType.kind !== "assistant") addIssuer(idx)
+  } else {
+    addIssuer(idx)
+  }
+  crumbs.add(idx)
+  return [...crumbs].filter(i => i <= idx).sort((a, b) => a - b)
+}
+
+function agentOf(node: Node): string | null {
+  const t = node.nodeType
+  if (t.kind === "agent_start" || t.kind === "agent_end") return t.agentId
+  return node.agentId ?? null
+}
+
+// A subagent's last reply and its agent_end, when one was recorded
+function agentEnding(nodes: Node[], agentId: string): { reply: number | null; end: number | null } {
+  let reply: number | null = null
+  let end: number | null = null
+  for (let i = nodes.length - 1; i >= 0 && (reply === null || end === null); i--) {
+    if (agentOf(nodes[i]) !== agentId) continue
+    const kind = nodes[i].nodeType.kind
+    if (kind === "agent_end" && end === null) end = i
+    if (kind === "assistant" && reply === null) reply = i
+  }
+  return { reply, end }
+}
+
+// Links what a subagent concluded (its last reply, or agent_end) with the Task
+// call whose output is what the parent received. From the Task call or any
+// other agent node → the last reply; from the last reply or agent_end → the Task call.
+export function findAgentLink(nodes: Node[], idx: number): number | null {
+  const node = nodes[idx]
+  if (!node) return null
+  const agentId = agentOf(node)
+  if (agentId === null) {
+    const first = nodes.findIndex(n => n.parentId === node.id && agentOf(n) !== null)
+    if (first < 0) return null
+    const { reply, end } = agentEnding(nodes, agentOf(nodes[first])!)
+    return reply ?? end
+  }
+  const { reply, end } = agentEnding(nodes, agentId)
+  if (idx !== reply && idx !== end && (reply ?? end) !== null) return reply ?? end
+  return findParent(nodes, nodes.findIndex(n => agentOf(n) === agentId))
+}
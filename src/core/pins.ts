@@ -0,0 +1,19 @@
+import type { Node } from "./types"
+
+// Nodes pinned into the comparison tray, by node id so pins survive the graph
+// being re-read as the session grows. Oldest pin first.
+
+export const MAX_PINS = 4 // side by side, more gets too narrow to read
+
+// Pin, or unpin if already pinned; past MAX_PINS the oldest pin drops out
+export function togglePin(pins: string[], id: string): string[] {
+  if (pins.includes(id)) return pins.filter(p => p !== id)
+  return [...pins, id].slice(-MAX_PINS)
+}
+
+// Pinned nodes that still exist, in pin order
+export function resolvePins(nodes: Node[], pins: string[]): Node[] {
+  if (pins.length === 0) return []
+  const byId = new Map(nodes.map(n => [n.id, n]))
+  return pins.flatMap(id => byId.get(id) ?? [])
+}
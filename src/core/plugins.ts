@@ -0,0 +1,69 @@
+import type { Node } from "./types"
+import { toolGroupOf } from "./groups"
+
+// Rendering hooks for specific tools, so a team's own MCP tools can get a
+// domain-specific compact label or details body (a SQL result as a table).
+// Plugins are matched by tool name like toolGroups; the first match wins.
+// A hook returning null, or throwing, falls back to the built-in rendering.
+
+export type ToolCallView = { name: string; input: string; output: string | null; isError: boolean }
+
+export type RenderPlugin = {
+  name: string // for error messages
+  tools: string[] // tool names, trailing * for a prefix
+  label?: (call: ToolCallView) => string | null
+  details?: (call: ToolCallView) => string[] | null
+}
+
+let plugins: RenderPlugin[] = []
+let groups: Record<string, string[]> = {}
+// Lookups by tool name; a graph only has a handful of distinct tools, and
+// every timeline row asks again on each render
+const resolved = new Map<string, RenderPlugin | null>()
+
+export function setRenderPlugins(next: RenderPlugin[]): void {
+  plugins = next
+  groups = Object.fromEntries(next.map((p, i) => [String(i), p.tools]))
+  resolved.clear()
+}
+
+export function pluginFor(toolName: string): RenderPlugin | null {
+  const cached = resolved.get(toolName)
+  if (cached !== undefined) return cached
+  const idx = toolGroupOf(toolName, groups)
+  const plugin = idx === null ? null : plugins[Number(idx)]
+  resolved.set(toolName, plugin)
+  return plugin
+}
+
+function callView(node: Node): ToolCallView | null {
+  const t = node.nodeType
+  if (t.kind === "tool_call") return { name: t.name, input: t.input, output: t.output, isError: t.isError }
+  if (t.kind === "tool_use") return { name: t.name, input: t.input, output: null, isError: false }
+  return null
+}
+
+function run<T>(hook: ((call: ToolCallView) => T | null) | undefined, call: ToolCallView): T | null {
+  if (!hook) return null
+  try {
+    return hook(call) ?? null
+  } catch {
+    return null
+  }
+}
+
+// Compact timeline label, in place of the tool name
+export function pluginLabel(node: Node): string | null {
+  const call = callView(node)
+  const plugin = call && pluginFor(call.name)
+  const label = plugin ? run(plugin.label, call) : null
+  return label?.trim() ? label.replace(/\s+/g, " ").trim() : null
+}
+
+// Details panel body for the call's response
+export function pluginDetails(node: Node): string[] | null {
+  const call = callView(node)
+  const plugin = call && pluginFor(call.name)
+  const lines = plugin ? run(plugin.details, call) : null
+  return lines && lines.length > 0 ? lines : null
+}
@@ -0,0 +1,79 @@
+import { createHash } from "node:crypto"
+
+// Which transcript an export was made from: every file the session was read
+// from, with its size, modification time and SHA-256, plus one hash over
+// them all. A single-file session's hash is that file's own, so
+// `sha256sum <file>` checks it; for several files it is the SHA-256 of their
+// `sha256sum` lines ("<hash>  <file name>\n", in the order listed).
+
+export type SourceFile = {
+  path: string
+  bytes: number
+  modifiedAt: number
+  sha256: string
+}
+
+export type Provenance = {
+  files: SourceFile[] // in the order the session reads them: main transcript first
+  sha256: string
+}
+
+// One session's provenance, for reports that span several
+export type SessionProvenance = { sessionId: string; provenance: Provenance }
+
+function baseName(path: string): string {
+  return path.replace(/^.*[\\/]/, "")
+}
+
+export function sessionHash(files: SourceFile[]): string {
+  if (files.length === 1) return files[0].sha256
+  const lines = files.map(f => `${f.sha256}  ${baseName(f.path)}\n`).join("")
+  return createHash("sha256").update(lines).digest("hex")
+}
+
+export function sha256Of(content: Buffer | string): string {
+  return createHash("sha256").update(content).digest("hex")
+}
+
+function when(ts: number): string {
+  return new Date(ts).toISOString()
+}
+
+// Markdown: a section for the end of an export
+export function provenanceMarkdown(p: Provenance): string {
+  const lines = ["## Source", "", `- SHA-256: \`${p.sha256}\``]
+  for (const f of p.files) {
+    lines.push(`- \`${f.path}\`: ${f.bytes} bytes, modified ${when(f.modifiedAt)}, sha256 \`${f.sha256}\``)
+  }
+  return lines.join("\n")
+}
+
+// One line, for exports whose end is spoken for (forks) or meant for pasting
+export function provenanceLine(p: Provenance): string {
+  const bytes = p.files.reduce((sum, f) => sum + f.bytes, 0)
+  return `Transcript sha256 \`${p.sha256}\` (${p.files.length} file${p.files.length === 1 ? "" : "s"}, ${bytes} bytes)`
+}
+
+// Shell and other line-comment formats
+export function provenanceComment(p: Provenance, prefix = "#"): string[] {
+  return [
+    `${prefix} Transcript sha256 ${p.sha256}`,
+    ...p.files.map(f => `${prefix}   ${f.path}: ${f.bytes} bytes, modified ${when(f.modifiedAt)}, sha256 ${f.sha256}`),
+  ]
+}
+
+export function provenanceToJson(p: Provenance): object {
+  return {
+    sha256: p.sha256,
+    files: p.files.map(f => ({ ...f, modifiedAt: when(f.modifiedAt) })),
+  }
+}
+
+// Reports: a "Sources" section with a line per session
+export function sourcesMarkdown(sources: SessionProvenance[]): string {
+  return ["## Sources", "", ...sources.map(s => `- ${s.sessionId}: ${provenanceLine(s.provenance)}`)].join("\n")
+}
+
+export function sourcesToJson(sources: SessionProvenance[]): object[] {
+  return sources.map(s => ({ sessionId: s.sessionId, ...provenanceToJson(s.provenance) }))
+}
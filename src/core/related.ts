@@ -0,0 +1,56 @@
+import type { Node } from "./types"
+import { getTurnBounds } from "./turns"
+
+// How a node relates to the selected one, strongest first
+export type Relation = "self" | "lineage" | "turn" | "none"
+
+// Lineage = parentId ancestors and descendants within the selected node's turn
+// (parent chains can span the whole session) plus nodes of the same agent;
+// everything else in the turn is "turn"
+export function computeRelations(nodes: Node[], selected: number | null): Relation[] {
+  const relations: Relation[] = nodes.map(() => "none")
+  if (selected === null || !nodes[selected]) return relations
+
+  const indexById = new Map<string, number>()
+  const childrenOf = new Map<string, number[]>()
+  nodes.forEach((n, i) => {
+    if (!indexById.has(n.id)) indexById.set(n.id, i)
+    if (n.parentId) {
+      const list = childrenOf.get(n.parentId) ?? []
+      list.push(i)
+      childrenOf.set(n.parentId, list)
+    }
+  })
+
+  const { start, end } = getTurnBounds(nodes, selected)
+  for (let i = start; i < end; i++) relations[i] = "turn"
+
+  const origin = nodes[selected]
+  if (origin.agentId) {
+    nodes.forEach((n, i) => { if (n.agentId === origin.agentId) relations[i] = "lineage" })
+  }
+
+  // Ancestors
+  const seen = new Set<number>([selected])
+  let cursor = origin.parentId ? indexById.get(origin.parentId) : undefined
+  const inTurn = (i: number) => i >= start && i < end
+  while (cursor !== undefined && inTurn(cursor) && !seen.has(cursor)) {
+    seen.add(cursor)
+    relations[cursor] = "lineage"
+    const pid = nodes[cursor].parentId
+    cursor = pid ? indexById.get(pid) : undefined
+  }
+
+  // Descendants
+  const queue = [...(childrenOf.get(origin.id) ?? [])]
+  while (queue.length > 0) {
+    const i = queue.shift()!
+    if (seen.has(i) || !inTurn(i)) continue
+    seen.add(i)
+    relations[i] = "lineage"
+    queue.push(...(childrenOf.get(nodes[i].id) ?? []))
+  }
+
+  relations[selected] = "self"
+  return relations
+}
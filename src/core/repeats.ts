@@ -0,0 +1,56 @@
+import type { Node } from "./types"
+
+// Identical consecutive tool calls (same actor, tool and input, nothing but
+// assistant chatter in between) usually mean the agent is thrashing. They are
+// collapsed into the last call of the run, which records the run length.
+
+export function normalizeInput(input: string): string {
+  return input.replace(/\s+/g, " ").trim()
+}
+
+function isRepeat(prev: Node, next: Node): boolean {
+  const a = prev.nodeType
+  const b = next.nodeType
+  return a.kind === "tool_call" && b.kind === "tool_call"
+    && a.output !== null
+    && a.name === b.name
+    && normalizeInput(a.input) === normalizeInput(b.input)
+}
+
+// Returns the surviving nodes and how many calls were folded away. Nodes that
+// pointed at a removed call are re-parented to that call's own parent.
+export function collapseRepeats(nodes: Node[]): { nodes: Node[]; removed: number } {
+  const out: (Node | null)[] = []
+  const lastTool = new Map<string, number>() // actor → index into out
+  const replaced = new Map<string, string | undefined>() // removed id → its parentId
+  for (const node of nodes) {
+    const actor = node.agentId ?? ""
+    const t = node.nodeType
+    if (t.kind === "user") lastTool.delete(actor)
+    if (t.kind !== "tool_call") {
+      out.push(node)
+      continue
+    }
+    const prevIdx = lastTool.get(actor)
+    const prev = prevIdx !== undefined ? out[prevIdx] : null
+    if (prev && isRepeat(prev, node)) {
+      node.repeatCount = (prev.repeatCount ?? 1) + 1
+      replaced.set(prev.id, prev.parentId)
+      out[prevIdx!] = null
+    }
+    lastTool.set(actor, out.length)
+    out.push(node)
+  }
+  if (replaced.size === 0) return { nodes, removed: 0 }
+
+  const resolve = (id: string | undefined): string | undefined => {
+    let current = id
+    while (current !== undefined && replaced.has(current)) current = replaced.get(current)
+    return current
+  }
+  const kept = out.filter((n): n is Node => n !== null)
+  for (const n of kept) {
+    if (n.parentId && replaced.has(n.parentId)) n.parentId = resolve(n.parentId)
+  }
+  return { nodes: kept, removed: replaced.size }
+}
@@ -0,0 +1,182 @@
+import type { Graph, SessionStats } from "./types"
+import { nodeStats, emptyStats, formatTokens, totalTokens, statsToJson } from "./stats"
+import { formatDuration } from "./time"
+import type { ToolGroups } from "./groups"
+import { toolLabel } from "./groups"
+import type { SessionProvenance } from "./provenance"
+import { sourcesMarkdown, sourcesToJson } from "./provenance"
+
+// Activity across the sessions of a project over a recent window: how much
+// was done, with which tools, and whether tool failures are trending up.
+
+// Gaps longer than this between two events count as idle, not activity
+export const IDLE_GAP_MS = 5 * 60_000
+
+const UNITS: Record<string, number> = { m: 60_000, h: 3_600_000, d: 86_400_000, w: 7 * 86_400_000 }
+
+// "7d", "24h", "2w", "90m" → milliseconds
+export function parseSince(text: string): number | null {
+  const m = /^(\d+)([mhdw])$/.exec(text.trim())
+  if (!m || Number(m[1]) === 0) return null
+  return Number(m[1]) * UNITS[m[2]]
+}
+
+export type ToolTally = { name: string; calls: number; errors: number } // name: tool, or its group
+export type DayTally = { day: string; sessions: number; calls: number; errors: number } // day: YYYY-MM-DD, local time
+
+export type ActivityReport = {
+  since: number
+  until: number
+  sessions: number
+  activeMs: number
+  tokens: SessionStats
+  tools: ToolTally[] // most-called first
+  days: DayTally[] // oldest first, one per day in the window
+}
+
+function dayKey(ts: number): string {
+  const d = new Date(ts)
+  return `${d.getFullYear()}-${String(d.getMonth() + 1).padStart(2, "0")}-${String(d.getDate()).padStart(2, "0")}`
+}
+
+function addStats(into: SessionStats, s: SessionStats): void {
+  into.totalInputTokens += s.totalInputTokens
+  into.totalOutputTokens += s.totalOutputTokens
+  into.totalCacheRead += s.totalCacheRead
+  into.totalCacheCreation += s.totalCacheCreation
+  if (s.totalCost) into.totalCost = (into.totalCost ?? 0) + s.totalCost
+  if (s.model) into.model = s.model
+}
+
+// Only events inside [since, until] count, so a long-running session
+// contributes just its recent part
+export function buildReport(graphs: Graph[], since: number, until: number, groups: ToolGroups = {}): ActivityReport {
+  const tools = new Map<string, ToolTally>()
+  const days = new Map<string, DayTally>()
+  // Calendar days from local midnight, so a DST change neither repeats nor skips one
+  const d = new Date(since)
+  d.setHours(0, 0, 0, 0)
+  for (; d.getTime() <= until; d.setDate(d.getDate() + 1)) {
+    days.set(dayKey(d.getTime()), { day: dayKey(d.getTime()), sessions: 0, calls: 0, errors: 0 })
+  }
+  const tokens = emptyStats()
+  let sessions = 0
+  let activeMs = 0
+
+  for (const graph of graphs) {
+    const nodes = graph.nodes.filter(n => n.timestamp >= since && n.timestamp <= until)
+    if (nodes.length === 0) continue
+    sessions++
+    addStats(tokens, nodeStats(nodes))
+    const sessionDays = new Set<string>()
+    for (let i = 0; i < nodes.length; i++) {
+      const node = nodes[i]
+      if (i > 0) activeMs += Math.min(IDLE_GAP_MS, Math.max(0, node.timestamp - nodes[i - 1].timestamp))
+      const day = days.get(dayKey(node.timestamp))
+      if (day && !sessionDays.has(day.day)) {
+        sessionDays.add(day.day)
+        day.sessions++
+      }
+      const t = node.nodeType
+      if (t.kind !== "tool_call") continue
+      const name = toolLabel(t.name, groups)
+      const tally = tools.get(name) ?? { name, calls: 0, errors: 0 }
+      tally.calls++
+      if (t.isError) tally.errors++
+      tools.set(name, tally)
+      if (day) {
+        day.calls++
+        if (t.isError) day.errors++
+      }
+    }
+  }
+
+  return {
+    since,
+    until,
+    sessions,
+    activeMs,
+    tokens,
+    tools: [...tools.values()].sort((a, b) => b.calls - a.calls || a.name.localeCompare(b.name)),
+    days: [...days.values()],
+  }
+}
+
+const TOP_TOOLS = 10
+
+function percent(errors: number, calls: number): string {
+  return calls > 0 ? `${Math.round(errors / calls * 100)}%` : "-"
+}
+
+function tokenSummary(s: SessionStats): string {
+  const cost = s.totalCost ? `, $${s.totalCost.toFixed(2)}` : ""
+  return `${formatTokens(totalTokens(s))} (in:${formatTokens(s.totalInputTokens)} out:${formatTokens(s.totalOutputTokens)} cache:${formatTokens(s.totalCacheRead + s.totalCacheCreation)}${cost})`
+}
+
+function formatTable(header: string[], rows: string[][]): string[] {
+  const widths = header.map((h, i) => Math.max(h.length, ...rows.map(r => r[i].length)))
+  const line = (cells: string[]) => cells.map((c, i) => i === 0 ? c.padEnd(widths[i]) : c.padStart(widths[i])).join("  ").trimEnd()
+  return [line(header), ...rows.map(line)]
+}
+
+function toolRows(report: ActivityReport): string[][] {
+  return report.tools.slice(0, TOP_TOOLS).map(t => [t.name, String(t.calls), String(t.errors), percent(t.errors, t.calls)])
+}
+
+function dayRows(report: ActivityReport): string[][] {
+  return report.days.map(d => [d.day, String(d.sessions), String(d.calls), String(d.errors), percent(d.errors, d.calls)])
+}
+
+export function formatReport(report: ActivityReport, label: string): string {
+  const out = [
+    `Activity for the last ${label} (${dayKey(report.since)} to ${dayKey(report.until)})`,
+    "",
+    `Sessions:     ${report.sessions}`,
+    `Active time:  ${formatDuration(report.activeMs)}`,
+    `Tokens:       ${tokenSummary(report.tokens)}`,
+  ]
+  if (report.tools.length > 0) {
+    out.push("", ...formatTable(["Tool", "calls", "errors", "rate"], toolRows(report)))
+  }
+  out.push("", ...formatTable(["Day", "sessions", "calls", "errors", "rate"], dayRows(report)))
+  return out.join("\n") + "\n"
+}
+
+export function reportToJson(report: ActivityReport, label: string, sources: SessionProvenance[] = []): string {
+  return JSON.stringify({
+    schemaVersion: 1,
+    kind: "report",
+    window: label,
+    since: new Date(report.since).toISOString(),
+    until: new Date(report.until).toISOString(),
+    sessions: report.sessions,
+    activeMs: report.activeMs,
+    tokens: statsToJson(report.tokens),
+    tools: report.tools,
+    days: report.days,
+    sources: sourcesToJson(sources),
+  }, null, 2) + "\n"
+}
+
+function markdownTable(header: string[], rows: string[][]): string[] {
+  const align = header.map((_, i) => i === 0 ? "---" : "---:")
+  return [header, align, ...rows].map(cells => `| ${cells.join(" | ")} |`)
+}
+
+export function formatReportMarkdown(report: ActivityReport, label: string, sources: SessionProvenance[] = []): string {
+  const out = [
+    `# Activity for the last ${label}`,
+    "",
+    `${dayKey(report.since)} to ${dayKey(report.until)}`,
+    "",
+    `- **Sessions:** ${report.sessions}`,
+    `- **Active time:** ${formatDuration(report.activeMs)}`,
+    `- **Tokens:** ${tokenSummary(report.tokens)}`,
+  ]
+  if (report.tools.length > 0) {
+    out.push("", "## Top tools", "", ...markdownTable(["Tool", "Calls", "Errors", "Rate"], toolRows(report)))
+  }
+  out.push("", "## Errors by day", "", ...markdownTable(["Day", "Sessions", "Calls", "Errors", "Rate"], dayRows(report)))
+  if (sources.length > 0) out.push("", sourcesMarkdown(sources))
+  return out.join("\n") + "\n"
+}
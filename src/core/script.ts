@@ -0,0 +1,61 @@
+import type { Node } from "./types"
+import { shellCommand } from "./testruns"
+import type { Provenance } from "./provenance"
+import { provenanceComment } from "./provenance"
+import { formatClock } from "./time"
+
+// A range of the session as the shell commands the agent ran, in order, so
+// its environment changes can be replayed by hand. Each command is commented
+// with the node it came from; nothing stops at a failure, since the agent
+// didn't either.
+
+export type ScriptMeta = { sessionId: string; exportedAt: number; provenance?: Provenance }
+
+type BashInput = { description?: unknown; run_in_background?: unknown }
+
+function bashInput(node: Node): BashInput {
+  if (node.nodeType.kind !== "tool_call") return {}
+  try {
+    const input = JSON.parse(node.nodeType.input) as unknown
+    return input && typeof input === "object" ? input as BashInput : {}
+  } catch {
+    return {}
+  }
+}
+
+function oneLine(text: string): string {
+  return text.replace(/\s+/g, " ").trim()
+}
+
+function commandBlock(node: Node, command: string): string[] {
+  const t = node.nodeType
+  const input = bashInput(node)
+  const notes = [formatClock(node.timestamp, { utc: true })]
+  if (node.agentId) notes.push(`agent ${node.agentId}`)
+  if (t.kind === "tool_call" && t.output === null) notes.push("still running")
+  else if (t.kind === "tool_call" && t.isError) notes.push("failed")
+  const lines = [`# ${node.id} ${notes.join(", ")}`]
+  if (typeof input.description === "string" && input.description.trim()) lines.push(`# ${oneLine(input.description)}`)
+  const background = input.run_in_background === true
+  if (background) lines.push("# ran in the background")
+  lines.push(background ? `${command.trimEnd()} &` : command.trimEnd())
+  return lines
+}
+
+// nodes: the selected range, in session order
+export function toShellScript(nodes: Node[], meta: ScriptMeta): string {
+  const blocks: string[][] = []
+  for (const node of nodes) {
+    const command = shellCommand(node)
+    if (command !== null && command.trim()) blocks.push(commandBlock(node, command))
+  }
+  const header = [
+    "#!/usr/bin/env bash",
+    `# Shell commands from session ${meta.sessionId}`,
+    nodes.length > 0 ? `# Nodes ${nodes[0].id} to ${nodes[nodes.length - 1].id}` : "# No nodes selected",
+    `# Exported ${new Date(meta.exportedAt).toISOString()}. Read it before running: commands replay as they were.`,
+    ...(meta.provenance ? provenanceComment(meta.provenance) : []),
+  ]
+  if (blocks.length === 0) header.push("", "# No shell commands in this range")
+  return [header.join("\n"), ...blocks.map(b => b.join("\n"))].join("\n\n") + "\n"
+}
@@ -0,0 +1,89 @@
+import type { SessionInfo } from "./types"
+
+// The id without its source prefix, cut to 8 characters, for tables,
+// messages and file names
+export function shortSessionId(sessionId: string): string {
+  return sessionId.replace(/^.*:/, "").slice(0, 8)
+}
+
+// Shallow compare of what the picker shows, so an unchanged refresh keeps
+// the previous list and React skips the redraw
+export function sameSessions(a: SessionInfo[], b: SessionInfo[]): boolean {
+  if (a.length !== b.length) return false
+  for (let i = 0; i < a.length; i++) {
+    const x = a[i]
+    const y = b[i]
+    if (x.id !== y.id || x.timestamp !== y.timestamp || x.nodeCount !== y.nodeCount || x.waitingForUser !== y.waitingForUser) {
+      return false
+    }
+  }
+  return true
+}
+
+// The state update for a refresh: the previous list itself when nothing changed
+export function refreshedSessions(prev: SessionInfo[], next: SessionInfo[]): SessionInfo[] {
+  return sameSessions(prev, next) ? prev : next
+}
+
+// Tab / Shift+Tab: the session `step` away from the current one among the
+// first `size` of the list, wrapping; from a session outside them, the first.
+// Null when there is nothing to switch to.
+export function cycleRecentSession(sessions: SessionInfo[], currentId: string, step: 1 | -1, size: number): { index: number; total: number; id: string } | null {
+  const recent = sessions.slice(0, size)
+  if (recent.length < 2) return null
+  const idx = recent.findIndex(s => s.id === currentId)
+  const index = idx < 0 ? 0 : (idx + step + recent.length) % recent.length
+  return { index, total: recent.length, id: recent[index].id }
+}
+
+export type SessionSort = "activity" | "started" | "events" | "waiting"
+
+const SORT_ORDER: SessionSort[] = ["activity", "started", "events", "waiting"]
+
+export function nextSessionSort(sort: SessionSort): SessionSort {
+  return SORT_ORDER[(SORT_ORDER.indexOf(sort) + 1) % SORT_ORDER.length]
+}
+
+export function getSessionSortLabel(sort: SessionSort): string {
+  switch (sort) {
+    case "activity": return "last activity"
+    case "started": return "start time"
+    case "events": return "event count"
+    case "waiting": return "waiting first"
+  }
+}
+
+// Newest first for every mode; ties fall back to last activity
+export function sortSessions(sessions: SessionInfo[], sort: SessionSort): SessionInfo[] {
+  const byActivity = (a: SessionInfo, b: SessionInfo) => b.timestamp - a.timestamp
+  const sorted = [...sessions]
+  switch (sort) {
+    case "activity":
+      return sorted.sort(byActivity)
+    case "started":
+      return sorted.sort((a, b) => (b.startedAt ?? b.timestamp) - (a.startedAt ?? a.timestamp) || byActivity(a, b))
+    case "events":
+      return sorted.sort((a, b) => b.nodeCount - a.nodeCount || byActivity(a, b))
+    case "waiting":
+      return sorted.sort((a, b) => Number(b.waitingForUser) - Number(a.waitingForUser) || byActivity(a, b))
+  }
+}
+
+// Case-insensitive substring match over id, title, directory and attached note
+export function filterSessions(
+  sessions: SessionInfo[],
+  query: string,
+  noteFor: (session: SessionInfo) => string | undefined,
+): SessionInfo[] {
+  const q = query.trim().toLowerCase()
+  if (!q) return sessions
+  return sessions.filter(s =>
+    [s.id, s.title, s.directory, noteFor(s)].some(field => field?.toLowerCase().includes(q))
+  )
+}
+
+// Changes whenever the session's transcript does — a cached graph is only
+// reused while its stamp still matches
+export function sessionStamp(info: SessionInfo | undefined): string | null {
+  return info ? `${info.timestamp}:${info.nodeCount}:${info.sizeBytes ?? ""}` : null
+}
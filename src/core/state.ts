@@ -0,0 +1,48 @@
+import type { Graph, Node } from "./types"
+import type { Lifecycle } from "./lifecycle"
+
+// Building blocks for where a session stands, judged from its nodes alone:
+// handed back to the user, or quiet long enough to call it done. lifecycle.ts
+// combines them into the session's state.
+
+// No new events for this long and the session counts as ended
+export const ENDED_AFTER_MS = 30 * 60_000
+
+// Mirrors isWaitingForUser on events: the last main-session node being a reply
+// means control is back with the user; a prompt or tool call means it isn't
+export function isAwaitingUser(nodes: Node[]): boolean {
+  for (let i = nodes.length - 1; i >= 0; i--) {
+    const node = nodes[i]
+    if (node.agentId) continue
+    const kind = node.nodeType.kind
+    if (kind === "assistant") return true
+    if (kind === "user" || kind === "tool_call" || kind === "tool_use" || kind === "tool_result") return false
+  }
+  return false
+}
+
+export function lastActivity(nodes: Node[]): number | null {
+  return nodes.length > 0 ? nodes[nodes.length - 1].timestamp : null
+}
+
+// Events worth telling someone about when nobody is watching the screen
+export type LiveEventKind = "waiting" | "tool_error" | "completed"
+
+export type LiveEvent = { kind: LiveEventKind; sessionId: string; nodeId?: string; message: string; state?: Lifecycle } // state: the session's, once the event happened
+
+// What changed between two reads of the same session. "completed" can't be
+// seen in a diff — it's the absence of change, so callers time it themselves.
+export function diffLiveEvents(prev: Graph, next: Graph, sessionId: string): LiveEvent[] {
+  const events: LiveEvent[] = []
+  const failedBefore = new Set(prev.nodes.filter(n => n.nodeType.kind === "tool_call" && n.nodeType.isError).map(n => n.id))
+  for (const node of next.nodes) {
+    const t = node.nodeType
+    if (t.kind !== "tool_call" || !t.isError || failedBefore.has(node.id)) continue
+    const firstLine = (t.output ?? "").trim().split("\n")[0].slice(0, 200)
+    events.push({ kind: "tool_error", sessionId, nodeId: node.id, message: `${t.name} failed: ${firstLine}` })
+  }
+  if (!isAwaitingUser(prev.nodes) && isAwaitingUser(next.nodes)) {
+    events.push({ kind: "waiting", sessionId, message: "waiting for input" })
+  }
+  return events
+}
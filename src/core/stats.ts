@@ -1,4 +1,4 @@
-import type { SessionStats, Usage } from "./types"
+import type { Node, SessionStats, Usage } from "./types"
 
 export type TokenInput = {
   usage?: Usage
@@ -13,6 +13,7 @@ export function computeStats(items: TokenInput[]): SessionStats {
   let totalCacheCreation = 0
   let totalReasoningTokens = 0
   let totalCost = 0
+  let contextTokens = 0
   let model: string | null = null
 
   for (const item of items) {
@@ -23,6 +24,7 @@ export function computeStats(items: TokenInput[]): SessionStats {
       totalCacheRead += u.cache_read_input_tokens ?? 0
       totalCacheCreation += u.cache_creation_input_tokens ?? 0
       totalReasoningTokens += u.reasoning_tokens ?? 0
+      contextTokens = (u.input_tokens ?? 0) + (u.cache_read_input_tokens ?? 0) + (u.cache_creation_input_tokens ?? 0)
     }
     if (item.cost) totalCost += item.cost
     if (item.model) model = item.model
@@ -36,9 +38,50 @@ export function computeStats(items: TokenInput[]): SessionStats {
     model,
     totalCost: totalCost || undefined,
     totalReasoningTokens: totalReasoningTokens || undefined,
+    contextTokens: contextTokens || undefined,
   }
 }
 
+export function formatTokens(n: number): string {
+  if (n >= 1_000_000) return `${(n / 1_000_000).toFixed(1)}M`
+  if (n >= 1_000) return `${(n / 1_000).toFixed(1)}k`
+  return String(n)
+}
+
+// Token counts in the field names shared by every --json output
+export function statsToJson(stats: SessionStats) {
+  return {
+    input: stats.totalInputTokens,
+    output: stats.totalOutputTokens,
+    cacheRead: stats.totalCacheRead,
+    cacheCreation: stats.totalCacheCreation,
+    total: totalTokens(stats),
+    cost: stats.totalCost ?? null,
+  }
+}
+
+// Everything billed so far: input, output and both kinds of cache traffic
+export function totalTokens(stats: SessionStats): number {
+  return stats.totalInputTokens + stats.totalOutputTokens + stats.totalCacheRead + stats.totalCacheCreation
+}
+
+// Stats for a slice of a graph. Nodes split from one message (text + its tool
+// calls) share the message's usage object, so each object is counted once.
+export function nodeStats(nodes: Node[]): SessionStats {
+  const seen = new Set<object>()
+  const items: TokenInput[] = []
+  let repeated = 0
+  for (const n of nodes) {
+    if (n.repeatCount) repeated += n.repeatCount - 1
+    if (n.usage && seen.has(n.usage)) continue
+    if (n.usage) seen.add(n.usage)
+    items.push({ usage: n.usage, model: n.model, cost: n.cost })
+  }
+  const stats = computeStats(items)
+  if (repeated > 0) stats.repeatedToolCalls = repeated
+  return stats
+}
+
 export function emptyStats(): SessionStats {
   return {
     totalInputTokens: 0,
@@ -0,0 +1,94 @@
+import type { Graph, Node } from "./types"
+import type { Lifecycle } from "./lifecycle"
+import { sessionLifecycle, latestTurnFailed, pendingTool, isAgentTurn, LIFECYCLE_LABELS, IDLE_AFTER_MS } from "./lifecycle"
+import { lastActivity, diffLiveEvents } from "./state"
+import { getTurnBounds } from "./turns"
+import { formatDuration } from "./time"
+import { shortSessionId } from "./sessions"
+
+// One-shot answer to "what is the agent doing right now?", for status lines
+// and scripts rather than the TUI
+
+export type SessionStatus = {
+  sessionId: string
+  state: Lifecycle
+  activeTool: { name: string; elapsedMs: number } | null // newest call still waiting for its result
+  turnElapsedMs: number // since the current turn's prompt
+  idleMs: number // since the last event
+  lastTurnFailed: boolean // a failed tool call in the latest turn was never retried successfully
+}
+
+// Exit statuses of `status` and `wait`, for scripts: 0 = your turn, 1 = still
+// working, 2 = stopped after an error. Anything else is a usage problem.
+export const EXIT_WAITING = 0
+export const EXIT_RUNNING = 1
+export const EXIT_ERROR = 2
+export const EXIT_USAGE = 3
+
+// A running agent may still recover, so only a stopped session reads as an error
+export function lifecycleExitCode(state: Lifecycle): number {
+  if (isAgentTurn(state)) return EXIT_RUNNING
+  return state === "error" ? EXIT_ERROR : EXIT_WAITING
+}
+
+export function statusExitCode(status: SessionStatus): number {
+  return lifecycleExitCode(status.state)
+}
+
+export function summarizeStatus(nodes: Node[], sessionId: string, now: number): SessionStatus {
+  const state = sessionLifecycle(nodes, now)
+  const pending = state === "tool-running" ? pendingTool(nodes) : null
+  const call = pending !== null ? nodes[pending] : null
+  const t = call?.nodeType
+  const activeTool = call && t?.kind === "tool_call" ? { name: t.name, elapsedMs: now - call.timestamp } : null
+  const turn = nodes.length > 0 ? getTurnBounds(nodes, nodes.length - 1) : null
+  const turnStart = turn ? nodes[turn.start].timestamp : now
+  return {
+    sessionId,
+    state,
+    activeTool,
+    turnElapsedMs: Math.max(0, now - turnStart),
+    idleMs: Math.max(0, now - (lastActivity(nodes) ?? now)),
+    lastTurnFailed: latestTurnFailed(nodes),
+  }
+}
+
+// "a1b2c3d4 RUNNING Bash 2m10s" / "a1b2c3d4 WAITING 3m05s" / "a1b2c3d4 ENDED"
+export function formatStatusBrief(status: SessionStatus): string {
+  const parts = [shortSessionId(status.sessionId), LIFECYCLE_LABELS[status.state]]
+  if (status.activeTool) parts.push(status.activeTool.name, formatDuration(status.activeTool.elapsedMs))
+  else if (status.state === "streaming" || status.state === "starting") parts.push(formatDuration(status.turnElapsedMs))
+  else if (status.state !== "ended") parts.push(formatDuration(status.idleMs))
+  return parts.join(" ")
+}
+
+export function formatStatus(status: SessionStatus): string {
+  const out = [
+    `Session:  ${status.sessionId}`,
+    `State:    ${LIFECYCLE_LABELS[status.state]}`,
+  ]
+  if (status.activeTool) out.push(`Running:  ${status.activeTool.name} for ${formatDuration(status.activeTool.elapsedMs)}`)
+  out.push(`Turn:     ${formatDuration(status.turnElapsedMs)}`)
+  out.push(`Idle:     ${formatDuration(status.idleMs)}`)
+  if (status.lastTurnFailed) out.push("Errors:   the latest turn has a failed tool call")
+  return out.join("\n")
+}
+
+// `vizier wait --until …`: waiting = the agent's turn is over, error = a tool
+// call failed after the wait began, idle = the agent's turn went quiet, or
+// the session stopped and stayed quiet, for the idle period. All but error
+// read the same lifecycle as `status`, with idleMs as its idle threshold.
+export type WaitCondition = "waiting" | "error" | "idle"
+
+export function isWaitCondition(value: string): value is WaitCondition {
+  return value === "waiting" || value === "error" || value === "idle"
+}
+
+export function waitSatisfied(condition: WaitCondition, baseline: Graph, current: Graph, now: number, idleMs = IDLE_AFTER_MS): boolean {
+  const state = sessionLifecycle(current.nodes, now, idleMs)
+  switch (condition) {
+    case "waiting": return !isAgentTurn(state)
+    case "error": return diffLiveEvents(baseline, current, "").some(e => e.kind === "tool_error")
+    case "idle": return state === "idle" || (!isAgentTurn(state) && now - (lastActivity(current.nodes) ?? 0) > idleMs)
+  }
+}
@@ -0,0 +1,34 @@
+import type { Graph, Node, SessionStats } from "./types"
+
+// Wire protocol for `vizier serve`, one JSON object per WebSocket message:
+//   hello    — first message on every connection
+//   snapshot — the whole session, sent right after hello
+//   append   — the session changed: drop nodes from index `from` on, then
+//              append `nodes` (a tool call getting its result re-sends that node)
+export const STREAM_PROTOCOL = 1
+
+export type StreamMessage =
+  | { type: "hello"; protocol: number; sessionId: string; source: string }
+  | { type: "snapshot"; sessionId: string; nodes: Node[]; stats: SessionStats }
+  | { type: "append"; sessionId: string; from: number; nodes: Node[]; stats: SessionStats }
+
+function sameNode(a: Node, b: Node): boolean {
+  if (a.id !== b.id || a.nodeType.kind !== b.nodeType.kind || a.repeatCount !== b.repeatCount) return false
+  const x = a.nodeType
+  const y = b.nodeType
+  if (x.kind === "tool_call" && y.kind === "tool_call") return x.output === y.output && x.isError === y.isError
+  return true
+}
+
+export function firstChangedIndex(prev: Node[], next: Node[]): number {
+  let i = 0
+  while (i < prev.length && i < next.length && sameNode(prev[i], next[i])) i++
+  return i
+}
+
+// null when the update changed nothing a client could see
+export function appendMessage(prev: Graph, next: Graph, sessionId: string): StreamMessage | null {
+  const from = firstChangedIndex(prev.nodes, next.nodes)
+  if (from === prev.nodes.length && from === next.nodes.length) return null
+  return { type: "append", sessionId, from, nodes: next.nodes.slice(from), stats: next.stats }
+}
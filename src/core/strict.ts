@@ -0,0 +1,9 @@
+import type { ParseIssue } from "./types"
+
+// --strict: any skipped line aborts, reported with its location
+export function formatStrictFailure(issues: ParseIssue[]): string {
+  const [first] = issues
+  const others = issues.length - 1
+  return `strict: ${first.file}:${first.line}: ${first.message}`
+    + (others > 0 ? `\n(${others} more skipped line${others === 1 ? "" : "s"}; run vizier validate for the full list)` : "")
+}
@@ -0,0 +1,41 @@
+import type { Graph, Node } from "./types"
+import { listTurns, isTurnStart } from "./turns"
+import { lastActivity, ENDED_AFTER_MS } from "./state"
+
+// --summarize: a long archived session cut down to what each turn asked and
+// concluded, plus everything that failed. The turn's first kept node records
+// how many were left out; X loads the session in full again.
+
+// Sessions still going are never summarized — their detail is the point
+export function isHistorical(graph: Graph, now: number): boolean {
+  const last = lastActivity(graph.nodes)
+  return last !== null && now - last > ENDED_AFTER_MS
+}
+
+function isFailure(node: Node): boolean {
+  const t = node.nodeType
+  return (t.kind === "tool_call" || t.kind === "tool_result") && t.isError
+}
+
+// Stats stay the whole session's: the summary covers everything it spent
+export function summarizeGraph(graph: Graph): Graph {
+  const nodes: Node[] = []
+  for (const { start, end } of listTurns(graph.nodes)) {
+    let reply = -1
+    for (let i = end - 1; i >= start; i--) {
+      const n = graph.nodes[i]
+      if (n.nodeType.kind === "assistant" && !n.agentId) { reply = i; break }
+    }
+    const kept: Node[] = []
+    for (let i = start; i < end; i++) {
+      const n = graph.nodes[i]
+      if ((i === start && isTurnStart(n)) || i === reply || isFailure(n)) kept.push(n)
+    }
+    const omitted = end - start - kept.length
+    if (kept.length > 0 && omitted > 0) kept[0] = { ...kept[0], summarized: omitted }
+    nodes.push(...kept)
+  }
+  if (nodes.length === graph.nodes.length) return graph
+  const ids = new Set(nodes.map(n => n.id))
+  return { ...graph, nodes, edges: graph.edges.filter(e => ids.has(e.from) && ids.has(e.to)) }
+}
@@ -0,0 +1,106 @@
+import type { Node } from "./types"
+
+// Test runs in shell tool calls: which calls ran a test suite, and how many
+// tests passed or failed going by the runner's summary. Over a session the
+// sequence of runs is the clearest sign of whether the agent is getting there.
+
+export type TestRun = {
+  nodeIdx: number
+  timestamp: number
+  runner: string // the command that matched, e.g. "cargo test"
+  passed: number | null // null when the output had no recognizable summary
+  failed: number | null
+  ok: boolean // exited cleanly with no failed tests
+}
+
+const SHELL_TOOLS = new Set(["bash", "shell"])
+
+export function shellCommand(node: Node): string | null {
+  const t = node.nodeType
+  if (t.kind !== "tool_call" || !SHELL_TOOLS.has(t.name.toLowerCase())) return null
+  try {
+    const command = (JSON.parse(t.input) as { command?: unknown }).command
+    return typeof command === "string" ? command : null
+  } catch {
+    return null
+  }
+}
+
+// Runner invocations anywhere in the command line, so `cd api && pytest -x` counts
+const TEST_RUNNERS = [
+  /\bcargo (?:test|nextest run)\b/,
+  /\b(?:python3? -m )?pytest\b/,
+  /\b(?:npm|pnpm|yarn|bun)(?: run)? test\b/,
+  /\bgo test\b/,
+  /\b(?:npx )?(?:jest|vitest|mocha)\b/,
+  /\b(?:bundle exec )?rspec\b/,
+  /\b(?:mvn|gradle|\.\/gradlew) test\b/,
+  /\bdotnet test\b/,
+  /\bmake (?:test|check)\b/,
+]
+
+export function testRunner(command: string): string | null {
+  for (const pattern of TEST_RUNNERS) {
+    const m = pattern.exec(command)
+    if (m) return m[0]
+  }
+  return null
+}
+
+// Shared with build diagnostics: the total of every match's first group, or
+// null when nothing matched
+export function sum(text: string, pattern: RegExp): number | null {
+  let total: number | null = null
+  for (const m of text.matchAll(pattern)) total = (total ?? 0) + Number(m[1])
+  return total
+}
+
+export function countLines(text: string, pattern: RegExp): number | null {
+  const n = text.match(pattern)?.length ?? 0
+  return n > 0 ? n : null
+}
+
+// Summary lines of the common runners: cargo's "test result:" lines (one per
+// test binary, summed), jest's "Tests:" line, pytest and vitest's "N passed",
+// bun's "N pass", mocha's "N passing", rspec's "N examples, M failures" and
+// go's per-test "--- PASS/FAIL" lines
+export function parseTestCounts(output: string): { passed: number | null; failed: number | null } {
+  const cargo = [...output.matchAll(/test result: \w+\. (\d+) passed; (\d+) failed/g)]
+  if (cargo.length > 0) {
+    return { passed: cargo.reduce((n, m) => n + Number(m[1]), 0), failed: cargo.reduce((n, m) => n + Number(m[2]), 0) }
+  }
+  const jest = /^\s*Tests:?\s+(.*)$/m.exec(output) // jest and vitest; vitest also has a "Test Files" line
+  const text = jest ? jest[1] : output
+  const rspec = /(\d+) examples?, (\d+) failures?/.exec(text)
+  if (rspec) return { passed: Number(rspec[1]) - Number(rspec[2]), failed: Number(rspec[2]) }
+  const passed = sum(text, /\b(\d+) (?:passed|pass|passing)\b/g) ?? countLines(text, /^\s*--- PASS/gm)
+  const failed = sum(text, /\b(\d+) (?:failed|fail|failing)\b/g) ?? countLines(text, /^\s*--- FAIL/gm)
+  return { passed, failed }
+}
+
+export function findTestRuns(nodes: Node[]): TestRun[] {
+  const runs: TestRun[] = []
+  nodes.forEach((node, nodeIdx) => {
+    const t = node.nodeType
+    const command = shellCommand(node)
+    const runner = command ? testRunner(command) : null
+    if (!runner || t.kind !== "tool_call" || t.output === null) return
+    const { passed, failed } = parseTestCounts(t.output)
+    // A parsed failure count that disagrees with the exit status still means failure
+    const ok = !t.isError && !failed
+    runs.push({ nodeIdx, timestamp: node.timestamp, runner, passed, failed: failed ?? (ok ? 0 : null), ok })
+  })
+  return runs
+}
+
+// The last run at or before a node, -1 before the first
+export function runAt(runs: TestRun[], nodeIdx: number): number {
+  let found = -1
+  for (let i = 0; i < runs.length && runs[i].nodeIdx <= nodeIdx; i++) found = i
+  return found
+}
+
+export function formatTestCounts(run: TestRun): string {
+  if (run.passed === null && run.failed === null) return run.ok ? "passed" : "failed"
+  return `${run.passed ?? 0} passed, ${run.failed ?? 0} failed`
+}
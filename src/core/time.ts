@@ -0,0 +1,21 @@
+// Compact durations: 42s, 3m05s, 1h12m
+export function formatDuration(ms: number): string {
+  const s = Math.max(0, Math.round(ms / 1000))
+  if (s < 60) return `${s}s`
+  if (s < 3600) return `${Math.floor(s / 60)}m${String(s % 60).padStart(2, "0")}s`
+  return `${Math.floor(s / 3600)}h${String(Math.floor(s / 60) % 60).padStart(2, "0")}m`
+}
+
+// Time of day: 14:02:11, or 14:02 without seconds. Exports and scripts read
+// in UTC, so the same transcript renders the same on any machine.
+export function formatClock(ts: number, { seconds = true, utc = false }: { seconds?: boolean; utc?: boolean } = {}): string {
+  const d = new Date(ts)
+  const parts = utc ? [d.getUTCHours(), d.getUTCMinutes(), d.getUTCSeconds()] : [d.getHours(), d.getMinutes(), d.getSeconds()]
+  return parts.slice(0, seconds ? 3 : 2).map(n => String(n).padStart(2, "0")).join(":")
+}
+
+// Minutes since local midnight, for after:/before: filters and time: jumps
+export function minuteOfDay(ts: number): number {
+  const d = new Date(ts)
+  return d.getHours() * 60 + d.getMinutes()
+}
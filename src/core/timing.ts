@@ -0,0 +1,72 @@
+import type { Node } from "./types"
+import { toolKey } from "./compare"
+import { formatDuration } from "./time"
+
+// How long each tool takes across the session, from the call → result
+// pairing: tools that often run close to the tool timeout, or whose
+// durations swing widely, are candidates for running in the background.
+// Shell calls are split by command, like the tool comparison.
+
+export const TOOL_TIMEOUT_MS = 120_000 // Claude Code's default Bash timeout
+export const NEAR_TIMEOUT = 0.8 // of the timeout
+const MIN_CALLS_FOR_SPREAD = 5
+const SPREAD_CV = 1 // standard deviation at least the mean
+const SLOW_P95_MS = 10_000 // spread only matters once some calls are slow
+
+export type ToolTiming = {
+  key: string // tool name, or "Bash: <command>"
+  calls: number
+  medianMs: number
+  p95Ms: number
+  maxMs: number
+  cv: number // coefficient of variation: standard deviation / mean
+  nearTimeout: number // calls that ran past NEAR_TIMEOUT of the timeout
+}
+
+function quantile(sorted: number[], q: number): number {
+  return sorted[Math.floor(q * (sorted.length - 1))]
+}
+
+export function toolTimings(nodes: Node[]): ToolTiming[] {
+  const durations = new Map<string, number[]>()
+  for (const node of nodes) {
+    const key = toolKey(node)
+    if (!key || node.endTimestamp === undefined) continue
+    const list = durations.get(key) ?? []
+    list.push(Math.max(0, node.endTimestamp - node.timestamp))
+    durations.set(key, list)
+  }
+  const rows: ToolTiming[] = []
+  for (const [key, list] of durations) {
+    const sorted = list.sort((a, b) => a - b)
+    const mean = sorted.reduce((s, d) => s + d, 0) / sorted.length
+    const sd = Math.sqrt(sorted.reduce((s, d) => s + (d - mean) ** 2, 0) / sorted.length)
+    rows.push({
+      key,
+      calls: sorted.length,
+      medianMs: quantile(sorted, 0.5),
+      p95Ms: quantile(sorted, 0.95),
+      maxMs: sorted[sorted.length - 1],
+      cv: mean > 0 ? sd / mean : 0,
+      nearTimeout: sorted.filter(d => d >= TOOL_TIMEOUT_MS * NEAR_TIMEOUT).length,
+    })
+  }
+  return rows.sort((a, b) => b.nearTimeout - a.nearTimeout || b.p95Ms - a.p95Ms || a.key.localeCompare(b.key))
+}
+
+export function isErratic(t: ToolTiming): boolean {
+  return t.calls >= MIN_CALLS_FOR_SPREAD && t.cv >= SPREAD_CV && t.p95Ms >= SLOW_P95_MS
+}
+
+// Null when the tool is fine as it is
+export function timingAdvice(t: ToolTiming): string | null {
+  if (t.nearTimeout > 0) {
+    return `${t.nearTimeout} of ${t.calls} near the ${TOOL_TIMEOUT_MS / 60_000}m timeout: run it in the background`
+  }
+  if (isErratic(t)) return `durations vary widely (p50 ${formatDuration(t.medianMs)}, p95 ${formatDuration(t.p95Ms)}): a candidate for the background`
+  return null
+}
+
+export function flaggedTimings(nodes: Node[]): ToolTiming[] {
+  return toolTimings(nodes).filter(t => timingAdvice(t) !== null)
+}
@@ -0,0 +1,115 @@
+import type { Graph, SessionInfo } from "./types"
+import type { SessionStatus } from "./status"
+import { summarizeStatus } from "./status"
+import { LIFECYCLE_LABELS } from "./lifecycle"
+import { shortSessionId } from "./sessions"
+import { ENDED_AFTER_MS } from "./state"
+import { nodeStats, totalTokens, formatTokens } from "./stats"
+import { formatDuration, formatClock } from "./time"
+import { displayWidth, padStartToWidth, padToWidth, truncateToWidth } from "../ui/width"
+
+// Sessions running side by side, ranked by how fast they're spending tokens
+// right now, so the one eating the budget is always on top
+
+// Burn rates are measured over this much recent activity
+export const TOP_WINDOW_MS = 5 * 60_000
+export const TOP_REFRESH_MS = 2000
+
+export type SessionActivity = {
+  sessionId: string
+  title?: string
+  status: SessionStatus
+  tokens: number // inside the window
+  tokensPerMin: number
+  cost: number | null
+  toolCalls: number // inside the window
+  errors: number
+}
+
+// The session list already knows when each was last touched, so ended ones
+// are dropped before reading their transcripts
+export function isRecentSession(info: SessionInfo, now: number): boolean {
+  return now - info.timestamp <= ENDED_AFTER_MS
+}
+
+export function sessionActivity(graph: Graph, info: SessionInfo, now: number, windowMs = TOP_WINDOW_MS): SessionActivity {
+  const recent = graph.nodes.filter(n => n.timestamp > now - windowMs && n.timestamp <= now)
+  const stats = nodeStats(recent)
+  // A session younger than the window is measured over its age, but at least
+  // a minute so one early burst doesn't read as a runaway rate
+  const started = graph.nodes[0]?.timestamp ?? now
+  const spanMs = Math.max(60_000, Math.min(windowMs, now - started))
+  const tokens = totalTokens(stats)
+  let toolCalls = 0
+  let errors = 0
+  for (const node of recent) {
+    const t = node.nodeType
+    if (t.kind !== "tool_call") continue
+    toolCalls++
+    if (t.isError) errors++
+  }
+  return {
+    sessionId: info.id,
+    title: info.title,
+    status: summarizeStatus(graph.nodes, info.id, now),
+    tokens,
+    tokensPerMin: tokens / (spanMs / 60_000),
+    cost: stats.totalCost ?? null,
+    toolCalls,
+    errors,
+  }
+}
+
+export function rankActivity(rows: SessionActivity[]): SessionActivity[] {
+  return [...rows].sort((a, b) => b.tokensPerMin - a.tokensPerMin || b.toolCalls - a.toolCalls || a.sessionId.localeCompare(b.sessionId))
+}
+
+function doing(status: SessionStatus): string {
+  if (status.activeTool) return `${status.activeTool.name} ${formatDuration(status.activeTool.elapsedMs)}`
+  if (status.state === "waiting-for-user") return `idle ${formatDuration(status.idleMs)}`
+  return ""
+}
+
+// label: the window as given on the command line, e.g. "5m"
+export function formatTop(rows: SessionActivity[], now: number, label: string): string {
+  const out = [`vizier top — ${formatClock(now)}, rates over the last ${label}`, ""]
+  if (rows.length === 0) return out.concat(`No sessions active in the last ${ENDED_AFTER_MS / 60_000}m`).join("\n") + "\n"
+  const header = ["Session", "State", "tok/min", "tokens", "cost", "tools", "errors", "Doing", "Title"]
+  const cells = rows.map(r => [
+    shortSessionId(r.sessionId),
+    LIFECYCLE_LABELS[r.status.state],
+    formatTokens(Math.round(r.tokensPerMin)),
+    formatTokens(r.tokens),
+    r.cost !== null ? `$${r.cost.toFixed(2)}` : "-",
+    String(r.toolCalls),
+    String(r.errors),
+    doing(r.status),
+    truncateToWidth(r.title ?? "", 40),
+  ])
+  const widths = header.map((h, i) => Math.max(displayWidth(h), ...cells.map(c => displayWidth(c[i]))))
+  // Text columns left-aligned, numbers right-aligned
+  const left = new Set([0, 1, 7, 8])
+  const line = (c: string[]) => c.map((s, i) => left.has(i) ? padToWidth(s, widths[i]) : padStartToWidth(s, widths[i])).join("  ").trimEnd()
+  return out.concat(line(header), cells.map(line)).join("\n") + "\n"
+}
+
+export function topToJson(rows: SessionActivity[], now: number, label: string, windowMs: number): string {
+  return JSON.stringify({
+    schemaVersion: 1,
+    kind: "top",
+    at: new Date(now).toISOString(),
+    window: label,
+    windowMs,
+    sessions: rows.map(r => ({
+      sessionId: r.sessionId,
+      title: r.title ?? null,
+      state: r.status.state,
+      activeTool: r.status.activeTool,
+      tokens: r.tokens,
+      tokensPerMin: Math.round(r.tokensPerMin),
+      cost: r.cost,
+      toolCalls: r.toolCalls,
+      errors: r.errors,
+    })),
+  }, null, 2) + "\n"
+}
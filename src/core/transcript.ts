@@ -0,0 +1,116 @@
+import type { Node } from "./types"
+import type { MdSpan } from "./markdown"
+import { renderMarkdown } from "./markdown"
+import { wrapRanges, truncateToWidth } from "../ui/width"
+import { formatClock } from "./time"
+
+// Chat-style rendering of a session: prompts and replies in full, tools and
+// agents as one-line annotations. Each line remembers the node it came from so
+// the view can follow the timeline cursor.
+
+export type TranscriptRole = "user" | "assistant" | "tool" | "error" | "meta"
+
+// spans: markdown styling for assistant text, covering the whole line
+export type TranscriptLine = { nodeIdx: number; text: string; role: TranscriptRole; heading?: boolean; spans?: MdSpan[] }
+
+function wrap(text: string, width: number): string[] {
+  const out: string[] = []
+  for (const raw of text.split("\n")) {
+    const line = raw.trimEnd()
+    if (line === "") { out.push(""); continue }
+    for (const [from, to] of wrapRanges(line, width)) out.push(line.slice(from, to))
+  }
+  return out
+}
+
+function oneLine(text: string, width: number): string {
+  const clean = text.replace(/\s+/g, " ").trim()
+  return truncateToWidth(clean, width, "...")
+}
+
+export function buildTranscript(nodes: Node[], width: number): TranscriptLine[] {
+  const lines: TranscriptLine[] = []
+  const w = Math.max(20, width)
+  nodes.forEach((node, nodeIdx) => {
+    const t = node.nodeType
+    const indent = node.agentId ? "  " : ""
+    const push = (text: string, role: TranscriptRole) => lines.push({ nodeIdx, text: indent + text, role })
+    switch (t.kind) {
+      case "user": {
+        if (lines.length > 0) push("", "meta")
+        push("User:", "user")
+        for (const l of wrap(t.text, w - indent.length - 2)) push(`  ${l}`, "user")
+        break
+      }
+      case "assistant": {
+        if (lines.length > 0) push("", "meta")
+        push("Assistant:", "assistant")
+        const prefix = `${indent}  `
+        for (const l of renderMarkdown(t.text, w - prefix.length)) {
+          lines.push({ nodeIdx, text: prefix + l.text, role: "assistant", spans: [{ text: prefix }, ...l.spans] })
+        }
+        break
+      }
+      case "tool_call": {
+        const status = t.output === null ? "running" : t.isError ? "error" : "ok"
+        push(`  [${t.name} ${status}] ${oneLine(t.input, w - t.name.length - 15)}`, t.isError ? "error" : "tool")
+        break
+      }
+      case "tool_use":
+        push(`  [${t.name}] ${oneLine(t.input, w - t.name.length - 8)}`, "tool")
+        break
+      case "tool_result":
+        push(`  [result ${t.isError ? "error" : "ok"}] ${oneLine(t.output, w - 20)}`, t.isError ? "error" : "tool")
+        break
+      case "agent_start":
+        push(`  -- agent ${t.agentType} started --`, "meta")
+        break
+      case "agent_end":
+        push("  -- agent finished --", "meta")
+        break
+      case "reasoning":
+        push(`  (thinking) ${oneLine(t.text, w - 16)}`, "meta")
+        break
+      case "patch":
+        push(`  [patch] ${oneLine(t.files.join(", "), w - 12)}`, "meta")
+        break
+      case "progress":
+        break
+    }
+  })
+  return lines
+}
+
+// First transcript line belonging to nodeIdx, or the nearest earlier node's
+// line when the node itself renders nothing (e.g. progress)
+export function lineOfNode(lines: TranscriptLine[], nodeIdx: number): number {
+  let best = 0
+  for (let i = 0; i < lines.length; i++) {
+    if (lines[i].nodeIdx === nodeIdx) return i
+    if (lines[i].nodeIdx > nodeIdx) break
+    if (i === 0 || lines[i].nodeIdx !== lines[i - 1].nodeIdx) best = i
+  }
+  return best
+}
+
+// Reading mode: the main conversation only — prompts and replies, no tools
+export function buildReadingLines(nodes: Node[], width: number): TranscriptLine[] {
+  const lines: TranscriptLine[] = []
+  const w = Math.max(20, width)
+  nodes.forEach((node, nodeIdx) => {
+    const t = node.nodeType
+    if (node.agentId || node.branchLevel > 0) return
+    if (t.kind !== "user" && t.kind !== "assistant") return
+    if (!t.text.trim()) return
+    if (lines.length > 0) lines.push({ nodeIdx, text: "", role: "meta" })
+    const time = formatClock(node.timestamp, { seconds: false })
+    lines.push({ nodeIdx, text: `${t.kind === "user" ? "You" : "Assistant"}  ${time}`, role: t.kind, heading: true })
+    // Body text stays uncolored; the heading carries the role
+    if (t.kind === "assistant") {
+      for (const l of renderMarkdown(t.text, w)) lines.push({ nodeIdx, text: l.text, role: "assistant", spans: l.spans })
+    } else {
+      for (const l of wrap(t.text, w)) lines.push({ nodeIdx, text: l, role: "assistant" })
+    }
+  })
+  return lines
+}
@@ -0,0 +1,86 @@
+import type { Node, SessionStats } from "./types"
+import { computeStats } from "./stats"
+import type { ToolFeedback } from "./attribution"
+import { attributeToolTokens } from "./attribution"
+import type { ToolGroups } from "./groups"
+import { toolLabel } from "./groups"
+
+// A turn runs from one main-session user prompt up to (not including) the next.
+// Nodes before the first prompt form a leading turn starting at 0.
+export type TurnBounds = { start: number; end: number } // [start, end) into nodes
+
+export function isTurnStart(node: Node): boolean {
+  return node.branchLevel === 0 && !node.agentId && node.nodeType.kind === "user"
+}
+
+export function listTurns(nodes: Node[]): TurnBounds[] {
+  const turns: TurnBounds[] = []
+  let start = 0
+  for (let i = 1; i < nodes.length; i++) {
+    if (isTurnStart(nodes[i])) {
+      turns.push({ start, end: i })
+      start = i
+    }
+  }
+  if (nodes.length > 0) turns.push({ start, end: nodes.length })
+  return turns
+}
+
+export function getTurnBounds(nodes: Node[], idx: number): TurnBounds {
+  let start = Math.min(idx, nodes.length - 1)
+  while (start > 0 && !isTurnStart(nodes[start])) start--
+  let end = idx + 1
+  while (end < nodes.length && !isTurnStart(nodes[end])) end++
+  return { start: Math.max(0, start), end }
+}
+
+export type TurnStats = {
+  nodeCount: number
+  tools: Record<string, number> // calls per tool name, or per group when grouped
+  toolCalls: number
+  errors: number
+  durationMs: number
+  tokens: SessionStats
+  feedback: ToolFeedback[] // prompt tokens spent on each tool's results
+}
+
+export function computeTurnStats(nodes: Node[], bounds: TurnBounds, groups: ToolGroups = {}): TurnStats {
+  const turnNodes = nodes.slice(bounds.start, bounds.end)
+  const tools: Record<string, number> = {}
+  let toolCalls = 0
+  let errors = 0
+  for (const n of turnNodes) {
+    const t = n.nodeType
+    if (t.kind === "tool_call" || t.kind === "tool_use") {
+      const name = toolLabel(t.name, groups)
+      tools[name] = (tools[name] ?? 0) + 1
+      toolCalls++
+    }
+    if ((t.kind === "tool_call" || t.kind === "tool_result") && t.isError) errors++
+  }
+  const first = turnNodes[0]?.timestamp ?? 0
+  const last = turnNodes[turnNodes.length - 1]?.timestamp ?? first
+  return {
+    nodeCount: turnNodes.length,
+    tools,
+    toolCalls,
+    errors,
+    durationMs: last - first,
+    tokens: computeStats(turnNodes),
+    feedback: attributeToolTokens(turnNodes, groups),
+  }
+}
+
+export type TurnPrompt = { index: number; turn: number; text: string } // turn counts prompts from 1
+
+// The prompt that opened the turn containing idx, on one line. Null for the
+// leading turn before the first prompt.
+export function turnPrompt(nodes: Node[], idx: number): TurnPrompt | null {
+  if (nodes.length === 0) return null
+  const { start } = getTurnBounds(nodes, idx)
+  const node = nodes[start]
+  if (!isTurnStart(node) || node.nodeType.kind !== "user") return null
+  let turn = 0
+  for (let i = 0; i <= start; i++) if (isTurnStart(nodes[i])) turn++
+  return { index: start, turn, text: node.nodeType.text.replace(/\s+/g, " ").trim() }
+}
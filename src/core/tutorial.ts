@@ -0,0 +1,28 @@
+// `vizier tutorial`: a guided tour over the demo session. Each step names the
+// keys that complete it; pressing a later step's key early doesn't skip ahead,
+// so every explanation gets read.
+
+export type TutorialStep = {
+  title: string
+  prompt: string
+  keys: string[] // any one of these completes the step
+}
+
+export const TUTORIAL_SEED = 1 // always the same session, so the prompts fit it
+
+export const TUTORIAL_STEPS: TutorialStep[] = [
+  { title: "Moving in time", prompt: "Press l or h (or the arrow keys) to step to the next or previous event.", keys: ["l", "h"] },
+  { title: "Rows", prompt: "Press j or k to move between rows: prompts, replies, tool calls, then subagents.", keys: ["j", "k"] },
+  { title: "Details", prompt: "Press d to open the details panel for the selected node. d closes it again.", keys: ["d"] },
+  { title: "Following links", prompt: "Press p to jump to the node's parent or c to its first child. Ctrl-o jumps back.", keys: ["p", "c"] },
+  { title: "Layouts", prompt: "Press L to give each kind of tool its own row, or F for one row per edited file.", keys: ["L", "F"] },
+  { title: "Zooming out", prompt: "Press V to see how long each tool call took, then V again to come back.", keys: ["V"] },
+  { title: "Filters", prompt: "Press / and type type:tool error:true, then Enter. An empty filter clears it.", keys: ["/"] },
+  { title: "Exporting", prompt: "Press e to write the selected turn to a Markdown file; E writes JSON.", keys: ["e", "E"] },
+]
+
+// Index of the step still to do; TUTORIAL_STEPS.length once all are done
+export function advanceTutorial(step: number, key: string): number {
+  const current = TUTORIAL_STEPS[step]
+  return current && current.keys.includes(key) ? step + 1 : step
+}
@@ -45,6 +45,7 @@ export type Node = {
   parentId?: string
   nodeType: NodeType
   timestamp: number // epoch ms for fast comparison
+  endTimestamp?: number // tool_call: when its result arrived
   branchLevel: number
   agentId?: string
   model?: string
@@ -52,6 +53,10 @@ export type Node = {
   source?: string       // "opencode" | "claude"
   cost?: number          // OpenCode tracks per-message cost
   turnId?: string        // groups nodes belonging to same user turn
+  repeatCount?: number   // identical consecutive calls collapsed into this one
+  fileLane?: number      // row in the file swimlane layout, -1 for none (see core/files)
+  badges?: string[]      // from the project's annotation hook (see core/annotations)
+  summarized?: number    // --summarize: nodes of this turn left out (see core/summary)
 }
 
 export type Edge = {
@@ -68,19 +73,29 @@ export type SessionStats = {
   model: string | null
   totalCost?: number
   totalReasoningTokens?: number
+  repeatedToolCalls?: number // duplicate calls folded by collapseRepeats
+  contextTokens?: number // prompt size of the latest request: input + cache read + cache write
 }
 
+// A transcript line the source had to skip
+export type ParseIssue = { file: string; line: number; message: string }
+
 export type Graph = {
   nodes: Node[]
   edges: Edge[]
   stats: SessionStats
+  skipped?: ParseIssue[]
+  fileLanes?: string[] // file swimlane row labels, from row 1
 }
 
 export type SessionInfo = {
   id: string
-  timestamp: number
+  timestamp: number      // last activity
+  startedAt?: number
   nodeCount: number
   waitingForUser: boolean
+  sizeBytes?: number     // transcript file size, when the source has one file per session
+  parseErrors?: number   // lines that failed to parse
   title?: string
   slug?: string
   directory?: string
@@ -93,8 +108,13 @@ export interface Source {
   kind: string
   listSessions(): Promise<SessionInfo[]>
   readGraph(sessionId: string): Promise<Graph>
-  watch(sessionId: string, onUpdate: (graph: Graph) => void): () => void
+  // onNotice: lasting conditions worth showing, e.g. watching fell back to polling
+  watch(sessionId: string, onUpdate: (graph: Graph) => void, onNotice?: (message: string) => void): () => void
   // Online-only capabilities (present when server is connected)
   sendMessage?(sessionId: string, text: string): Promise<void>
   abortSession?(sessionId: string): Promise<void>
+  // --summarize: load this session in full from now on
+  expandSession?(sessionId: string): void
+  // Files the session is read from, main transcript first, for export provenance
+  sessionFiles?(sessionId: string): string[]
 }
@@ -0,0 +1,48 @@
+import type { Graph, Node } from "./types"
+import { listTurns, isTurnStart } from "./turns"
+import { formatTokens } from "./stats"
+
+// Prompts whose turn pulled in unusually much new context, usually a big
+// paste, so people learn which of their prompts blow up the context. New
+// context is input plus cache creation: cache reads grow with every session
+// anyway and would flag the last turns of any long one.
+
+export const HEAVY_MIN_TOKENS = 20_000 // nothing below this is heavy, however light the rest are
+export const HEAVY_RATIO = 3 // times the session's median turn
+
+function freshTokens(node: Node): number {
+  const u = node.usage
+  return u ? (u.input_tokens ?? 0) + (u.cache_creation_input_tokens ?? 0) : 0
+}
+
+// Prompt node id → new context tokens of its turn, for the heavy ones only.
+// Measured on the whole session so a filter doesn't change what's heavy.
+export function heavyPrompts(nodes: Node[]): Map<string, number> {
+  const turns = listTurns(nodes).filter(t => isTurnStart(nodes[t.start]))
+  const totals = turns.map(({ start, end }) => {
+    let sum = 0
+    for (let i = start; i < end; i++) sum += freshTokens(nodes[i])
+    return sum
+  })
+  const sorted = [...totals].sort((a, b) => a - b)
+  const median = sorted[Math.floor((sorted.length - 1) / 2)] ?? 0
+  const heavy = new Map<string, number>()
+  turns.forEach((t, i) => {
+    if (totals[i] >= HEAVY_MIN_TOKENS && totals[i] >= median * HEAVY_RATIO) heavy.set(nodes[t.start].id, totals[i])
+  })
+  return heavy
+}
+
+export function weightBadge(tokens: number): string {
+  return `+${formatTokens(tokens)} ctx`
+}
+
+// Heavy prompts get a weight badge, after any the annotation hook gave them
+export function withPromptWeights(graph: Graph, heavy: Map<string, number>): Graph {
+  if (heavy.size === 0) return graph
+  const nodes = graph.nodes.map(node => {
+    const tokens = heavy.get(node.id)
+    return tokens === undefined ? node : { ...node, badges: [...node.badges ?? [], weightBadge(tokens)] }
+  })
+  return { ...graph, nodes }
+}
@@ -1,12 +1,17 @@
-import type { Node } from "./types"
+import type { Graph, Node } from "./types"
+import { truncateToWidth } from "../ui/width"
+import { laneIndex } from "./lanes"
+import { pluginLabel } from "./plugins"
+import { buildCheck, buildBadge } from "./builds"
+import type { NodeFilter, FilterContext } from "./filter"
+import { matchesFilter, filterContext } from "./filter"
 
-export type ZoomLevel = "sessions" | "conversations" | "details" | "focus"
+export type ZoomLevel = "sessions" | "conversations" | "details" | "focus" | "lanes" | "files" // lanes: a row per kind of tool, files: per modified file
 export type CellMode = "symbol" | "preview"
 
-// Extract first N words from text, truncated to maxLen chars
+// Extract first N words from text, truncated to maxLen columns
 function firstWords(text: string, n: number, maxLen: number): string {
-  const words = text.trim().split(/\s+/).slice(0, n).join(" ")
-  return words.length <= maxLen ? words : words.slice(0, maxLen - 1) + "…"
+  return truncateToWidth(text.trim().split(/\s+/).slice(0, n).join(" "), maxLen)
 }
 
 // Short content preview for a node — pure function, no IO
@@ -14,8 +19,13 @@ export function getNodePreview(node: Node, maxLen = 18): string {
   switch (node.nodeType.kind) {
     case "user": return firstWords(node.nodeType.text, 5, maxLen)
     case "assistant": return firstWords(node.nodeType.text, 5, maxLen)
-    case "tool_call": return node.nodeType.name
-    case "tool_use": return node.nodeType.name
+    case "tool_call":
+    case "tool_use": {
+      const label = pluginLabel(node)
+      if (label) return truncateToWidth(label, maxLen)
+      const check = buildCheck(node)
+      return check ? truncateToWidth(`${node.nodeType.name} ${buildBadge(check)}`, maxLen) : node.nodeType.name
+    }
     case "tool_result": return node.nodeType.isError ? "error" : "ok"
     case "agent_start": return node.nodeType.agentType
     case "agent_end": return "end"
@@ -61,7 +71,10 @@ export function filterByZoom(nodes: Node[], level: ZoomLevel): number[] {
         .filter(i => i >= 0)
     case "details":
     case "focus":
+    case "lanes":
       return nodes.map((_, i) => i)
+    case "files":
+      return nodes.map((n, i) => (n.fileLane ?? -1) >= 0 ? i : -1).filter(i => i >= 0)
   }
 }
 
@@ -98,6 +111,10 @@ export function getVisualBranch(node: Node, level: ZoomLevel): number {
         case "patch": return 1
         default: return 2
       }
+    case "lanes":
+      return laneIndex(node)
+    case "files":
+      return node.fileLane ?? -1
     case "sessions":
       return 0
   }
@@ -109,5 +126,39 @@ export function getZoomLabel(level: ZoomLevel): string {
     case "conversations": return "CONVERSATIONS"
     case "details": return "DETAILS"
     case "focus": return "FOCUS"
+    case "lanes": return "LANES"
+    case "files": return "FILES"
   }
 }
+
+// Node-type visibility, orthogonal to zoom: hidden nodes are dropped from the
+// graph before any zoom filtering, so navigation never lands on them
+export type VisibilityMask = {
+  hideProgress: boolean
+  hideOkResults: boolean // compact mode: failed results stay visible
+  filter?: NodeFilter // "/" filter expression
+}
+
+export const SHOW_ALL: VisibilityMask = { hideProgress: false, hideOkResults: false }
+
+export function isNodeVisible(node: Node, mask: VisibilityMask, ctx?: FilterContext): boolean {
+  const t = node.nodeType
+  if (mask.hideProgress && t.kind === "progress") return false
+  if (mask.hideOkResults && t.kind === "tool_result" && !t.isError) return false
+  if (mask.filter && !matchesFilter(node, mask.filter, ctx ?? { agentTypes: new Map() })) return false
+  return true
+}
+
+export function applyVisibility(graph: Graph, mask: VisibilityMask): Graph {
+  if (!mask.hideProgress && !mask.hideOkResults && !mask.filter) return graph
+  const ctx = mask.filter ? filterContext(graph.nodes) : undefined
+  const nodes = graph.nodes.filter(n => isNodeVisible(n, mask, ctx))
+  return nodes.length === graph.nodes.length ? graph : { ...graph, nodes }
+}
+
+export function getHiddenLabels(mask: VisibilityMask): string[] {
+  const labels: string[] = []
+  if (mask.hideProgress) labels.push("progress")
+  if (mask.hideOkResults) labels.push("ok results")
+  return labels
+}
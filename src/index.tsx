@@ -1,26 +1,132 @@
 #!/usr/bin/env bun
 import React from "react"
 import { render } from "ink"
+import { basename } from "node:path"
 import { App } from "./app"
 import { createOpenCodeSource, storageExists } from "./sources/opencode/index"
-import { createClaudeSource, getClaudeDir, getProjectSlug } from "./sources/claude/index"
+import { createClaudeSource, getClaudeDir, getProjectSlug, getProjectDir } from "./sources/claude/index"
+import { setStrictSchema } from "./sources/claude/watcher"
 import { createMultiSource } from "./sources/multi/index"
+import { createDemoSource } from "./sources/demo/index"
+import { createSummarizingSource } from "./sources/summary/index"
+import { createSnapshotSource } from "./sources/snapshot/index"
+import { TUTORIAL_SEED } from "./core/tutorial"
+import { canonicalProjectPath, projectSetting, watchedDirs, workspaceDirs } from "./sources/project"
+import type { FeedProject } from "./sources/feed"
 import type { Source } from "./core/types"
+import { setAsciiMode } from "./ui/glyphs"
+import { setMonochrome, shouldDisableColor, setTheme, parseTheme } from "./ui/theme"
+import { setLocale, parseLocale } from "./ui/i18n"
+import { parseGotoTarget, resolveGotoTarget } from "./core/goto"
+import { runErrors } from "./cli/errors"
+import { runLayoutCheck } from "./cli/layout"
+import { runRender } from "./cli/render"
+import { runValidate } from "./cli/validate"
+import { runReport } from "./cli/report"
+import { runCompareTools } from "./cli/compare"
+import { runTop } from "./cli/top"
+import { runServe } from "./cli/serve"
+import { runStatus } from "./cli/status"
+import { runWait } from "./cli/wait"
+import { EXIT_USAGE, isWaitCondition } from "./core/status"
+import { IDLE_AFTER_MS } from "./core/lifecycle"
+import { parseSince } from "./core/report"
+import { parseFrame } from "./core/frames"
+import { formatStrictFailure } from "./core/strict"
+import { setLaneGroups } from "./core/lanes"
+import { loadConfig, getConfigPath } from "./storage/config"
+import { setRenderPlugins } from "./core/plugins"
+import { loadPlugins } from "./plugins/wasm"
+import { parseKeyRemaps, setKeyRemaps } from "./core/keymap"
+import type { StartupCommand } from "./core/commands"
+import { parseCommand } from "./core/commands"
 
-function parseArgs(): { session?: string; project?: string; source?: string; server?: string } {
+const USAGE = "Usage: vizier [errors|demo|tutorial|render|layout-check|report|compare-tools|top|serve|status|wait|validate [<session|file>]] [--brief] [--until <waiting|error|idle>] [--for <5m>] [--port <n>] [--since <7d|24h>] [--markdown] [--json] [--last <n>] [--seed <n>] [--width <cols>] [--height <rows>] [--frame <n|last>] [--source opencode|claude|multi] [--session <id>] [--project <path>] [--server <url>] [--ascii] [--no-color] [--list] [--tick-rate <ms>] [--goto <uuid|turn:N|time:HH:MM>] [--cmd '<:command>']... [--strict] [--summarize] [--no-watch]"
+
+type CliArgs = {
+  command?: string // subcommand, e.g. "errors"; absent for the TUI
+  target?: string   // positional argument after the subcommand
+  session?: string
+  project?: string
+  source?: string
+  server?: string
+  ascii?: boolean
+  noColor?: boolean
+  list?: boolean
+  tickRate?: number
+  goto?: string
+  cmds: string[] // --cmd, repeatable: startup commands such as ":filter error:true"
+  seed?: number // demo: which synthetic session to generate
+  width?: number // render: output size
+  height?: number
+  frame?: string
+  since?: string // report: window to aggregate, e.g. "7d"; top: window for burn rates
+  markdown?: boolean
+  json?: boolean // errors/report/compare-tools/top/validate: machine-readable output
+  last?: number // compare-tools: how many recent sessions
+  port?: number // serve: WebSocket port
+  brief?: boolean // status: one line for tmux and shell prompts
+  until?: string // wait: condition to block for
+  for?: string // wait: how long "idle" has to last
+  strict?: boolean // abort instead of skipping unparseable transcript lines
+  summarize?: boolean // historical sessions load as turn summaries plus errors
+  noWatch?: boolean // read sessions once: no file watcher, no polling
+}
+
+const COMMANDS = ["errors", "demo", "tutorial", "render", "layout-check", "report", "compare-tools", "top", "serve", "status", "wait", "validate"]
+
+function parseArgs(): CliArgs {
   const args = process.argv.slice(2)
-  const result: { session?: string; project?: string; source?: string; server?: string } = {}
+  const result: CliArgs = { cmds: [] }
+  if (args[0] && !args[0].startsWith("-")) result.command = args[0]
+  if (result.command && args[1] && !args[1].startsWith("-")) result.target = args[1]
   for (let i = 0; i < args.length; i++) {
     if (args[i] === "--session" && args[i + 1]) result.session = args[++i]
     if (args[i] === "--project" && args[i + 1]) result.project = args[++i]
     if (args[i] === "--source" && args[i + 1]) result.source = args[++i]
     if (args[i] === "--server" && args[i + 1]) result.server = args[++i]
+    if (args[i] === "--ascii") result.ascii = true
+    if (args[i] === "--no-color") result.noColor = true
+    if (args[i] === "--list") result.list = true
+    if (args[i] === "--strict") result.strict = true
+    if (args[i] === "--summarize") result.summarize = true
+    if (args[i] === "--no-watch") result.noWatch = true
+    if (args[i] === "--markdown") result.markdown = true
+    if (args[i] === "--json") result.json = true
+    if (args[i] === "--brief") result.brief = true
+    if (args[i] === "--since" && args[i + 1]) result.since = args[++i]
+    if (args[i] === "--until" && args[i + 1]) result.until = args[++i]
+    if (args[i] === "--for" && args[i + 1]) result.for = args[++i]
+    if (args[i] === "--goto" && args[i + 1]) result.goto = args[++i]
+    if (args[i] === "--cmd" && args[i + 1]) result.cmds.push(args[++i])
+    if (args[i] === "--frame" && args[i + 1]) result.frame = args[++i]
+    if ((args[i] === "--width" || args[i] === "--height") && args[i + 1]) {
+      const key = args[i] === "--width" ? "width" : "height"
+      const n = Number(args[++i])
+      if (Number.isInteger(n) && n > 0) result[key] = n
+    }
+    if ((args[i] === "--last" || args[i] === "--port") && args[i + 1]) {
+      const key = args[i] === "--last" ? "last" : "port"
+      const n = Number(args[++i])
+      if (Number.isInteger(n) && n > 0) result[key] = n
+    }
+    if (args[i] === "--seed" && args[i + 1]) {
+      const seed = Number(args[++i])
+      if (Number.isInteger(seed)) result.seed = seed
+    }
+    if (args[i] === "--tick-rate" && args[i + 1]) {
+      const ms = Number(args[++i])
+      if (Number.isFinite(ms) && ms >= 0) result.tickRate = ms
+    }
   }
   return result
 }
 
-async function main() {
-  const args = parseArgs()
+async function createSource(args: CliArgs): Promise<{ source: Source; sourceKind: string }> {
+  if (args.command === "demo") return { source: createDemoSource({ seed: args.seed }), sourceKind: "demo" }
+  if (args.command === "tutorial") return { source: createDemoSource({ seed: TUTORIAL_SEED }), sourceKind: "demo" }
+
+  const projectPath = canonicalProjectPath(args.project || process.cwd())
 
   // Determine source
   let sourceKind = args.source
@@ -32,7 +138,6 @@ async function main() {
   let source: Source
 
   if (sourceKind === "opencode") {
-    const projectPath = args.project || process.cwd()
     source = createOpenCodeSource(projectPath)
 
     // If server URL provided, wire up online capabilities
@@ -44,13 +149,11 @@ async function main() {
     }
   } else if (sourceKind === "claude") {
     const claudeDir = getClaudeDir()
-    const projectPath = args.project || process.cwd()
-    const project = getProjectSlug(projectPath)
-    source = createClaudeSource(claudeDir, project)
+    const projects = workspaceDirs(projectPath, loadConfig().workspaces).map(getProjectSlug)
+    source = createClaudeSource(claudeDir, projects)
   } else {
     const entries: { kind: string; source: Source }[] = []
     if (storageExists()) {
-      const projectPath = args.project || process.cwd()
       const oc = createOpenCodeSource(projectPath)
       if (args.server) {
         const { connectToServer } = await import("./sources/opencode/server")
@@ -61,12 +164,78 @@ async function main() {
       entries.push({ kind: "opencode", source: oc })
     }
     const claudeDir = getClaudeDir()
-    const projectPath = args.project || process.cwd()
-    const project = getProjectSlug(projectPath)
-    const cl = createClaudeSource(claudeDir, project)
+    const projects = workspaceDirs(projectPath, loadConfig().workspaces).map(getProjectSlug)
+    const cl = createClaudeSource(claudeDir, projects)
     entries.push({ kind: "claude", source: cl })
     source = createMultiSource(entries)
   }
+  return { source, sourceKind }
+}
+
+async function main() {
+  const args = parseArgs()
+  setStrictSchema(args.strict === true)
+  setAsciiMode(args.ascii === true || process.env.VIZIER_ASCII === "1")
+  setMonochrome(shouldDisableColor(args.noColor === true))
+  const theme = loadConfig().theme
+  if (theme !== undefined && !parseTheme(theme)) console.error(`vizier: unknown theme "${theme}", using default`)
+  setTheme(parseTheme(theme) ?? "default")
+  const locale = loadConfig().locale
+  if (locale !== undefined && !parseLocale(locale)) console.error(`vizier: unknown locale "${locale}", using English`)
+  setLocale(parseLocale(locale) ?? "en")
+  setLaneGroups(loadConfig().toolGroups)
+  const { plugins, errors: pluginErrors } = loadPlugins(loadConfig().plugins, getConfigPath())
+  for (const err of pluginErrors) console.error(`vizier: ${err}`)
+  setRenderPlugins(plugins)
+  const { remaps, errors: keyErrors } = parseKeyRemaps(loadConfig().keys)
+  for (const err of keyErrors) console.error(`vizier: ${err}`)
+  setKeyRemaps(remaps)
+
+  if (args.command && !COMMANDS.includes(args.command)) {
+    console.error(`Unknown command: ${args.command}`)
+    console.error(USAGE)
+    process.exit(1)
+  }
+
+  // Reads transcript files directly — no source needed
+  if (args.command === "validate") {
+    const projects = workspaceDirs(canonicalProjectPath(args.project || process.cwd()), loadConfig().workspaces).map(getProjectSlug)
+    process.exit(runValidate(args.target ?? args.session, projects, args.json === true))
+  }
+
+  const { source, sourceKind } = await createSource(args)
+  // --summarize only changes what the TUI loads; reports and scripts read every event
+  const opensTui = !args.command || args.command === "demo" || args.command === "tutorial"
+  const viewSource = args.summarize && opensTui ? createSummarizingSource(source) : source
+
+  // Spans every session, so it runs before one is picked
+  if (args.command === "report") {
+    const since = args.since ?? "7d"
+    const sinceMs = parseSince(since)
+    if (sinceMs === null) {
+      console.error(`Invalid --since: ${since} (expected e.g. 90m, 24h, 7d or 2w)`)
+      process.exit(1)
+    }
+    await runReport(source, { since, sinceMs, format: args.json ? "json" : args.markdown ? "markdown" : "table" })
+    return
+  }
+  if (args.command === "compare-tools") {
+    await runCompareTools(source, args.last ?? 10, args.json === true)
+    return
+  }
+  if (args.command === "top") {
+    const since = args.since ?? "5m"
+    const windowMs = parseSince(since)
+    if (windowMs === null) {
+      console.error(`Invalid --since: ${since} (expected e.g. 5m or 1h)`)
+      process.exit(1)
+    }
+    await runTop(source, since, windowMs, args.json === true)
+    return
+  }
+
+  // status and wait keep 1 and 2 for session states
+  const failureCode = args.command === "status" || args.command === "wait" ? EXIT_USAGE : 1
 
   // Find session
   const sessions = await source.listSessions()
@@ -75,18 +244,104 @@ async function main() {
   if (!sessionId) {
     if (sessions.length === 0) {
       console.error(`No sessions found for source: ${sourceKind}`)
-      console.error("\nUsage: vizier [--source opencode|claude|multi] [--session <id>] [--project <path>] [--server <url>]")
-      process.exit(1)
+      console.error(`\n${USAGE}`)
+      process.exit(failureCode)
     }
     sessionId = sessions[0].id
   } else if (!sessions.some(s => s.id === sessionId)) {
     console.error(`Session not found: ${sessionId}`)
+    if (args.command && args.command !== "demo") process.exit(failureCode)
     console.error("Opening session list...")
   }
 
-  const graph = await source.readGraph(sessionId)
+  const graph = await viewSource.readGraph(sessionId)
+  if (args.strict && graph.skipped) {
+    console.error(formatStrictFailure(graph.skipped))
+    process.exit(failureCode)
+  }
+
+  if (args.command === "errors") {
+    await runErrors(source, graph, sessionId, args.json === true)
+    return
+  }
+  if (args.command === "render") {
+    const frame = parseFrame(args.frame ?? "last")
+    if (frame === null) {
+      console.error(`Invalid --frame: ${args.frame} (expected a node count or "last")`)
+      process.exit(1)
+    }
+    runRender(graph, { width: args.width ?? 120, height: args.height ?? 40, frame })
+    return
+  }
+  if (args.command === "layout-check") {
+    runLayoutCheck(graph, sessionId, args.width ?? process.stdout.columns ?? 120, args.json === true)
+    return
+  }
+  if (args.command === "status") {
+    process.exit(runStatus(graph, sessionId, args.brief === true))
+  }
+  if (args.command === "wait") {
+    const condition = args.until ?? "waiting"
+    if (!isWaitCondition(condition)) {
+      console.error(`Invalid --until: ${condition} (expected waiting, error or idle)`)
+      process.exit(EXIT_USAGE)
+    }
+    const idleMs = args.for ? parseSince(args.for) : IDLE_AFTER_MS
+    if (idleMs === null) {
+      console.error(`Invalid --for: ${args.for} (expected e.g. 5m or 1h)`)
+      process.exit(EXIT_USAGE)
+    }
+    process.exit(await runWait(source, sessionId, graph, condition, idleMs))
+  }
+  if (args.command === "serve") {
+    runServe(source, sessionId, graph, args.port ?? 7777)
+    return
+  }
+
   const openSessionListOnStart = graph.nodes.length === 0
 
+  let initialNodeIdx: number | null = null
+  if (args.goto) {
+    const target = parseGotoTarget(args.goto)
+    if (!target) {
+      console.error(`Invalid --goto target: ${args.goto} (expected <uuid>, turn:N or time:HH:MM)`)
+      process.exit(1)
+    }
+    initialNodeIdx = resolveGotoTarget(graph.nodes, target)
+    if (initialNodeIdx === null) console.error(`--goto target not found: ${args.goto}`)
+  }
+
+  // demo sessions aren't any project's
+  const projectDir = canonicalProjectPath(args.project || process.cwd())
+  const annotateCommand = sourceKind === "demo" ? null : projectSetting(projectDir, loadConfig().annotate)
+  const annotate = typeof annotateCommand === "string" && annotateCommand.trim() ? { command: annotateCommand, cwd: projectDir } : undefined
+  // Claude transcript directories are watched; other sources are polled
+  const claudeDirsOf = (dirs: string[]) => dirs.map(dir => getProjectDir(getClaudeDir(), getProjectSlug(dir)))
+  const feed: FeedProject[] = sourceKind === "demo"
+    ? [{ name: "demo", source }]
+    : [
+        {
+          name: basename(projectDir),
+          source,
+          dirs: sourceKind === "claude" ? claudeDirsOf(workspaceDirs(projectDir, loadConfig().workspaces)) : undefined,
+        },
+        ...watchedDirs(projectDir, loadConfig().watchProjects).map(dir => ({
+          name: basename(dir),
+          source: createClaudeSource(getClaudeDir(), getProjectSlug(dir)),
+          dirs: claudeDirsOf([dir]),
+        })),
+      ]
+
+  const startupCommands: StartupCommand[] = []
+  for (const text of args.cmds) {
+    const cmd = parseCommand(text)
+    if ("error" in cmd) {
+      console.error(`Invalid --cmd ${JSON.stringify(text)}: ${cmd.error}`)
+      process.exit(1)
+    }
+    startupCommands.push(cmd)
+  }
+
   // Enter alternate screen buffer (like vim/less/htop)
   process.stdout.write("\x1b[?1049h\x1b[H")
 
@@ -95,14 +350,32 @@ async function main() {
       initialGraph={graph}
       sessionId={sessionId}
       initialSessionListOpen={openSessionListOnStart}
-      source={source}
+      initialViewMode={args.list ? "list" : "timeline"}
+      tickRate={args.tickRate}
+      initialNodeIdx={initialNodeIdx}
+      source={args.noWatch ? createSnapshotSource(viewSource) : viewSource}
+      strict={args.strict}
+      tutorial={args.command === "tutorial"}
+      startupCommands={startupCommands}
+      annotate={annotate}
+      feed={args.noWatch ? undefined : feed}
+      live={!args.noWatch}
     />,
     { exitOnCtrlC: true }
   )
-  await waitUntilExit()
+  let failure: unknown = null
+  try {
+    await waitUntilExit()
+  } catch (e) {
+    failure = e // --strict: a live update brought a bad line
+  }
 
   // Leave alternate screen buffer — restores previous terminal content
   process.stdout.write("\x1b[?1049l")
+  if (failure) {
+    console.error(failure instanceof Error ? failure.message : String(failure))
+    process.exit(1)
+  }
 }
 
 main()
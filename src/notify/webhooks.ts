@@ -0,0 +1,75 @@
+import type { Graph } from "../core/types"
+import type { LiveEvent, LiveEventKind } from "../core/state"
+import { diffLiveEvents, isAwaitingUser, lastActivity, ENDED_AFTER_MS } from "../core/state"
+import { sessionLifecycle, latestTurnFailed } from "../core/lifecycle"
+import { shortSessionId } from "../core/sessions"
+
+export type WebhookFormat = "slack" | "discord" | "generic"
+
+export type WebhookConfig = {
+  url: string
+  format?: WebhookFormat // default "generic"
+  events?: LiveEventKind[] // default: all of them
+}
+
+const ALL_EVENTS: LiveEventKind[] = ["waiting", "tool_error", "completed"]
+
+// Body for one hook: Slack and Discord take a chat message, anything else
+// gets the event itself
+export function webhookPayload(hook: WebhookConfig, event: LiveEvent, now = Date.now()): unknown {
+  const text = `vizier: session ${shortSessionId(event.sessionId)} ${event.message}`
+  if (hook.format === "slack") return { text }
+  if (hook.format === "discord") return { content: text }
+  return { event: event.kind, sessionId: event.sessionId, nodeId: event.nodeId ?? null, message: event.message, state: event.state ?? null, time: new Date(now).toISOString() }
+}
+
+export function wantsEvent(hook: WebhookConfig, kind: LiveEventKind): boolean {
+  return (hook.events ?? ALL_EVENTS).includes(kind)
+}
+
+// Fire and forget — a dead endpoint must never disturb the TUI
+export function postEvent(hooks: WebhookConfig[], event: LiveEvent): void {
+  for (const hook of hooks) {
+    if (!hook.url || !wantsEvent(hook, event.kind)) continue
+    fetch(hook.url, {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(webhookPayload(hook, event)),
+    }).catch(() => {})
+  }
+}
+
+export type SessionNotifier = { update(graph: Graph): void; close(): void }
+
+// Tracks one watched session: diffs each update for errors and hand-backs,
+// and reports "completed" once a handed-back session stays quiet
+export function createSessionNotifier(sessionId: string, hooks: WebhookConfig[], initial: Graph): SessionNotifier {
+  let previous = initial
+  let completedTimer: ReturnType<typeof setTimeout> | null = null
+  let completedSent = false
+
+  const scheduleCompleted = (graph: Graph) => {
+    if (completedTimer) clearTimeout(completedTimer)
+    completedTimer = null
+    const last = lastActivity(graph.nodes)
+    if (completedSent || last === null || !isAwaitingUser(graph.nodes)) return
+    completedTimer = setTimeout(() => {
+      completedSent = true
+      postEvent(hooks, { kind: "completed", sessionId, message: "completed", state: latestTurnFailed(graph.nodes) ? "error" : "ended" })
+    }, Math.max(0, last + ENDED_AFTER_MS - Date.now()))
+  }
+  scheduleCompleted(initial)
+
+  return {
+    update(graph) {
+      const state = sessionLifecycle(graph.nodes, Date.now())
+      for (const event of diffLiveEvents(previous, graph, sessionId)) postEvent(hooks, { ...event, state })
+      if (graph.nodes.length > previous.nodes.length) completedSent = false
+      previous = graph
+      scheduleCompleted(graph)
+    },
+    close() {
+      if (completedTimer) clearTimeout(completedTimer)
+    },
+  }
+}
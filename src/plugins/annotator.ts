@@ -0,0 +1,66 @@
+import { spawn } from "node:child_process"
+import { createInterface } from "node:readline"
+import type { Node } from "../core/types"
+import type { Annotations } from "../core/annotations"
+import { annotationKey, annotationRequest, parseAnnotation } from "../core/annotations"
+
+// Runs a project's annotation hook (see core/annotations) for one session.
+// The command starts once and stays up; nodes are written as they appear and
+// replies are delivered in batches so a fast hook doesn't redraw per line.
+
+export type Annotator = { annotate(nodes: Node[]): void; close(): void }
+
+const BATCH_MS = 100
+
+export function startAnnotator(
+  command: string,
+  cwd: string,
+  onBadges: (batch: Annotations) => void,
+  onError: (message: string) => void,
+): Annotator {
+  const child = spawn(command, { cwd, shell: true, stdio: ["pipe", "pipe", "ignore"] })
+  const sent = new Set<string>()
+  let pending: Annotations = {}
+  let timer: ReturnType<typeof setTimeout> | null = null
+  let stopped = false
+
+  const stop = (message: string | null) => {
+    if (stopped) return
+    stopped = true
+    if (message) onError(message)
+  }
+  child.on("error", err => stop(`annotation hook failed: ${err.message}`))
+  child.on("exit", code => stop(code ? `annotation hook exited with code ${code}` : null))
+  child.stdin.on("error", () => {}) // EPIPE once the hook is gone; "exit" reports it
+
+  createInterface({ input: child.stdout }).on("line", line => {
+    const reply = parseAnnotation(line)
+    if (!reply) return
+    pending[reply.id] = reply.badges
+    timer ??= setTimeout(() => {
+      timer = null
+      const batch = pending
+      pending = {}
+      onBadges(batch)
+    }, BATCH_MS)
+  })
+
+  return {
+    annotate(nodes) {
+      if (stopped) return
+      let out = ""
+      for (const node of nodes) {
+        const key = annotationKey(node)
+        if (sent.has(key)) continue
+        sent.add(key)
+        out += annotationRequest(node) + "\n"
+      }
+      if (out) child.stdin.write(out)
+    },
+    close() {
+      if (timer) clearTimeout(timer)
+      stopped = true
+      child.kill()
+    },
+  }
+}
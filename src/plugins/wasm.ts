@@ -0,0 +1,70 @@
+import { readFileSync } from "node:fs"
+import { dirname, isAbsolute, join } from "node:path"
+import type { RenderPlugin, ToolCallView } from "../core/plugins"
+
+// WebAssembly render plugins. A module exports `memory`, `alloc(len) -> ptr`
+// and either or both of `label` and `details`, each `(ptr, len) -> i64`: the
+// argument is the tool call as UTF-8 JSON ({name, input, output, isError}),
+// the result a UTF-8 string packed as (ptr << 32) | len, or 0 to fall back.
+// `details` returns lines separated by "\n". Any WASM toolchain works,
+// including Lua compiled to WASM.
+
+export type PluginConfig = {
+  wasm: string // path to the .wasm file, relative to the config file
+  tools: string[]
+}
+
+type Exports = {
+  memory: WebAssembly.Memory
+  alloc: (len: number) => number
+  label?: (ptr: number, len: number) => bigint
+  details?: (ptr: number, len: number) => bigint
+}
+
+const encoder = new TextEncoder()
+const decoder = new TextDecoder()
+
+function callHook(exports: Exports, hook: (ptr: number, len: number) => bigint, call: ToolCallView): string | null {
+  const bytes = encoder.encode(JSON.stringify(call))
+  const ptr = exports.alloc(bytes.length)
+  new Uint8Array(exports.memory.buffer, ptr, bytes.length).set(bytes)
+  const packed = hook(ptr, bytes.length)
+  if (packed === 0n) return null
+  const outPtr = Number(packed >> 32n)
+  const outLen = Number(packed & 0xffffffffn)
+  return decoder.decode(new Uint8Array(exports.memory.buffer, outPtr, outLen))
+}
+
+export function instantiatePlugin(bytes: Uint8Array, name: string, tools: string[]): RenderPlugin {
+  const instance = new WebAssembly.Instance(new WebAssembly.Module(bytes), {})
+  const exports = instance.exports as unknown as Exports
+  if (!(exports.memory instanceof WebAssembly.Memory) || typeof exports.alloc !== "function") {
+    throw new Error("must export memory and alloc")
+  }
+  const { label, details } = exports
+  return {
+    name,
+    tools,
+    label: label ? call => callHook(exports, label, call) : undefined,
+    details: details ? call => callHook(exports, details, call)?.split("\n") ?? null : undefined,
+  }
+}
+
+// A plugin that fails to load is reported and left out; the rest still load
+export function loadPlugins(configs: PluginConfig[] | undefined, configPath: string): { plugins: RenderPlugin[]; errors: string[] } {
+  const plugins: RenderPlugin[] = []
+  const errors: string[] = []
+  for (const config of configs ?? []) {
+    if (typeof config?.wasm !== "string" || !Array.isArray(config.tools)) {
+      errors.push("plugin entries need a wasm path and a tools list")
+      continue
+    }
+    const path = isAbsolute(config.wasm) ? config.wasm : join(dirname(configPath), config.wasm)
+    try {
+      plugins.push(instantiatePlugin(readFileSync(path), config.wasm, config.tools))
+    } catch (err) {
+      errors.push(`plugin ${config.wasm}: ${err instanceof Error ? err.message : String(err)}`)
+    }
+  }
+  return { plugins, errors }
+}
@@ -1,8 +1,11 @@
 import type { SessionEvent, Graph, Node } from "../../core/types"
 import { computeStats } from "../../core/stats"
+import { collapseRepeats } from "../../core/repeats"
 import { parseEventToNodes } from "./parser"
 
-// Merge tool_use + tool_result pairs into single tool_call nodes
+// Merge tool_use + tool_result pairs into single tool_call nodes.
+// Nodes are freshly parsed on every build, so they're updated in place
+// rather than copied — avoids a second allocation per node on each reload.
 function mergeToolCalls(nodes: Node[]): Node[] {
   // Index tool_results by their parentId (which is the tool_use id)
   const resultByToolId = new Map<string, Node>()
@@ -19,16 +22,15 @@ function mergeToolCalls(nodes: Node[]): Node[] {
     if (n.nodeType.kind === "tool_use") {
       const result = resultByToolId.get(n.id)
       if (result) consumedResults.add(result.id)
-      merged.push({
-        ...n,
-        nodeType: {
-          kind: "tool_call",
-          name: n.nodeType.name,
-          input: n.nodeType.input,
-          output: result?.nodeType.kind === "tool_result" ? result.nodeType.output : null,
-          isError: result?.nodeType.kind === "tool_result" ? result.nodeType.isError : false,
-        },
-      })
+      n.nodeType = {
+        kind: "tool_call",
+        name: n.nodeType.name,
+        input: n.nodeType.input,
+        output: result?.nodeType.kind === "tool_result" ? result.nodeType.output : null,
+        isError: result?.nodeType.kind === "tool_result" ? result.nodeType.isError : false,
+      }
+      if (result) n.endTimestamp = result.timestamp
+      merged.push(n)
     } else if (n.nodeType.kind === "tool_result") {
       if (!consumedResults.has(n.id)) {
         // Orphan result with no matching tool_use — keep as-is
@@ -87,36 +89,41 @@ export function buildGraph(events: SessionEvent[]): Graph {
     agentToBranch.set(aid, assigned + 1) // branchLevel 1-based
   }
 
-  const rawNodes = events.flatMap(parseEventToNodes)
-  rawNodes.sort((a, b) => a.timestamp - b.timestamp)
+  // Events arrive in merged file order; keep that order as the tiebreaker so
+  // parallel tool calls within one millisecond don't shuffle between reloads
+  const rawNodes = events
+    .flatMap(parseEventToNodes)
+    .map((node, seq) => ({ node, seq }))
+    .sort((a, b) => a.node.timestamp - b.node.timestamp || a.seq - b.seq)
+    .map(entry => entry.node)
   const merged = mergeToolCalls(rawNodes)
 
   // 3. Set branchLevel for agent nodes, filter out agent user nodes,
   //    and link first agent node to parent tool_use
   const linkedAgents = new Set<string>()
-  const nodes: Node[] = []
+  const linked: Node[] = []
   for (const node of merged) {
     if (!node.agentId) {
-      nodes.push(node)
+      linked.push(node)
       continue
     }
     // Skip agent user nodes — redundant with Task tool_call input
     if (node.nodeType.kind === "user") continue
 
-    const bl = agentToBranch.get(node.agentId) ?? 0
-    const updated = { ...node, branchLevel: bl }
+    node.branchLevel = agentToBranch.get(node.agentId) ?? 0
 
     // Link first node of each agent to its parent tool_use
     if (!linkedAgents.has(node.agentId)) {
       linkedAgents.add(node.agentId)
       const parentToolId = agentToParentToolUse.get(node.agentId)
       if (parentToolId) {
-        updated.parentId = parentToolId
+        node.parentId = parentToolId
       }
     }
 
-    nodes.push(updated)
+    linked.push(node)
   }
+  const { nodes, removed } = collapseRepeats(linked)
 
   const edges = nodes
     .filter(n => n.parentId)
@@ -131,6 +138,7 @@ export function buildGraph(events: SessionEvent[]): Graph {
       .filter(e => e.message?.usage)
       .map(e => ({ usage: e.message!.usage, model: e.message!.model }))
   )
+  if (removed > 0) stats.repeatedToolCalls = removed
 
   return { nodes, edges, stats }
 }
@@ -1,41 +1,62 @@
+import { existsSync } from "node:fs"
 import type { Source, SessionInfo, Graph } from "../../core/types"
-import { buildGraph } from "./graph"
+import { parseSessionGraphAsync } from "./loader"
 import {
   getClaudeDir,
   getProjectSlug,
+  getProjectDir,
   getSessionFile,
   discoverAgentFiles,
-  readAllEvents,
   listSessions as listClaudeSessions,
   watchSession,
 } from "./watcher"
 
-export function createClaudeSource(claudeDir: string, project: string): Source {
+// Several projects (a monorepo workspace) list as one; session ids are UUIDs,
+// so each belongs to whichever project directory holds its file
+export function createClaudeSource(claudeDir: string, projects: string | string[]): Source {
+  const all = Array.isArray(projects) ? projects : [projects]
+  const projectOf = (sessionId: string) =>
+    all.find(p => existsSync(getSessionFile(claudeDir, p, sessionId))) ?? all[0]
+
   return {
     kind: "claude",
 
     async listSessions(): Promise<SessionInfo[]> {
-      return listClaudeSessions(claudeDir, project)
+      return all.flatMap(p => listClaudeSessions(claudeDir, p)).sort((a, b) => b.timestamp - a.timestamp)
     },
 
     async readGraph(sessionId: string): Promise<Graph> {
+      const project = projectOf(sessionId)
       const sessionFile = getSessionFile(claudeDir, project, sessionId)
       const agentFiles = discoverAgentFiles(claudeDir, project, sessionId)
-      const events = readAllEvents(sessionFile, agentFiles)
-      return buildGraph(events)
+      return parseSessionGraphAsync(sessionFile, agentFiles)
+    },
+
+    sessionFiles(sessionId: string): string[] {
+      const project = projectOf(sessionId)
+      const sessionFile = getSessionFile(claudeDir, project, sessionId)
+      return [sessionFile, ...discoverAgentFiles(claudeDir, project, sessionId)].filter(f => existsSync(f))
     },
 
-    watch(sessionId: string, onUpdate: (graph: Graph) => void): () => void {
+    watch(sessionId: string, onUpdate: (graph: Graph) => void, onNotice?: (message: string) => void): () => void {
+      // Parses finish out of order under bursty writes — only deliver the newest
+      let latest = 0
+      let closed = false
+      const project = projectOf(sessionId)
       const watcher = watchSession(claudeDir, project, sessionId, () => {
+        const seq = ++latest
         const sessionFile = getSessionFile(claudeDir, project, sessionId)
         const agentFiles = discoverAgentFiles(claudeDir, project, sessionId)
-        const events = readAllEvents(sessionFile, agentFiles)
-        const graph = buildGraph(events)
-        onUpdate(graph)
-      })
-      return () => { watcher.close() }
+        parseSessionGraphAsync(sessionFile, agentFiles).then(graph => {
+          if (!closed && seq === latest) onUpdate(graph)
+        })
+      }, onNotice)
+      return () => {
+        closed = true
+        watcher.close()
+      }
     },
   }
 }
 
-export { getClaudeDir, getProjectSlug }
+export { getClaudeDir, getProjectSlug, getProjectDir }
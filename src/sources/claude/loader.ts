@@ -0,0 +1,92 @@
+import type { Graph, ParseIssue } from "../../core/types"
+import { buildGraph } from "./graph"
+import { internGraph } from "../../core/intern"
+import { readAllEvents, readAllEventsAsync, isStrictSchema } from "./watcher"
+
+export type ParseRequest = { id: number; sessionFile: string; agentFiles: string[]; strict: boolean }
+export type ParseResponse = { id: number; graph?: Graph; error?: string }
+
+type Pending = { request: ParseRequest; resolve: (graph: Graph) => void }
+
+let worker: Worker | null = null
+let workerFailed = false
+let warnedFallback = false
+let nextId = 0
+const pending = new Map<number, Pending>()
+
+// Skipped lines travel with the graph so the UI can report them
+function withSkipped(graph: Graph, issues: ParseIssue[]): Graph {
+  if (issues.length > 0) graph.skipped = issues
+  return graph
+}
+
+export function parseSessionGraph(sessionFile: string, agentFiles: string[]): Graph {
+  const issues: ParseIssue[] = []
+  return withSkipped(buildGraph(readAllEvents(sessionFile, agentFiles, issues)), issues)
+}
+
+export async function readSessionGraph(sessionFile: string, agentFiles: string[]): Promise<Graph> {
+  const issues: ParseIssue[] = []
+  return withSkipped(buildGraph(await readAllEventsAsync(sessionFile, agentFiles, issues)), issues)
+}
+
+// Parsing on the calling thread blocks input, so say so the first time a
+// request has to fall back to it
+function parseAfterFallback(request: ParseRequest, reason: string): Graph {
+  if (!warnedFallback) {
+    warnedFallback = true
+    console.error(`vizier: ${reason}; parsing on the main thread`)
+  }
+  return parseSessionGraph(request.sessionFile, request.agentFiles)
+}
+
+// Settle every in-flight request synchronously — used when the worker dies
+function drainPending() {
+  for (const { request, resolve } of pending.values()) {
+    resolve(parseAfterFallback(request, "the parse worker stopped"))
+  }
+  pending.clear()
+}
+
+function getWorker(): Worker | null {
+  if (worker) return worker
+  if (workerFailed || typeof Worker === "undefined" || process.env.VIZIER_NO_WORKER === "1") return null
+  try {
+    const w = new Worker(new URL("./parse-worker.ts", import.meta.url).href)
+    w.onmessage = (event: MessageEvent<ParseResponse>) => {
+      const { id, graph, error } = event.data
+      const entry = pending.get(id)
+      if (!entry) return
+      pending.delete(id)
+      if (pending.size === 0) w.unref()
+      entry.resolve(graph ? internGraph(graph) : parseAfterFallback(entry.request, `the parse worker failed on ${entry.request.sessionFile}: ${error ?? "no graph"}`))
+    }
+    w.onerror = () => {
+      workerFailed = true
+      worker = null
+      w.terminate()
+      drainPending()
+    }
+    // Idle, the worker doesn't keep the process alive; it's ref'd while a
+    // request is pending so one-shot commands wait for their graph
+    w.unref()
+    worker = w
+    return w
+  } catch {
+    workerFailed = true
+    return null
+  }
+}
+
+// Parse on a background worker so multi-megabyte sessions don't block input
+// handling; falls back to the calling thread when workers are unavailable
+export function parseSessionGraphAsync(sessionFile: string, agentFiles: string[]): Promise<Graph> {
+  const w = getWorker()
+  if (!w) return readSessionGraph(sessionFile, agentFiles)
+  const request: ParseRequest = { id: nextId++, sessionFile, agentFiles, strict: isStrictSchema() }
+  return new Promise(resolve => {
+    pending.set(request.id, { request, resolve })
+    w.ref()
+    w.postMessage(request)
+  })
+}
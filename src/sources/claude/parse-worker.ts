@@ -0,0 +1,18 @@
+// Worker entry — reads JSONL files and builds the graph off the UI thread
+import { readSessionGraph } from "./loader"
+import type { ParseRequest, ParseResponse } from "./loader"
+import { setStrictSchema } from "./watcher"
+
+declare var self: Worker
+
+self.onmessage = async (event: MessageEvent<ParseRequest>) => {
+  const { id, sessionFile, agentFiles, strict } = event.data
+  setStrictSchema(strict) // the worker doesn't share the main thread's modules
+  let response: ParseResponse
+  try {
+    response = { id, graph: await readSessionGraph(sessionFile, agentFiles) }
+  } catch (err) {
+    response = { id, error: err instanceof Error ? err.message : String(err) }
+  }
+  postMessage(response)
+}
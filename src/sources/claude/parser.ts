@@ -1,4 +1,5 @@
 import type { SessionEvent, Node, NodeType } from "../../core/types"
+import { intern } from "../../core/intern"
 
 let counter = 0
 function generateId(): string {
@@ -46,7 +47,7 @@ export function parseEventToNodes(event: SessionEvent): Node[] {
     if (role === "assistant") {
       const text = extractTextContent(content)
       const hasTextNode = text.length > 0
-      const model = event.message?.model
+      const model = intern(event.message?.model)
       const usage = event.message?.usage
 
       if (hasTextNode) {
@@ -86,41 +87,89 @@ export function parseEventToNodes(event: SessionEvent): Node[] {
   return nodes
 }
 
+// Decided by the last meaningful main-session event: assistant text with no
+// tool call means control has been handed back to the user. User messages
+// (prompts or tool results) and tool calls mean the agent is still working.
+export function isWaitingForUser(events: SessionEvent[]): boolean {
+  for (let i = events.length - 1; i >= 0; i--) {
+    const event = events[i]
+    if (event.agentId || event.isSidechain || !event.message) continue
+    const { role, content } = event.message
+    if (role === "user") return false
+    if (role !== "assistant") continue
+    if (extractToolUses(content)) return false
+    if (extractTextContent(content).trim()) return true
+    // thinking-only or empty message — keep looking back
+  }
+  return false
+}
+
+// Typed views of message content blocks — narrowed from the parsed JSON once,
+// instead of probing `any` objects in every extractor
+type TextBlock = { type: "text"; text: string }
+type ToolUseBlock = { type: "tool_use"; id: string; name: string; input?: unknown }
+type ToolResultBlock = { type: "tool_result"; tool_use_id: string; content?: unknown; is_error?: boolean }
+type ContentBlock = TextBlock | ToolUseBlock | ToolResultBlock
+
+function asBlock(item: unknown): ContentBlock | null {
+  if (typeof item !== "object" || item === null) return null
+  const b = item as Record<string, unknown>
+  switch (b.type) {
+    case "text":
+      return typeof b.text === "string" ? (b as TextBlock) : null
+    case "tool_use":
+      return typeof b.id === "string" && typeof b.name === "string" ? (b as ToolUseBlock) : null
+    case "tool_result":
+      return typeof b.tool_use_id === "string" ? (b as ToolResultBlock) : null
+    default:
+      return null
+  }
+}
+
+function blocks(content: unknown): ContentBlock[] {
+  if (!Array.isArray(content)) return []
+  const out: ContentBlock[] = []
+  for (const item of content) {
+    const block = asBlock(item)
+    if (block) out.push(block)
+  }
+  return out
+}
+
 function extractTextContent(content: unknown): string {
   if (typeof content === "string") return content
-  if (!Array.isArray(content)) return ""
-  return content
-    .filter((item: any) => item?.type === "text" && typeof item?.text === "string")
-    .map((item: any) => item.text as string)
+  return blocks(content)
+    .filter((b): b is TextBlock => b.type === "text")
+    .map(b => b.text)
     .join(" ")
 }
 
-// Returns [toolId, name, prettyInput][]
+// Returns [toolId, name, rawInput][] — input is kept as compact JSON;
+// pretty-printing is deferred to the views that display it
 function extractToolUses(content: unknown): [string, string, string][] | null {
-  if (!Array.isArray(content)) return null
   const tools: [string, string, string][] = []
-  for (const item of content) {
-    if (item?.type === "tool_use") {
-      const id = item.id as string
-      const name = item.name as string
-      const input = JSON.stringify(item.input, null, 2)
-      tools.push([id, name, input])
-    }
+  for (const b of blocks(content)) {
+    if (b.type !== "tool_use") continue
+    tools.push([b.id, intern(b.name), JSON.stringify(b.input ?? {})])
   }
   return tools.length > 0 ? tools : null
 }
 
+// Tool result content is either a string or an array of text blocks
+function resultText(content: unknown): string {
+  if (typeof content === "string") return content
+  return blocks(content)
+    .filter((b): b is TextBlock => b.type === "text")
+    .map(b => b.text)
+    .join("\n")
+}
+
 // Returns [toolUseId, output, isError][]
 function extractToolResults(content: unknown): [string, string, boolean][] | null {
-  if (!Array.isArray(content)) return null
   const results: [string, string, boolean][] = []
-  for (const item of content) {
-    if (item?.type === "tool_result") {
-      const toolUseId = item.tool_use_id as string
-      const output = typeof item.content === "string" ? item.content : ""
-      const isError = item.is_error === true
-      results.push([toolUseId, output, isError])
-    }
+  for (const b of blocks(content)) {
+    if (b.type !== "tool_result") continue
+    results.push([b.tool_use_id, resultText(b.content), b.is_error === true])
   }
   return results.length > 0 ? results : null
 }
@@ -0,0 +1,67 @@
+import type { SessionEvent } from "../../core/types"
+
+// Transcript layouts written by different Claude CLI releases:
+//   legacy  — subagent turns are sidechain events inside the session file,
+//             without an agentId, and nothing links them to their Task call
+//   current — each subagent gets its own file under <session>/subagents/,
+//             events carry agentId, and progress events link agent → Task
+// Everything downstream expects the current layout, so older files are
+// upgraded as they're read.
+
+export type SchemaVersion = "legacy" | "current"
+
+// Decided per file: current fields anywhere win, sidechains without them mean legacy.
+// A file with no subagent activity reads the same either way.
+export function detectSchema(events: SessionEvent[]): SchemaVersion {
+  let sidechain = false
+  for (const e of events) {
+    if (e.agentId || e.data?.type === "agent_progress") return "current"
+    if (e.isSidechain) sidechain = true
+  }
+  return sidechain ? "legacy" : "current"
+}
+
+function taskCallIds(event: SessionEvent): string[] {
+  const content = event.message?.content
+  if (event.message?.role !== "assistant" || !Array.isArray(content)) return []
+  return content
+    .filter(b => b?.type === "tool_use" && b.name === "Task" && typeof b.id === "string")
+    .map(b => b.id as string)
+}
+
+// Each sidechain chain (followed through parentUuid) becomes an agent. Chains
+// start in the order their Task calls were made, so each new chain claims the
+// oldest unclaimed Task call and gets the progress event the current layout has.
+function fromLegacy(events: SessionEvent[]): SessionEvent[] {
+  const agentOf = new Map<string, string>() // event uuid → agentId
+  const openTasks: string[] = []
+  const out: SessionEvent[] = []
+  for (const event of events) {
+    if (!event.isSidechain) {
+      openTasks.push(...taskCallIds(event))
+      out.push(event)
+      continue
+    }
+    let agentId = event.parentUuid ? agentOf.get(event.parentUuid) : undefined
+    if (!agentId) {
+      agentId = `sidechain-${event.uuid}`
+      const task = openTasks.shift()
+      if (task) {
+        out.push({
+          uuid: `${event.uuid}:progress`,
+          type: "progress",
+          timestamp: event.timestamp,
+          data: { type: "agent_progress", agentId },
+          parentToolUseID: task,
+        })
+      }
+    }
+    agentOf.set(event.uuid, agentId)
+    out.push({ ...event, agentId })
+  }
+  return out
+}
+
+export function upgradeEvents(events: SessionEvent[]): SessionEvent[] {
+  return detectSchema(events) === "legacy" ? fromLegacy(events) : events
+}
@@ -0,0 +1,100 @@
+// Line-by-line checks for Claude transcripts. The loader skips anything it
+// can't use; this reports what was skipped and why.
+
+export type IssueKind = "malformed" | "unknown-type" | "missing-field" | "orphan-result"
+
+export type ValidationIssue = { file: string; line: number; kind: IssueKind; message: string }
+
+export type ValidationReport = { issues: ValidationIssue[]; files: number; lines: number }
+
+export type TranscriptFile = { path: string; content: string }
+
+export const KNOWN_EVENT_TYPES = new Set([
+  "user",
+  "assistant",
+  "progress",
+  "system",
+  "summary",
+  "file-history-snapshot",
+  "queue-operation",
+])
+
+// Event types whose position in the timeline depends on their timestamp
+const TIMED_TYPES = new Set(["user", "assistant", "progress"])
+
+function contentBlocks(message: unknown): Record<string, unknown>[] {
+  if (typeof message !== "object" || message === null) return []
+  const content = (message as { content?: unknown }).content
+  if (!Array.isArray(content)) return []
+  return content.filter((b): b is Record<string, unknown> => typeof b === "object" && b !== null)
+}
+
+// Tool results are matched against tool uses across all files of a session,
+// since a subagent's results never reference the main transcript
+export function validateSession(files: TranscriptFile[]): ValidationReport {
+  const issues: ValidationIssue[] = []
+  const toolUseIds = new Set<string>()
+  const results: { file: string; line: number; id: string }[] = []
+  let lines = 0
+
+  for (const { path, content } of files) {
+    content.split("\n").forEach((text, i) => {
+      if (!text.trim()) return
+      lines++
+      const line = i + 1
+      const report = (kind: IssueKind, message: string) => issues.push({ file: path, line, kind, message })
+      let event: unknown
+      try {
+        event = JSON.parse(text)
+      } catch (e) {
+        report("malformed", e instanceof Error ? e.message : "invalid JSON")
+        return
+      }
+      if (typeof event !== "object" || event === null || Array.isArray(event)) {
+        report("malformed", "not a JSON object")
+        return
+      }
+      const e = event as Record<string, unknown>
+      if (typeof e.type !== "string") {
+        report("missing-field", "no event type")
+        return
+      }
+      if (!KNOWN_EVENT_TYPES.has(e.type)) report("unknown-type", `unknown event type "${e.type}"`)
+      if (TIMED_TYPES.has(e.type) && (typeof e.timestamp !== "string" || !Number.isFinite(new Date(e.timestamp).getTime()))) {
+        report("missing-field", `${e.type} event without a valid timestamp`)
+      }
+      if ((e.type === "user" || e.type === "assistant") && (typeof e.message !== "object" || e.message === null)) {
+        report("missing-field", `${e.type} event without a message`)
+        return
+      }
+      for (const block of contentBlocks(e.message)) {
+        if (block.type === "tool_use" && typeof block.id === "string") toolUseIds.add(block.id)
+        if (block.type === "tool_result" && typeof block.tool_use_id === "string") {
+          results.push({ file: path, line, id: block.tool_use_id })
+        }
+      }
+    })
+  }
+
+  for (const r of results) {
+    if (!toolUseIds.has(r.id)) {
+      issues.push({ file: r.file, line: r.line, kind: "orphan-result", message: `tool result for unknown tool use ${r.id}` })
+    }
+  }
+  issues.sort((a, b) => files.findIndex(f => f.path === a.file) - files.findIndex(f => f.path === b.file) || a.line - b.line)
+  return { issues, files: files.length, lines }
+}
+
+export function validationReportToJson(report: ValidationReport): string {
+  return JSON.stringify({ schemaVersion: 1, kind: "validate", ...report }, null, 2) + "\n"
+}
+
+export function formatValidationReport(report: ValidationReport): string {
+  const out = report.issues.map(i => `${i.file}:${i.line}: ${i.kind}: ${i.message}`)
+  const plural = (n: number, word: string) => `${n} ${word}${n === 1 ? "" : "s"}`
+  const summary = `${plural(report.lines, "line")} in ${plural(report.files, "file")}`
+  out.push(report.issues.length === 0
+    ? `OK: ${summary}`
+    : `${plural(report.issues.length, "issue")} in ${summary}`)
+  return out.join("\n") + "\n"
+}
@@ -1,7 +1,10 @@
-import { watch as chokidarWatch, type FSWatcher } from "chokidar"
-import { readFileSync, readdirSync, existsSync, statSync } from "fs"
+import { watchFiles, type FileWatcher } from "../watch"
+import { readFileSync, readdirSync, existsSync, statSync, openSync, readSync, closeSync } from "fs"
+import { readFile } from "fs/promises"
 import { join, extname, basename } from "path"
-import type { SessionEvent, SessionInfo } from "../../core/types"
+import type { SessionEvent, SessionInfo, ParseIssue } from "../../core/types"
+import { isWaitingForUser } from "./parser"
+import { upgradeEvents } from "./schema"
 import { homedir } from "os"
 
 export function getClaudeDir(): string {
@@ -12,38 +15,125 @@ export function getProjectSlug(cwd: string): string {
   return cwd.replace(/\//g, "-")
 }
 
-function readJsonlFile(path: string): SessionEvent[] {
-  if (!existsSync(path)) return []
-  const content = readFileSync(path, "utf-8")
+// --strict also refuses user and assistant events without a message, which
+// are otherwise read as they always were
+let strictSchema = false
+
+export function setStrictSchema(on: boolean): void {
+  strictSchema = on
+}
+
+export function isStrictSchema(): boolean {
+  return strictSchema
+}
+
+// Why a parsed line can't be used as an event, if it can't
+function schemaProblem(value: unknown): string | null {
+  if (typeof value !== "object" || value === null || Array.isArray(value)) return "not a JSON object"
+  const e = value as Record<string, unknown>
+  if (typeof e.type !== "string") return "no event type"
+  if (strictSchema && (e.type === "user" || e.type === "assistant") && (typeof e.message !== "object" || e.message === null)) {
+    return `${e.type} event without a message`
+  }
+  return null
+}
+
+// Malformed lines are skipped and, when `issues` is given, recorded there.
+// A trailing line that doesn't parse may still be being written, so it is
+// never reported.
+function parseJsonl(content: string, file?: string, issues?: ParseIssue[]): SessionEvent[] {
   const events: SessionEvent[] = []
-  for (const line of content.split("\n")) {
-    if (!line.trim()) continue
+  const lines = content.split("\n")
+  const report = (line: number, message: string) => {
+    if (issues && file) issues.push({ file, line, message })
+  }
+  lines.forEach((line, i) => {
+    if (!line.trim()) return
+    let value: unknown
     try {
-      events.push(JSON.parse(line))
-    } catch {
-      // skip malformed lines
+      value = JSON.parse(line)
+    } catch (e) {
+      if (i < lines.length - 1) report(i + 1, e instanceof Error ? e.message : "invalid JSON")
+      return
     }
-  }
+    const problem = schemaProblem(value)
+    if (problem) report(i + 1, problem)
+    else events.push(value as SessionEvent)
+  })
   return events
 }
 
-export function readAllEvents(sessionFile: string, agentFiles: string[]): SessionEvent[] {
-  const events = [
-    ...readJsonlFile(sessionFile),
-    ...agentFiles.flatMap(readJsonlFile),
-  ]
-  events.sort((a, b) => new Date(a.timestamp).getTime() - new Date(b.timestamp).getTime())
-  return events
+function readJsonlFile(path: string, issues?: ParseIssue[]): SessionEvent[] {
+  if (!existsSync(path)) return []
+  return upgradeEvents(parseJsonl(readFileSync(path, "utf-8"), path, issues))
+}
+
+async function readJsonlFileAsync(path: string, issues?: ParseIssue[]): Promise<SessionEvent[]> {
+  try {
+    return upgradeEvents(parseJsonl(await readFile(path, "utf-8"), path, issues))
+  } catch {
+    return []
+  }
+}
+
+// Missing/invalid timestamps sort first instead of poisoning comparisons with NaN
+function timestampKey(timestamp: string): number {
+  const ts = new Date(timestamp).getTime()
+  return Number.isFinite(ts) ? ts : 0
+}
+
+// Sort each file's events, then k-way merge by timestamp. Earlier files win
+// ties, so the main session file precedes agent files at equal timestamps.
+function mergeByTimestamp(files: SessionEvent[][]): SessionEvent[] {
+  // Line number breaks ties within a file, so same-millisecond events keep file order
+  const keyed = files.map(events =>
+    events
+      .map((e, seq) => ({ e, seq, ts: timestampKey(e.timestamp) }))
+      .sort((a, b) => a.ts - b.ts || a.seq - b.seq)
+  )
+  const heads = keyed.map(() => 0)
+  const total = keyed.reduce((n, list) => n + list.length, 0)
+  const merged: SessionEvent[] = []
+  while (merged.length < total) {
+    let best = -1
+    for (let f = 0; f < keyed.length; f++) {
+      if (heads[f] >= keyed[f].length) continue
+      if (best < 0 || keyed[f][heads[f]].ts < keyed[best][heads[best]].ts) best = f
+    }
+    merged.push(keyed[best][heads[best]++].e)
+  }
+  return merged
+}
+
+export function readAllEvents(sessionFile: string, agentFiles: string[], issues?: ParseIssue[]): SessionEvent[] {
+  return mergeByTimestamp([sessionFile, ...agentFiles].map(f => readJsonlFile(f, issues)))
+}
+
+// Reads the session and all agent files concurrently — agent-heavy sessions
+// can have dozens of subagent transcripts
+export async function readAllEventsAsync(sessionFile: string, agentFiles: string[], issues?: ParseIssue[]): Promise<SessionEvent[]> {
+  // Per-file lists keep the issues in file order even though reads finish out of order
+  const paths = [sessionFile, ...agentFiles]
+  const perFile = paths.map((): ParseIssue[] => [])
+  const files = await Promise.all(paths.map((f, i) => readJsonlFileAsync(f, perFile[i])))
+  if (issues) for (const list of perFile) issues.push(...list)
+  return mergeByTimestamp(files)
 }
 
 export function discoverAgentFiles(claudeDir: string, project: string, sessionId: string): string[] {
   const agentDir = join(claudeDir, "projects", project, sessionId, "subagents")
   if (!existsSync(agentDir)) return []
+  // readdir order is filesystem-dependent; sort so tie-breaking by file order is stable across reloads
   return readdirSync(agentDir)
     .filter(f => extname(f) === ".jsonl")
+    .sort()
     .map(f => join(agentDir, f))
 }
 
+export function getProjectDir(claudeDir: string, project: string): string {
+  return join(claudeDir, "projects", project)
+}
+
 export function getSessionFile(claudeDir: string, project: string, sessionId: string): string {
   return join(claudeDir, "projects", project, `${sessionId}.jsonl`)
 }
@@ -55,19 +145,145 @@ export function watchSession(
   project: string,
   sessionId: string,
   onChange: WatchCallback,
-): FSWatcher {
+  onNotice?: (message: string) => void,
+): FileWatcher {
   const sessionFile = getSessionFile(claudeDir, project, sessionId)
   const agentFiles = discoverAgentFiles(claudeDir, project, sessionId)
   const paths = [sessionFile, ...agentFiles]
 
-  const watcher = chokidarWatch(paths, {
+  return watchFiles(paths, {
     persistent: true,
     ignoreInitial: true,
     awaitWriteFinish: { stabilityThreshold: 100, pollInterval: 50 },
-  })
+  }, { change: onChange }, onNotice)
+}
+
+// Count non-empty lines without materializing a line array
+function countLines(content: string): number {
+  let count = 0
+  let hasContent = false
+  for (let i = 0; i < content.length; i++) {
+    const c = content.charCodeAt(i)
+    if (c === 10) {
+      if (hasContent) count++
+      hasContent = false
+    } else if (c !== 32 && c !== 9 && c !== 13) {
+      hasContent = true
+    }
+  }
+  return hasContent ? count + 1 : count
+}
+
+// Parse only the last chunk of a transcript — enough to find the final turn
+// without JSON-decoding multi-megabyte sessions for every picker refresh
+const TAIL_BYTES = 256 * 1024
+
+// New lines are scanned this much at a time, so a first look at a large
+// transcript doesn't hold all of it in memory at once
+const SCAN_BYTES = 1024 * 1024
+
+// Timestamp of the first event that carries one
+function firstTimestamp(content: string): number | undefined {
+  let start = 0
+  while (start < content.length) {
+    let end = content.indexOf("\n", start)
+    if (end < 0) end = content.length
+    const line = content.slice(start, end)
+    start = end + 1
+    if (!line.trim()) continue
+    try {
+      const ts = new Date(JSON.parse(line).timestamp).getTime()
+      if (Number.isFinite(ts)) return ts
+    } catch {
+      // skip malformed lines
+    }
+  }
+  return undefined
+}
+
+function readRange(fd: number, start: number, end: number): Buffer {
+  const buf = Buffer.alloc(Math.max(0, end - start))
+  let read = 0
+  while (read < buf.length) {
+    const n = readSync(fd, buf, read, buf.length - read, start + read)
+    if (n === 0) break
+    read += n
+  }
+  return buf.subarray(0, read)
+}
+
+// Cut by bytes; a character split at the cut sits on the partial first
+// line, which is dropped
+function parseTail(fd: number, size: number): SessionEvent[] {
+  const start = Math.max(0, size - TAIL_BYTES)
+  const tail = readRange(fd, start, size).toString("utf-8")
+  return parseJsonl(start === 0 ? tail : tail.slice(tail.indexOf("\n") + 1))
+}
+
+export function countParseErrors(content: string, from = 0): { offset: number; errors: number } {
+  let errors = 0
+  let start = from
+  for (;;) {
+    const end = content.indexOf("\n", start)
+    if (end < 0) break // trailing partial line may still be being written
+    const line = content.slice(start, end)
+    if (line.trim()) {
+      try {
+        JSON.parse(line)
+      } catch {
+        errors++
+      }
+    }
+    start = end + 1
+  }
+  return { offset: start, errors }
+}
+
+// What the picker shows for one transcript. JSONL only grows, so counts are
+// advanced from offset, where the next unscanned line starts; a file whose
+// size and mtime haven't changed isn't read at all.
+type FileSummary = {
+  size: number
+  mtimeMs: number
+  offset: number
+  lines: number // complete, non-empty
+  parseErrors: number
+  startedAt?: number
+  waitingForUser: boolean
+}
 
-  watcher.on("change", onChange)
-  return watcher
+const summaryCache = new Map<string, FileSummary>()
+
+function summarizeFile(path: string, size: number, mtimeMs: number): FileSummary {
+  const cached = summaryCache.get(path)
+  if (cached && cached.size === size && cached.mtimeMs === mtimeMs) return cached
+  // Shrunk: rewritten rather than appended to, so start over
+  const prev = cached && size >= cached.offset ? cached : { offset: 0, lines: 0, parseErrors: 0, startedAt: undefined }
+  const fd = openSync(path, "r")
+  try {
+    let { offset, lines, parseErrors, startedAt } = prev
+    let want = SCAN_BYTES
+    while (offset < size) {
+      const buf = readRange(fd, offset, Math.min(size, offset + want))
+      const end = buf.lastIndexOf(10)
+      if (end < 0) {
+        if (offset + buf.length >= size) break // a line still being written
+        want *= 2 // a line longer than the window
+        continue
+      }
+      const text = buf.subarray(0, end + 1).toString("utf-8")
+      lines += countLines(text)
+      parseErrors += countParseErrors(text).errors
+      startedAt ??= firstTimestamp(text)
+      offset += end + 1
+      want = SCAN_BYTES
+    }
+    const summary = { size, mtimeMs, offset, lines, parseErrors, startedAt, waitingForUser: isWaitingForUser(parseTail(fd, size)) }
+    summaryCache.set(path, summary)
+    return summary
+  } finally {
+    closeSync(fd)
+  }
 }
 
 export function listSessions(claudeDir: string, project: string): SessionInfo[] {
@@ -81,17 +297,17 @@ export function listSessions(claudeDir: string, project: string): SessionInfo[]
     const id = basename(entry, ".jsonl")
 
     const stat = statSync(path)
-    const content = readFileSync(path, "utf-8")
-    const lines = content.trim().split("\n").filter(Boolean)
-    const nodeCount = lines.length
-
-    const waitingForUser = false
+    const summary = summarizeFile(path, stat.size, stat.mtimeMs)
 
     sessions.push({
       id,
       timestamp: stat.mtimeMs,
-      nodeCount,
-      waitingForUser,
+      startedAt: summary.startedAt ?? stat.birthtimeMs,
+      // The last line counts once it has content, even before its newline
+      nodeCount: summary.lines + (summary.offset < stat.size ? 1 : 0),
+      waitingForUser: summary.waitingForUser,
+      sizeBytes: stat.size,
+      parseErrors: summary.parseErrors,
       source: "claude",
     })
   }
@@ -0,0 +1,132 @@
+import type { SessionEvent, Usage } from "../../core/types"
+
+// Synthetic Claude-format transcripts: prompts, replies, parallel tool calls,
+// failures and subagents. Seeded, so the same options always produce the same
+// events — usable both as a demo and as fixed input for rendering tests.
+
+export type DemoOptions = {
+  seed?: number
+  turns?: number
+  start?: number // epoch ms of the first event
+}
+
+export const DEMO_MODEL = "claude-demo"
+
+const PROMPTS = [
+  "The login form accepts empty passwords — can you fix the validation?",
+  "Add a --verbose flag to the CLI and document it",
+  "Why is the test suite slow? Profile it and speed up the worst offenders",
+  "Refactor the config loader so it can read TOML as well as JSON",
+  "Review the retry logic in the HTTP client for edge cases",
+  "Rename the `Widget` type to `Component` across the codebase",
+]
+
+const FILES = ["src/auth.ts", "src/cli.ts", "src/config.ts", "src/http/client.ts", "tests/auth.test.ts", "README.md"]
+
+const COMMANDS = ["bun test", "bun run lint", "git status", "git diff --stat", "bun run build"]
+
+// mulberry32 — tiny, fast and good enough for fixture data
+function createRandom(seed: number): () => number {
+  let a = seed >>> 0
+  return () => {
+    a = (a + 0x6D2B79F5) >>> 0
+    let t = a
+    t = Math.imul(t ^ (t >>> 15), t | 1)
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61)
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296
+  }
+}
+
+export function generateDemoEvents(options: DemoOptions = {}): SessionEvent[] {
+  const random = createRandom(options.seed ?? 1)
+  const turns = Math.max(1, options.turns ?? 6)
+  const pick = <T>(items: T[]): T => items[Math.floor(random() * items.length)]
+  const events: SessionEvent[] = []
+  let clock = options.start ?? Date.UTC(2025, 0, 15, 9, 0, 0)
+  let nextId = 0
+  const id = (prefix: string) => `${prefix}-${++nextId}`
+  const tick = (minMs: number, maxMs: number) => {
+    clock += minMs + Math.floor(random() * (maxMs - minMs))
+    return new Date(clock).toISOString()
+  }
+  const usage = (): Usage => ({
+    input_tokens: 200 + Math.floor(random() * 3000),
+    output_tokens: 50 + Math.floor(random() * 800),
+    cache_read_input_tokens: Math.floor(random() * 20000),
+  })
+
+  let lastUuid: string | undefined
+  const push = (event: Omit<SessionEvent, "uuid" | "timestamp">, at: string, uuid = id("evt")) => {
+    events.push({ uuid, timestamp: at, ...event })
+    if (!event.agentId) lastUuid = uuid
+    return uuid
+  }
+  const assistant = (content: unknown, agentId?: string, at = tick(800, 4000)) =>
+    push({ type: "assistant", parentUuid: lastUuid, agentId, isSidechain: agentId ? true : undefined,
+      message: { role: "assistant", content, model: DEMO_MODEL, usage: usage() } }, at)
+  const results = (items: { toolId: string; output: string; isError?: boolean }[], agentId?: string, at = tick(200, 6000)) =>
+    push({ type: "user", parentUuid: lastUuid, agentId, isSidechain: agentId ? true : undefined,
+      message: { role: "user", content: items.map(r => ({ type: "tool_result", tool_use_id: r.toolId, content: r.output, is_error: r.isError === true })) } }, at)
+  const toolUse = (name: string, input: Record<string, unknown>) => ({ type: "tool_use", id: id("toolu"), name, input })
+
+  for (let turn = 0; turn < turns; turn++) {
+    const file = pick(FILES)
+    push({ type: "user", parentUuid: lastUuid, message: { role: "user", content: PROMPTS[turn % PROMPTS.length] } }, tick(20_000, 90_000))
+
+    // Parallel reads
+    const reads = [toolUse("Read", { file_path: file }), toolUse("Grep", { pattern: "TODO", path: "src" })]
+    if (random() < 0.5) reads.push(toolUse("Glob", { pattern: "**/*.test.ts" }))
+    assistant([{ type: "text", text: `Let me look at \`${file}\` first.` }, ...reads])
+    results(reads.map(r => ({ toolId: r.id, output: `${r.name} output for ${JSON.stringify(r.input)}` })))
+
+    // A subagent (sometimes two in parallel) on alternate turns
+    if (turn % 2 === 1) {
+      const parallel = random() < 0.5 ? 2 : 1
+      const tasks = Array.from({ length: parallel }, (_, i) =>
+        toolUse("Task", { description: i === 0 ? "Search for callers" : "Check test coverage", subagent_type: "general-purpose" }))
+      assistant(tasks)
+      // Parallel agents run over the same stretch of time
+      const agentStart = clock
+      let agentEnd = clock
+      tasks.forEach((task, i) => {
+        const agentId = `agent${turn}${i}`
+        clock = agentStart
+        push({ type: "progress", data: { type: "agent_progress", agentId }, parentToolUseID: task.id }, tick(50, 200))
+        push({ type: "user", agentId, isSidechain: true, message: { role: "user", content: String(task.input.description) } }, tick(50, 200))
+        const grep = toolUse("Grep", { pattern: "login", path: "src" })
+        assistant([grep], agentId)
+        results([{ toolId: grep.id, output: `${file}:12: login(user)` }], agentId)
+        assistant([{ type: "text", text: `Found 3 references in ${file}.` }], agentId)
+        agentEnd = Math.max(agentEnd, clock)
+      })
+      clock = agentEnd
+      results(tasks.map(t => ({ toolId: t.id, output: "Found 3 references." })))
+    }
+
+    // Edit, then a command that fails about a third of the time and is retried after a fix
+    const edit = toolUse("Edit", { file_path: file, old_string: "if (password)", new_string: "if (password && password.length > 0)" })
+    assistant([edit])
+    results([{ toolId: edit.id, output: "File updated" }])
+    const command = pick(COMMANDS)
+    const run = toolUse("Bash", { command })
+    assistant([run])
+    if (random() < 0.35) {
+      results([{ toolId: run.id, output: "error: 1 test failed\n  expected true, received false", isError: true }], undefined, tick(2000, 30_000))
+      const fix = toolUse("Edit", { file_path: "tests/auth.test.ts", old_string: "toBe(true)", new_string: "toBe(false)" })
+      assistant([{ type: "text", text: "One failure — fixing the assertion and re-running." }, fix])
+      results([{ toolId: fix.id, output: "File updated" }])
+      const retry = toolUse("Bash", { command })
+      assistant([retry])
+      results([{ toolId: retry.id, output: "12 pass, 0 fail" }], undefined, tick(2000, 30_000))
+    } else {
+      results([{ toolId: run.id, output: "12 pass, 0 fail" }], undefined, tick(2000, 30_000))
+    }
+
+    assistant([{
+      type: "text",
+      text: `## Done\n\n- Updated **${file}**\n- \`${command}\` passes\n\n\`\`\`diff\n-if (password)\n+if (password && password.length > 0)\n\`\`\``,
+    }])
+  }
+
+  return events
+}
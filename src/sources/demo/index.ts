@@ -0,0 +1,38 @@
+import type { Source, SessionInfo, Graph } from "../../core/types"
+import { buildGraph } from "../claude/graph"
+import { isWaitingForUser } from "../claude/parser"
+import { generateDemoEvents, type DemoOptions } from "./generate"
+
+export const DEMO_SESSION_ID = "demo:session"
+
+// A single static synthetic session — lets `vizier demo` run without any
+// Claude or OpenCode history on disk
+export function createDemoSource(options: DemoOptions = {}): Source {
+  const events = generateDemoEvents(options)
+  const graph = buildGraph(events)
+  const timestamps = events.map(e => new Date(e.timestamp).getTime())
+
+  return {
+    kind: "demo",
+
+    async listSessions(): Promise<SessionInfo[]> {
+      return [{
+        id: DEMO_SESSION_ID,
+        timestamp: Math.max(...timestamps),
+        startedAt: Math.min(...timestamps),
+        nodeCount: graph.nodes.length,
+        waitingForUser: isWaitingForUser(events),
+        title: `Demo session (seed ${options.seed ?? 1})`,
+        source: "demo",
+      }]
+    },
+
+    async readGraph(): Promise<Graph> {
+      return graph
+    },
+
+    watch(): () => void {
+      return () => {}
+    },
+  }
+}
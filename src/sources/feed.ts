@@ -0,0 +1,89 @@
+import type { Graph, Source } from "../core/types"
+import type { FeedItem } from "../core/feed"
+import { feedItems, mergeFeed, FEED_REFRESH_MS, FEED_WINDOW_MS } from "../core/feed"
+import { createLru } from "../core/lru"
+import type { FileWatcher } from "./watch"
+import { watchFiles } from "./watch"
+
+// Reads the session lists of several projects for the activity feed. Projects
+// whose transcripts live in known directories are re-read when a file there
+// changes; the rest are polled. Only sessions touched inside the window are
+// read, and a transcript is parsed again only after its last-activity time moves.
+
+export type FeedProject = {
+  name: string
+  source: Source
+  dirs?: string[] // where its transcripts are written, watched instead of polled
+}
+
+export type FeedWatcher = { close(): void }
+
+const CACHE_SIZE = 64 // parsed sessions across every project
+
+export function watchFeed(
+  projects: FeedProject[],
+  limit: number,
+  onFeed: (items: FeedItem[]) => void,
+  onNotice?: (message: string) => void,
+): FeedWatcher {
+  const cache = createLru<string, { stamp: number; graph: Graph }>(CACHE_SIZE) // project + session id
+  let closed = false
+  let busy = false
+  let again = false
+
+  const refresh = async () => {
+    if (closed) return
+    if (busy) {
+      again = true // a slow read shouldn't pile up reads, but mustn't drop a change either
+      return
+    }
+    busy = true
+    try {
+      const since = Date.now() - FEED_WINDOW_MS
+      const lists: FeedItem[][] = []
+      for (const project of projects) {
+        const sessions = (await project.source.listSessions().catch(() => [])).filter(s => s.timestamp >= since)
+        for (const info of sessions) {
+          const key = `${project.name}\0${info.id}`
+          let entry = cache.get(key)
+          if (!entry || entry.stamp !== info.timestamp) {
+            const graph = await project.source.readGraph(info.id).catch(() => null)
+            if (!graph) continue
+            entry = { stamp: info.timestamp, graph }
+            cache.set(key, entry)
+          }
+          lists.push(feedItems(project.name, info.id, entry.graph.nodes, since))
+        }
+      }
+      if (!closed) onFeed(mergeFeed(lists, limit))
+    } finally {
+      busy = false
+      if (again) {
+        again = false
+        void refresh()
+      }
+    }
+  }
+
+  const dirs = projects.flatMap(p => p.dirs ?? [])
+  const watcher: FileWatcher | null = dirs.length > 0
+    ? watchFiles(dirs, {
+        persistent: true,
+        ignoreInitial: true,
+        depth: 2, // <session>/subagents/*.jsonl
+        awaitWriteFinish: { stabilityThreshold: 100, pollInterval: 50 },
+      }, { add: () => void refresh(), change: () => void refresh() }, onNotice)
+    : null
+  const interval = projects.some(p => !p.dirs)
+    ? setInterval(() => { void refresh() }, FEED_REFRESH_MS)
+    : null
+
+  void refresh()
+  return {
+    close() {
+      closed = true
+      watcher?.close()
+      if (interval) clearInterval(interval)
+    },
+  }
+}
@@ -45,12 +45,18 @@ export function createMultiSource(entries: SourceEntry[]): Source {
       return source.readGraph(decoded.id)
     },
 
-    watch(sessionId: string, onUpdate: (graph: Graph) => void): () => void {
+    sessionFiles(sessionId: string): string[] {
+      const decoded = decodeSessionId(sessionId)
+      const source = decoded ? byKind.get(decoded.kind) : undefined
+      return decoded && source?.sessionFiles ? source.sessionFiles(decoded.id) : []
+    },
+
+    watch(sessionId: string, onUpdate: (graph: Graph) => void, onNotice?: (message: string) => void): () => void {
       const decoded = decodeSessionId(sessionId)
       if (!decoded) return () => {}
       const source = byKind.get(decoded.kind)
       if (!source) return () => {}
-      return source.watch(decoded.id, onUpdate)
+      return source.watch(decoded.id, onUpdate, onNotice)
     },
 
     sendMessage: async (sessionId: string, text: string) => {
@@ -1,5 +1,7 @@
 import type { Graph, Node, Edge, Usage } from "../../core/types"
 import { computeStats, emptyStats } from "../../core/stats"
+import { intern } from "../../core/intern"
+import { collapseRepeats } from "../../core/repeats"
 import { readMessages, readParts } from "./reader"
 import type { OCMessage, OCPart } from "./reader"
 
@@ -16,7 +18,7 @@ function mapTokens(msg: OCMessage): Usage | undefined {
 }
 
 function getModelId(msg: OCMessage): string | undefined {
-  return msg.modelID ?? msg.model?.modelID
+  return intern(msg.modelID ?? msg.model?.modelID)
 }
 
 // Sort parts by ID (lexicographic = creation order since IDs are time-based)
@@ -128,7 +130,7 @@ export function buildOpenCodeGraph(sessionID: string): Graph {
             turnId,
           }
         } else if (part.type === "tool" && part.tool && part.state) {
-          const input = part.state.input ? JSON.stringify(part.state.input, null, 2) : "{}"
+          const input = part.state.input ? JSON.stringify(part.state.input) : "{}"
           const output = part.state.status === "error"
             ? (part.state.error ?? "Unknown error")
             : (part.state.output ?? null)
@@ -137,12 +139,13 @@ export function buildOpenCodeGraph(sessionID: string): Graph {
             parentId: prevNodeId,
             nodeType: {
               kind: "tool_call",
-              name: part.tool,
+              name: intern(part.tool),
               input,
               output,
               isError: part.state.status === "error",
             },
             timestamp: part.state.time?.start ?? asst.time.created,
+            endTimestamp: output !== null ? part.state.time?.end : undefined,
             branchLevel: 0,
             model,
             usage,
@@ -170,8 +173,10 @@ export function buildOpenCodeGraph(sessionID: string): Graph {
     }
   }
 
+  const { nodes: collapsed, removed } = collapseRepeats(nodes)
+
   // Build edges
-  const edges: Edge[] = nodes
+  const edges: Edge[] = collapsed
     .filter(n => n.parentId)
     .map(n => ({ from: n.parentId!, to: n.id, isBranch: false }))
 
@@ -181,6 +186,7 @@ export function buildOpenCodeGraph(sessionID: string): Graph {
       .filter(m => m.role === "assistant")
       .map(m => ({ usage: mapTokens(m), model: getModelId(m), cost: m.cost }))
   )
+  if (removed > 0) stats.repeatedToolCalls = removed
 
-  return { nodes, edges, stats }
+  return { nodes: collapsed, edges, stats }
 }
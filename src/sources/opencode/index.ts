@@ -1,4 +1,4 @@
-import { watch as chokidarWatch } from "chokidar"
+import { watchFiles } from "../watch"
 import { existsSync } from "fs"
 import type { Source, SessionInfo, Graph } from "../../core/types"
 import {
@@ -7,9 +7,21 @@ import {
   readMessages,
   getMessageDir,
   getPartDir,
+  sessionFilePaths,
 } from "./reader"
+import type { OCMessage } from "./reader"
 import { buildOpenCodeGraph } from "./graph"
 
+// The newest message is a finished assistant reply that didn't stop to call tools
+function isWaitingForUser(messages: OCMessage[]): boolean {
+  let last: OCMessage | null = null
+  for (const m of messages) {
+    if (!last || m.time.created > last.time.created) last = m
+  }
+  if (!last || last.role !== "assistant") return false
+  return last.time.completed !== undefined && last.finish !== "tool-calls"
+}
+
 export function createOpenCodeSource(projectPath?: string): Source {
   return {
     kind: "opencode",
@@ -18,11 +30,13 @@ export function createOpenCodeSource(projectPath?: string): Source {
       const sessions = listAllSessions()
         .filter(s => !projectPath || s.directory === projectPath)
       return sessions
-        .map(s => ({
+        .map(s => ({ session: s, messages: readMessages(s.id) }))
+        .map(({ session: s, messages }) => ({
           id: s.id,
           timestamp: s.time.updated,
-          nodeCount: readMessages(s.id).length,
-          waitingForUser: false,
+          startedAt: s.time.created,
+          nodeCount: messages.length,
+          waitingForUser: isWaitingForUser(messages),
           source: "opencode",
           title: s.title,
           slug: s.slug,
@@ -36,7 +50,11 @@ export function createOpenCodeSource(projectPath?: string): Source {
       return buildOpenCodeGraph(sessionId)
     },
 
-    watch(sessionId: string, onUpdate: (graph: Graph) => void): () => void {
+    sessionFiles(sessionId: string): string[] {
+      return sessionFilePaths(sessionId)
+    },
+
+    watch(sessionId: string, onUpdate: (graph: Graph) => void, onNotice?: (message: string) => void): () => void {
       const messageDir = getMessageDir(sessionId)
       const paths = [messageDir]
 
@@ -49,14 +67,6 @@ export function createOpenCodeSource(projectPath?: string): Source {
         }
       }
 
-      const watcher = chokidarWatch(paths, {
-        persistent: true,
-        ignoreInitial: true,
-        awaitWriteFinish: { stabilityThreshold: 100, pollInterval: 50 },
-        // Watch for new directories (new message part dirs)
-        depth: 1,
-      })
-
       let debounceTimer: ReturnType<typeof setTimeout> | null = null
 
       const rebuild = () => {
@@ -76,8 +86,13 @@ export function createOpenCodeSource(projectPath?: string): Source {
         }, 150)
       }
 
-      watcher.on("add", rebuild)
-      watcher.on("change", rebuild)
+      const watcher = watchFiles(paths, {
+        persistent: true,
+        ignoreInitial: true,
+        awaitWriteFinish: { stabilityThreshold: 100, pollInterval: 50 },
+        // Watch for new directories (new message part dirs)
+        depth: 1,
+      }, { add: rebuild, change: rebuild }, onNotice)
 
       return () => {
         if (debounceTimer) clearTimeout(debounceTimer)
@@ -160,6 +160,18 @@ export function readParts(messageID: string): OCPart[] {
   return readJsonDir<OCPart>(dir)
 }
 
+function jsonFiles(dir: string): string[] {
+  if (!existsSync(dir)) return []
+  return readdirSync(dir).filter(entry => extname(entry) === ".json").sort().map(entry => join(dir, entry))
+}
+
+// Every file a session's graph is built from: its messages, then each message's parts
+export function sessionFilePaths(sessionID: string): string[] {
+  const messages = jsonFiles(getMessageDir(sessionID))
+  const parts = messages.flatMap(file => jsonFiles(getPartDir(basename(file, ".json"))))
+  return [...messages, ...parts]
+}
+
 export function getMessageDir(sessionID: string): string {
   return join(getMessagesDir(), sessionID)
 }
@@ -0,0 +1,80 @@
+import { readdirSync, realpathSync, statSync } from "node:fs"
+import { homedir } from "node:os"
+import { basename, dirname, join, resolve, sep } from "node:path"
+
+// One canonical form per project directory, so ./foo/, foo and /abs/foo (or
+// a symlink to it) all map to the same slug. A directory that no longer
+// exists keeps its resolved path, anchored at its deepest surviving ancestor.
+export function canonicalProjectPath(path: string): string {
+  const abs = resolve(path)
+  const missing: string[] = []
+  let dir = abs
+  for (;;) {
+    try {
+      return join(realpathSync(dir), ...missing)
+    } catch {
+      const parent = dirname(dir)
+      if (parent === dir) return abs
+      missing.unshift(basename(dir))
+      dir = parent
+    }
+  }
+}
+
+// Monorepo workspaces from config: { "shop": ["~/src/shop", "~/src/shop/packages/*"] }.
+// Sessions started anywhere in a workspace show up together; a trailing /*
+// takes each subdirectory.
+export type Workspaces = Record<string, string[]>
+
+function expandHome(entry: string, home: string): string {
+  return entry.startsWith("~/") ? join(home, entry.slice(2)) : entry
+}
+
+function expandDir(entry: string, home: string): string[] {
+  const path = expandHome(entry, home)
+  if (!path.endsWith("/*")) return [canonicalProjectPath(path)]
+  const parent = canonicalProjectPath(path.slice(0, -2))
+  try {
+    return readdirSync(parent)
+      .map(name => join(parent, name))
+      .filter(dir => statSync(dir).isDirectory())
+      .sort()
+  } catch {
+    return []
+  }
+}
+
+function within(path: string, dir: string): boolean {
+  return path === dir || path.startsWith(dir.endsWith(sep) ? dir : dir + sep)
+}
+
+// Every directory whose sessions belong with projectPath's: itself first,
+// then the rest of the first workspace that contains it
+export function workspaceDirs(projectPath: string, workspaces: Workspaces = {}, home = homedir()): string[] {
+  for (const entries of Object.values(workspaces)) {
+    if (!Array.isArray(entries)) continue
+    const dirs = entries.filter(e => typeof e === "string").flatMap(e => expandDir(e, home))
+    if (dirs.some(dir => within(projectPath, dir))) return [...new Set([projectPath, ...dirs])]
+  }
+  return [projectPath]
+}
+
+// Per-project settings from config, keyed by directory: { "~/src/shop": ... }.
+// The deepest configured directory containing projectPath wins.
+export function projectSetting<T>(projectPath: string, byDir: Record<string, T> = {}, home = homedir()): T | null {
+  let best: { dir: string; value: T } | null = null
+  for (const [entry, value] of Object.entries(byDir)) {
+    const dir = canonicalProjectPath(expandHome(entry, home))
+    if (within(projectPath, dir) && (!best || dir.length > best.dir.length)) best = { dir, value }
+  }
+  return best?.value ?? null
+}
+
+// Other projects to follow in the activity feed, from config; ~ expands and
+// the current project is left out, since it's always included
+export function watchedDirs(projectPath: string, entries: string[] = [], home = homedir()): string[] {
+  const dirs = entries
+    .filter(e => typeof e === "string")
+    .map(e => canonicalProjectPath(expandHome(e, home)))
+  return [...new Set(dirs)].filter(dir => dir !== projectPath)
+}
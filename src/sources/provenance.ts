@@ -0,0 +1,47 @@
+import { createReadStream } from "node:fs"
+import { stat } from "node:fs/promises"
+import { createHash } from "node:crypto"
+import type { Source } from "../core/types"
+import type { Provenance, SessionProvenance, SourceFile } from "../core/provenance"
+import { sessionHash } from "../core/provenance"
+
+// Streamed, so hashing a large transcript doesn't hold up input
+function readSourceFile(path: string): Promise<SourceFile> {
+  return new Promise((resolve, reject) => {
+    const hash = createHash("sha256")
+    let bytes = 0
+    createReadStream(path)
+      .on("data", chunk => {
+        bytes += chunk.length
+        hash.update(chunk)
+      })
+      .on("error", reject)
+      .on("end", () => {
+        stat(path).then(s => resolve({ path, bytes, modifiedAt: s.mtimeMs, sha256: hash.digest("hex") }), reject)
+      })
+  })
+}
+
+// Hashes the session's files as they are on disk now: for a live session
+// that can be a little past the graph on screen. Undefined when the source
+// has no files (demo) or they can't be read.
+export async function readProvenance(source: Source, sessionId: string): Promise<Provenance | undefined> {
+  const paths = source.sessionFiles?.(sessionId) ?? []
+  if (paths.length === 0) return undefined
+  try {
+    const files = await Promise.all(paths.map(readSourceFile))
+    return { files, sha256: sessionHash(files) }
+  } catch {
+    return undefined
+  }
+}
+
+// For reports over several sessions; sessions without files are left out
+export async function readSessionProvenance(source: Source, sessionIds: string[]): Promise<SessionProvenance[]> {
+  const out: SessionProvenance[] = []
+  for (const sessionId of sessionIds) {
+    const provenance = await readProvenance(source, sessionId)
+    if (provenance) out.push({ sessionId, provenance })
+  }
+  return out
+}
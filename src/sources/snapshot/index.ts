@@ -0,0 +1,13 @@
+import type { Graph, Source } from "../../core/types"
+
+// --no-watch: sessions are read once and never watched, so no file watcher
+// or poller is started (archived sessions, read-only mounts, hosts out of
+// inotify watches). Reopening a session from the picker reads it again.
+export function createSnapshotSource(inner: Source): Source {
+  return {
+    ...inner,
+    watch(_sessionId: string, _onUpdate: (graph: Graph) => void): () => void {
+      return () => {}
+    },
+  }
+}
@@ -0,0 +1,24 @@
+import type { Graph, Source } from "../../core/types"
+import { isHistorical, summarizeGraph } from "../../core/summary"
+
+// --summarize: wraps a source so historical sessions load as summaries (see
+// core/summary). expandSession switches one back to full detail for the rest
+// of the run, through the same readGraph and watch calls.
+export function createSummarizingSource(inner: Source): Source {
+  const expanded = new Set<string>()
+  const shape = (sessionId: string, graph: Graph): Graph =>
+    expanded.has(sessionId) || !isHistorical(graph, Date.now()) ? graph : summarizeGraph(graph)
+
+  return {
+    ...inner,
+    async readGraph(sessionId: string): Promise<Graph> {
+      return shape(sessionId, await inner.readGraph(sessionId))
+    },
+    watch(sessionId: string, onUpdate: (graph: Graph) => void, onNotice?: (message: string) => void): () => void {
+      return inner.watch(sessionId, graph => onUpdate(shape(sessionId, graph)), onNotice)
+    },
+    expandSession(sessionId: string): void {
+      expanded.add(sessionId)
+    },
+  }
+}
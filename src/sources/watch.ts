@@ -0,0 +1,84 @@
+import { watch as chokidarWatch, type FSWatcher, type ChokidarOptions } from "chokidar"
+import { tr } from "../ui/i18n"
+
+// Transcript watching that survives the OS refusing native watches: on
+// Linux a busy machine runs out of inotify watches (ENOSPC), and read-only
+// or foreign mounts may deny them. Rather than erroring out, the watcher
+// switches to polling the same files and says so once through onNotice.
+
+export const POLL_INTERVAL_MS = 1000
+
+export type FileWatcher = {
+  add(path: string): void
+  close(): void
+}
+
+export type WatchHandlers = {
+  add?: (path: string) => void
+  change?: (path: string) => void
+}
+
+// Why native watching failed, for the notice
+export function watchFailureReason(err: unknown): string {
+  const code = typeof err === "object" && err !== null ? (err as { code?: unknown }).code : undefined
+  if (code === "ENOSPC") return tr("inotify watch limit reached")
+  if (code === "EMFILE" || code === "ENFILE") return tr("too many open files")
+  if (code === "EACCES" || code === "EPERM") return tr("permission denied")
+  return err instanceof Error ? err.message : String(err)
+}
+
+export function pollingNotice(err: unknown): string {
+  return tr("file watching unavailable ({reason}): polling every {seconds}s", { reason: watchFailureReason(err), seconds: POLL_INTERVAL_MS / 1000 })
+}
+
+// chokidar's watch, swappable so tests can refuse native watching on demand
+export type WatchFactory = (paths: string[], options: ChokidarOptions) => FSWatcher
+
+export function watchFiles(
+  paths: string[],
+  options: ChokidarOptions,
+  handlers: WatchHandlers,
+  onNotice?: (message: string) => void,
+  watch: WatchFactory = chokidarWatch,
+): FileWatcher {
+  const watched = new Set(paths)
+  let polling = false
+  let closed = false
+
+  const start = (usePolling: boolean): FSWatcher => {
+    const watcher = watch([...watched], usePolling ? { ...options, usePolling: true, interval: POLL_INTERVAL_MS } : options)
+    if (handlers.add) watcher.on("add", handlers.add)
+    if (handlers.change) watcher.on("change", handlers.change)
+    watcher.on("error", fallBack)
+    return watcher
+  }
+
+  // Errors while already polling are dropped: there's nothing left to fall back to
+  function fallBack(err: unknown): void {
+    if (polling || closed) return
+    polling = true
+    void current.close()
+    current = start(true)
+    onNotice?.(pollingNotice(err))
+  }
+
+  let current: FSWatcher
+  try {
+    current = start(false)
+  } catch (err) {
+    polling = true
+    current = start(true)
+    onNotice?.(pollingNotice(err))
+  }
+
+  return {
+    add(path: string): void {
+      watched.add(path)
+      current.add(path)
+    },
+    close(): void {
+      closed = true
+      void current.close()
+    },
+  }
+}
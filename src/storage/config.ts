@@ -0,0 +1,52 @@
+import { existsSync, readFileSync } from "node:fs"
+import { homedir } from "node:os"
+import { join } from "node:path"
+import type { Budget } from "../core/budget"
+import type { WebhookConfig } from "../notify/webhooks"
+import type { ToolGroups } from "../core/groups"
+import type { PluginConfig } from "../plugins/wasm"
+import type { Workspaces } from "../sources/project"
+
+// General settings file — separate from tool-icons.json, same lookup rules
+export type VizierConfig = {
+  // Seconds a running tool may take before it is flagged; keys are tool
+  // names (case-insensitive), "*" applies to every other tool
+  toolThresholds?: Record<string, number>
+  camera?: string // initial timeline camera: "center" | "left" | "trailing"
+  theme?: string // "default" | "high-contrast" | "colorblind"
+  locale?: string // "en" | "es"; regional forms like "es-MX" work too
+  focusDock?: string // initial focus pane position: "bottom" | "right" | "off"
+  budget?: Budget // per-session token/cost limits that raise a warning banner
+  webhooks?: WebhookConfig[] // POSTed on live events of the watched session
+  toolGroups?: ToolGroups // tool categories that stats are aggregated under
+  plugins?: PluginConfig[] // WASM hooks that render specific tools
+  workspaces?: Workspaces // project directories whose sessions list together
+  keys?: Record<string, string> // remaps: your key → the built-in key it acts as
+  annotate?: Record<string, string> // project directory → command that badges nodes
+  watchProjects?: string[] // other project directories in the activity feed
+}
+
+export function getConfigPath(): string {
+  if (process.env.VIZIER_CONFIG) return process.env.VIZIER_CONFIG
+  const base = process.env.XDG_CONFIG_HOME ?? join(homedir(), ".config")
+  return join(base, "vizier", "config.json")
+}
+
+let cachedConfig: VizierConfig | null = null
+
+export function loadConfig(): VizierConfig {
+  if (cachedConfig) return cachedConfig
+  try {
+    const path = getConfigPath()
+    const parsed = existsSync(path) ? JSON.parse(readFileSync(path, "utf8")) : {}
+    cachedConfig = parsed && typeof parsed === "object" ? parsed as VizierConfig : {}
+  } catch {
+    cachedConfig = {}
+  }
+  return cachedConfig
+}
+
+// Tests and callers that load a config from elsewhere
+export function setConfig(config: VizierConfig | null): void {
+  cachedConfig = config
+}
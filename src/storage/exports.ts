@@ -0,0 +1,16 @@
+import { writeFileSync, mkdirSync } from "node:fs"
+import { join, resolve } from "node:path"
+
+// Exports land in VIZIER_EXPORT_DIR, or the directory vizier was started from
+export function getExportDir(): string {
+  return resolve(process.env.VIZIER_EXPORT_DIR ?? process.cwd())
+}
+
+// Writes an export file and returns its absolute path
+export function writeExport(name: string, content: string): string {
+  const dir = getExportDir()
+  mkdirSync(dir, { recursive: true })
+  const path = join(dir, name)
+  writeFileSync(path, content)
+  return path
+}
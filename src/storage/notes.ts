@@ -0,0 +1,51 @@
+import { existsSync, readFileSync, writeFileSync, mkdirSync } from "node:fs"
+import { homedir } from "node:os"
+import { dirname, join } from "node:path"
+import type { SessionInfo } from "../core/types"
+
+// Session notes live in vizier's own sidecar file — Claude/OpenCode data is never modified
+export type SessionNote = {
+  note: string
+  updated: number
+}
+
+export type NotesMap = Record<string, SessionNote>
+
+export function getNotesPath(): string {
+  if (process.env.VIZIER_NOTES) return process.env.VIZIER_NOTES
+  const base = process.env.XDG_DATA_HOME ?? join(homedir(), ".local", "share")
+  return join(base, "vizier", "notes.json")
+}
+
+// Keyed by source + raw id so notes survive switching between --source modes
+export function sessionNoteKey(session: SessionInfo): string {
+  const source = session.source ?? "claude"
+  const rawId = session.id.startsWith(`${source}:`) ? session.id.slice(source.length + 1) : session.id
+  return `${source}:${rawId}`
+}
+
+export function loadNotes(): NotesMap {
+  try {
+    const path = getNotesPath()
+    if (!existsSync(path)) return {}
+    const parsed = JSON.parse(readFileSync(path, "utf8")) as { notes?: NotesMap }
+    return parsed?.notes && typeof parsed.notes === "object" ? parsed.notes : {}
+  } catch {
+    return {}
+  }
+}
+
+// Empty text removes the note. Returns the updated map.
+export function saveNote(key: string, text: string): NotesMap {
+  const notes = loadNotes()
+  const trimmed = text.trim()
+  if (trimmed) {
+    notes[key] = { note: trimmed, updated: Date.now() }
+  } else {
+    delete notes[key]
+  }
+  const path = getNotesPath()
+  mkdirSync(dirname(path), { recursive: true })
+  writeFileSync(path, JSON.stringify({ notes }, null, 2))
+  return notes
+}
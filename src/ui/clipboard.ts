@@ -0,0 +1,6 @@
+// Copy via the OSC 52 escape: the terminal owns the clipboard, so this works
+// over SSH and inside tmux (with set-clipboard on) without a helper binary
+export function copyToClipboard(text: string): void {
+  const payload = Buffer.from(text, "utf8").toString("base64")
+  process.stdout.write(`\x1b]52;c;${payload}\x07`)
+}
@@ -0,0 +1,106 @@
+// Glyph sets for timeline rendering — Unicode by default, ASCII for terminals
+// and fonts that render the symbols as tofu and break column alignment
+
+export type BorderStyle = "single" | "classic"
+
+export type GlyphSet = {
+  user: string
+  assistant: string
+  toolPending: string
+  ok: string
+  error: string
+  running: string
+  agent: string
+  progress: string
+  reasoning: string
+  patch: string
+  blinkOn: string
+  blinkOff: string
+  hLine: string
+  vLine: string
+  pointer: string
+  live: string
+  ellipsis: string
+  toolSep: string
+  repeat: string
+  warning: string
+  bullet: string
+  bar: string // Gantt bars
+  density: string // overview bucket fill, lightest to heaviest
+  mix: string // node mix chart fill, one per kind: message, read, edit, run, error
+  border: BorderStyle
+  // Tool emoji are multi-width and font-dependent, so ASCII mode drops them
+  toolIcons: boolean
+}
+
+export const UNICODE_GLYPHS: GlyphSet = {
+  user: "\u25CF",        // ●
+  assistant: "\u25C9",   // ◉
+  toolPending: "\u2B22", // ⬢
+  ok: "\u2713",          // ✓
+  error: "\u2717",       // ✗
+  running: "\u2026",     // …
+  agent: "\u27D0",       // ⟐
+  progress: "\u25CB",    // ○
+  reasoning: "\u25C7",   // ◇
+  patch: "\u25A0",       // ■
+  blinkOn: "\u25D0",     // ◐
+  blinkOff: "\u25D1",    // ◑
+  hLine: "\u2500",       // ─
+  vLine: "\u2502",       // │
+  pointer: "\u25B8",     // ▸
+  live: "\u25CF",        // ●
+  ellipsis: "\u2026",    // …
+  toolSep: "\u00B7",     // ·
+  repeat: "\u00D7",      // ×
+  warning: "\u26A0",     // ⚠
+  bullet: "\u2022",      // •
+  bar: "\u2588",         // █
+  density: "\u2581\u2582\u2583\u2584\u2585\u2586\u2587\u2588", // ▁▂▃▄▅▆▇█
+  mix: "\u2588\u2593\u2592\u2591\u2717", // █▓▒░✗
+  border: "single",
+  toolIcons: true,
+}
+
+export const ASCII_GLYPHS: GlyphSet = {
+  user: "U",
+  assistant: "A",
+  toolPending: "#",
+  ok: "+",
+  error: "x",
+  running: ".",
+  agent: "@",
+  progress: "o",
+  reasoning: "r",
+  patch: "P",
+  blinkOn: "*",
+  blinkOff: "#",
+  hLine: "-",
+  vLine: "|",
+  pointer: ">",
+  live: "*",
+  ellipsis: "~",
+  toolSep: ".",
+  repeat: "x",
+  warning: "!",
+  bullet: "-",
+  bar: "=",
+  density: ".:-=+*#@",
+  mix: "#=+:x",
+  border: "classic",
+  toolIcons: false,
+}
+
+let active: GlyphSet = UNICODE_GLYPHS
+
+export function setAsciiMode(enabled: boolean): void {
+  active = enabled ? ASCII_GLYPHS : UNICODE_GLYPHS
+}
+
+export function isAsciiMode(): boolean {
+  return active === ASCII_GLYPHS
+}
+
+export function glyphs(): GlyphSet {
+  return active
+}
@@ -0,0 +1,38 @@
+import { es } from "./locales/es"
+
+// User-facing strings in the TUI: the help line, the cheatsheet, the status
+// bar, status messages, panel labels and the tutorial. Catalogs are keyed by the English text, so
+// English needs no table and anything a catalog lacks stays readable.
+// Command-line output is left in English for the scripts that parse it.
+
+export type Locale = "en" | "es"
+export type Catalog = Record<string, string>
+
+const CATALOGS: Record<Exclude<Locale, "en">, Catalog> = { es }
+
+export const LOCALES: Locale[] = ["en", "es"]
+
+export function parseLocale(value: string | undefined): Locale | null {
+  const base = value?.toLowerCase().split(/[-_.]/)[0]
+  return LOCALES.find(l => l === base) ?? null
+}
+
+let locale: Locale = "en"
+
+export function setLocale(next: Locale): void {
+  locale = next
+}
+
+export function getLocale(): Locale {
+  return locale
+}
+
+export function catalogFor(l: Exclude<Locale, "en">): Catalog {
+  return CATALOGS[l]
+}
+
+// tr("exported {path}", { path }) — placeholders name their values
+export function tr(text: string, vars: Record<string, string | number> = {}): string {
+  const translated = locale === "en" ? text : CATALOGS[locale][text] ?? text
+  return translated.replace(/\{(\w+)\}/g, (match, name: string) => name in vars ? String(vars[name]) : match)
+}
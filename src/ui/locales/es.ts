@@ -0,0 +1,204 @@
+import type { Catalog } from "../i18n"
+
+// Spanish. Help-line hints stay short: they share one line
+export const es: Catalog = {
+  // Cheatsheet sections
+  "Navigation": "Navegación",
+  "Views": "Vistas",
+  "Panels": "Paneles",
+  "Sessions": "Sesiones",
+  "Session list": "Lista de sesiones",
+  "Live": "En vivo",
+  "Export": "Exportar",
+  "General": "General",
+
+  // Cheatsheet actions
+  "Previous / next node in time (or ←/→)": "Nodo anterior / siguiente en el tiempo (o ←/→)",
+  "Next / previous node with the same tool": "Nodo siguiente / anterior con la misma herramienta",
+  "Parent / first child of the node": "Padre / primer hijo del nodo",
+  "Subagent's conclusion / the Task result the parent got": "Conclusión del subagente / resultado de Task que recibió el padre",
+  "Jump to breadcrumb N (details open)": "Saltar a la miga N (con detalles abiertos)",
+  "Back / forward through the jump list (not ^i, which is Tab)": "Atrás / adelante en la lista de saltos (no ^i, que es Tab)",
+  "Filter nodes by an expression": "Filtrar nodos con una expresión",
+  "Filter like the cursor's node: same tool, file, agent, error (again: next, then off)": "Filtrar como el nodo del cursor: misma herramienta, archivo, agente, error (otra vez: siguiente, luego nada)",
+  "Highlight nodes related to the cursor": "Resaltar nodos relacionados con el cursor",
+  "Color nodes by token cost": "Colorear nodos por coste en tokens",
+  "Move within the current row": "Moverse dentro de la fila actual",
+  "Row down / up (or ↓/↑)": "Fila abajo / arriba (o ↓/↑)",
+  "First / last node of the row; 12G goes to the 12th": "Primer / último nodo de la fila; 12G va al 12.º",
+  "Count for the next motion (10l, 5k)": "Cuenta para el siguiente movimiento (10l, 5k)",
+  "Symbol / preview cells": "Celdas de símbolo / vista previa",
+  "List view": "Vista de lista",
+  "Gantt view": "Vista de Gantt",
+  "Zoom out to prompts and replies; buckets on a long session (z again: in)": "Alejar a preguntas y respuestas; intervalos en una sesión larga (z otra vez: acercar)",
+  "Node mix chart": "Gráfico de tipos de nodo",
+  "Test run strip": "Franja de ejecuciones de tests",
+  "Activity feed across watched projects": "Actividad de los proyectos vigilados",
+  "Reading mode (^d/^u page, g/G ends)": "Modo lectura (^d/^u página, g/G extremos)",
+  "Cycle the camera: center, left, trailing": "Cambiar la cámara: centro, izquierda, al final",
+  "Lane-per-tool layout": "Un carril por herramienta",
+  "File swimlanes": "Carriles por archivo",
+  "Scroll the focus pane (Esc leaves)": "Desplazar el panel de foco (Esc sale)",
+  "Dock the focus pane bottom, right or off": "Panel de foco abajo, a la derecha u oculto",
+  "Grow / shrink the focus pane": "Agrandar / reducir el panel de foco",
+  "Show / hide the timeline": "Mostrar / ocultar la línea de tiempo",
+  "Show / hide details": "Mostrar / ocultar detalles",
+  "Scroll details": "Desplazar detalles",
+  "Show / hide the transcript": "Mostrar / ocultar la transcripción",
+  "Lines skipped while loading": "Líneas omitidas al cargar",
+  "Load a --summarize summary in full": "Cargar completo un resumen de --summarize",
+  "Export the turn as markdown / JSON": "Exportar el turno como markdown / JSON",
+  "Copy and save an issue comment": "Copiar y guardar un comentario para la incidencia",
+  "Save the conversation up to here to seed a new session (fork)": "Guardar la conversación hasta aquí para iniciar otra sesión (bifurcar)",
+  "Mark a range start, S again at its end: save its shell commands": "Marcar el inicio de un rango, S otra vez al final: guardar sus comandos",
+  "Copy / save a code block from the reply (then its number)": "Copiar / guardar un bloque de código de la respuesta (luego su número)",
+  "Pin the node / show the pin tray (e exports pins)": "Fijar el nodo / mostrar la bandeja (e exporta los fijados)",
+  "Scrub back to the cursor's moment (B again: the present)": "Volver al momento del cursor (B otra vez: el presente)",
+  "Scrub one event back / forward (takes a count)": "Un evento atrás / adelante (admite cuenta)",
+  "Pause / resume live updates": "Pausar / reanudar las actualizaciones",
+  "Hide progress events": "Ocultar eventos de progreso",
+  "Hide successful tool results": "Ocultar resultados correctos",
+  "Next recent session (shift+tab: previous)": "Siguiente sesión reciente (shift+tab: anterior)",
+  "Close the split view": "Cerrar la vista dividida",
+  "Switch split pane": "Cambiar de panel dividido",
+  "Cycle the sort order": "Cambiar el orden",
+  "Edit the session's note": "Editar la nota de la sesión",
+  "Search sessions": "Buscar sesiones",
+  "Open the session": "Abrir la sesión",
+  "Open the session beside this one": "Abrir la sesión junto a esta",
+  "Follow the newest node": "Seguir el nodo más reciente",
+  "Send a message (OpenCode server only)": "Enviar un mensaje (solo servidor OpenCode)",
+  "Abort the session (OpenCode server only)": "Abortar la sesión (solo servidor OpenCode)",
+  "This cheatsheet (e/E export it as markdown / text)": "Esta chuleta (e/E la exportan como markdown / texto)",
+  "Quit": "Salir",
+
+  // Help-line hints
+  "chrono": "crono",
+  "same tool": "misma herr.",
+  "parent/child": "padre/hijo",
+  "breadcrumb": "migas",
+  "jump back/fwd": "saltos",
+  "filter": "filtro",
+  "like this": "como este",
+  "related": "relacionados",
+  "token colors": "color tokens",
+  "level": "nivel",
+  "row": "fila",
+  "preview": "vista previa",
+  "list": "lista",
+  "gantt": "gantt",
+  "zoom out": "alejar",
+  "mix": "mezcla",
+  "tests": "tests",
+  "feed": "actividad",
+  "reading": "lectura",
+  "camera": "cámara",
+  "lanes": "carriles",
+  "files": "archivos",
+  "focus": "foco",
+  "focus dock": "pos. foco",
+  "resize": "tamaño",
+  "timeline": "línea",
+  "details": "detalles",
+  "transcript": "transcripción",
+  "export turn": "exportar turno",
+  "issue comment": "comentario",
+  "pin/tray": "fijar/bandeja",
+  "scrub": "viajar",
+  "pause": "pausa",
+  "progress": "progreso",
+  "compact": "compacto",
+  "sessions": "sesiones",
+  "next session": "sig. sesión",
+  "split": "dividir",
+  "pane": "panel",
+  "follow": "seguir",
+  "keys": "teclas",
+  "quit": "salir",
+
+  // Cheatsheet title
+  "vizier keys": "teclas de vizier",
+  "Key": "Tecla",
+  "Action": "Acción",
+
+  // Status bar
+  "{count} nodes": "{count} nodos",
+  "{count} repeated calls": "{count} llamadas repetidas",
+  "STARTING": "INICIANDO",
+  "LIVE": "EN VIVO",
+  "RUNNING": "EJECUTANDO",
+  "WAITING": "ESPERANDO",
+  "IDLE": "INACTIVA",
+  "ENDED": "TERMINADA",
+  "ERROR": "ERROR",
+  "PAUSED": "EN PAUSA",
+  "+{count} new": "+{count} nuevos",
+  "FOLLOW": "SIGUIENDO",
+  "AT {time}": "EN {time}",
+  "1 line skipped (!)": "1 línea omitida (!)",
+  "{count} lines skipped (!)": "{count} líneas omitidas (!)",
+  "{name} running {duration}": "{name} ejecutándose {duration}",
+
+  // Status messages
+  "exported {path}": "exportado en {path}",
+  "exported {path} (and .json)": "exportado en {path} (y .json)",
+  "export failed: {error}": "la exportación falló: {error}",
+  "copied, exported {path}": "copiado, exportado en {path}",
+  "copied; export failed: {error}": "copiado; la exportación falló: {error}",
+  "copied block {n}": "bloque {n} copiado",
+  "saved {path}": "guardado en {path}",
+  "save failed: {error}": "no se pudo guardar: {error}",
+  "range start marked: press S again at its end": "inicio del rango marcado: pulsa S otra vez en su final",
+  "the range start is gone": "el inicio del rango ya no existe",
+  "no older jumps": "no hay saltos anteriores",
+  "no newer jumps": "no hay saltos posteriores",
+  ":goto {spec}: not found": ":goto {spec}: no encontrado",
+  "the session is already in full": "la sesión ya está completa",
+  "loaded {count} nodes": "{count} nodos cargados",
+  "back to the present": "de vuelta al presente",
+  "filter: {expr}": "filtro: {expr}",
+  "filter cleared": "filtro quitado",
+  "session {n}/{total}: {label}": "sesión {n}/{total}: {label}",
+  "not in a subagent or on a Task call": "no estás en un subagente ni en una llamada Task",
+  "result the parent received": "resultado que recibió el padre",
+  "agent's conclusion": "conclusión del agente",
+
+  // Panels
+  "No node selected": "Ningún nodo seleccionado",
+  "Reading {percent}%  j/k:scroll ctrl+d/u:page g/G:top/end R:exit": "Leyendo {percent}%  j/k:desplazar ctrl+d/u:página g/G:inicio/fin R:salir",
+  "No messages yet": "Aún no hay mensajes",
+  "Pinned {count}/{max}": "Fijados {count}/{max}",
+  "a:pin/unpin selected  e:export report  A:close tray": "a:fijar/soltar seleccionado  e:exportar informe  A:cerrar bandeja",
+  "Nothing pinned: press a on a node to add it here": "Nada fijado: pulsa a en un nodo para añadirlo aquí",
+  "Gantt": "Gantt",
+  "no tool calls yet": "aún no hay llamadas a herramientas",
+  "{count} bars  {from} - {to} ({duration})  j/k:bar V:exit": "{count} barras  {from} - {to} ({duration})  j/k:barra V:salir",
+  "Keys": "Teclas",
+  "(widen the terminal for the rest)": "(ensancha la terminal para ver el resto)",
+  "e:export markdown E:export text ?:close": "e:exportar markdown E:exportar texto ?:cerrar",
+  "file watching unavailable ({reason}): polling every {seconds}s": "vigilancia de archivos no disponible ({reason}): sondeo cada {seconds}s",
+  "inotify watch limit reached": "se alcanzó el límite de vigilancias de inotify",
+  "too many open files": "demasiados archivos abiertos",
+  "permission denied": "permiso denegado",
+
+  // Tutorial
+  "Tutorial {step}/{total}: {title}": "Tutorial {step}/{total}: {title}",
+  "Tutorial complete": "Tutorial completado",
+  "Keep exploring this session, or press q to quit. vizier on its own opens your latest session.": "Sigue explorando esta sesión o pulsa q para salir. vizier sin argumentos abre tu última sesión.",
+  "Moving in time": "Moverse en el tiempo",
+  "Press l or h (or the arrow keys) to step to the next or previous event.": "Pulsa l o h (o las flechas) para pasar al evento siguiente o anterior.",
+  "Rows": "Filas",
+  "Press j or k to move between rows: prompts, replies, tool calls, then subagents.": "Pulsa j o k para cambiar de fila: mensajes, respuestas, llamadas a herramientas y subagentes.",
+  "Details": "Detalles",
+  "Press d to open the details panel for the selected node. d closes it again.": "Pulsa d para abrir el panel de detalles del nodo seleccionado. d lo vuelve a cerrar.",
+  "Following links": "Seguir enlaces",
+  "Press p to jump to the node's parent or c to its first child. Ctrl-o jumps back.": "Pulsa p para saltar al padre del nodo o c a su primer hijo. Ctrl-o vuelve atrás.",
+  "Layouts": "Disposiciones",
+  "Press L to give each kind of tool its own row, or F for one row per edited file.": "Pulsa L para dar a cada tipo de herramienta su propia fila, o F para una fila por archivo editado.",
+  "Zooming out": "Alejarse",
+  "Press V to see how long each tool call took, then V again to come back.": "Pulsa V para ver cuánto tardó cada llamada a herramienta y V otra vez para volver.",
+  "Filters": "Filtros",
+  "Press / and type type:tool error:true, then Enter. An empty filter clears it.": "Pulsa / y escribe type:tool error:true, luego Enter. Un filtro vacío lo quita.",
+  "Exporting": "Exportar",
+  "Press e to write the selected turn to a Markdown file; E writes JSON.": "Pulsa e para guardar el turno seleccionado en un archivo Markdown; E guarda JSON.",
+}
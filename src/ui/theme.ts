@@ -0,0 +1,90 @@
+// Text styling — maps semantic colors to Ink props. In monochrome mode
+// (NO_COLOR, non-tty, --no-color) colors are swapped for text modifiers so
+// status signals survive without color.
+
+export type InkColor = "black" | "red" | "green" | "yellow" | "blue" | "magenta" | "cyan" | "white" | "gray"
+
+export type TextStyle = {
+  color?: InkColor
+  backgroundColor?: InkColor
+  bold?: boolean
+  dimColor?: boolean
+  italic?: boolean
+  underline?: boolean
+  inverse?: boolean
+}
+
+const MONO_STYLES: Record<InkColor, TextStyle> = {
+  red: { bold: true, underline: true },
+  yellow: { bold: true },
+  green: {},
+  cyan: { bold: true },
+  magenta: { italic: true },
+  blue: { underline: true },
+  gray: { dimColor: true },
+  white: {},
+  black: {},
+}
+
+// Config files name colors as plain strings
+export function isInkColor(value: unknown): value is InkColor {
+  return typeof value === "string" && Object.hasOwn(MONO_STYLES, value)
+}
+
+// Accessible palettes, picked with "theme" in config.json. Each swaps some
+// colors for others; OK and ERR always carry distinct glyphs as well, so no
+// signal rests on telling red from green.
+export type ThemeName = "default" | "high-contrast" | "colorblind"
+
+export const THEMES: ThemeName[] = ["default", "high-contrast", "colorblind"]
+
+const THEME_COLORS: Record<ThemeName, Partial<Record<InkColor, InkColor>>> = {
+  default: {},
+  // gray and blue are hard to read on dark backgrounds
+  "high-contrast": { gray: "white", blue: "cyan" },
+  // blue/yellow stay apart for red-green color blindness; pending moves to white
+  colorblind: { green: "blue", red: "yellow", yellow: "white" },
+}
+
+let theme: ThemeName = "default"
+
+export function parseTheme(value: unknown): ThemeName | null {
+  return THEMES.find(t => t === value) ?? null
+}
+
+export function setTheme(name: ThemeName): void {
+  theme = name
+}
+
+let monochrome = false
+
+export function setMonochrome(enabled: boolean): void {
+  monochrome = enabled
+}
+
+export function isMonochrome(): boolean {
+  return monochrome
+}
+
+// https://no-color.org — any non-empty value disables color
+export function shouldDisableColor(flag: boolean, env = process.env, isTTY = process.stdout.isTTY): boolean {
+  if (flag) return true
+  if (env.NO_COLOR !== undefined && env.NO_COLOR !== "") return true
+  return !isTTY
+}
+
+export function paint(color: InkColor | undefined): TextStyle {
+  if (!color) return {}
+  if (monochrome) return MONO_STYLES[color]
+  const mapped = THEME_COLORS[theme][color] ?? color
+  return theme === "high-contrast" ? { color: mapped, bold: true } : { color: mapped }
+}
+
+export function borderColor(color: InkColor): InkColor | undefined {
+  return monochrome ? undefined : THEME_COLORS[theme][color] ?? color
+}
+
+// Highlight for the cursor cell
+export function cursorStyle(): TextStyle {
+  return monochrome ? { inverse: true } : { backgroundColor: "white", color: "black" }
+}
@@ -13,6 +13,7 @@ export type UiSpec = {
 export type ToolIconRule = {
   icon?: string
   iconId?: string
+  color?: string // timeline color for the tool's calls: red, green, yellow, blue, magenta, cyan, white, gray
   tool?: string
   toolPattern?: string
   inputContains?: string
@@ -32,7 +33,7 @@ function normalizeToolName(name: string): string {
 
 function baseToolName(name: string): string {
   const normalized = normalizeToolName(name)
-  const parts = normalized.split(/[/:]/).filter(Boolean)
+  const parts = normalized.split(/__|[/:]/).filter(Boolean) // mcp__server__tool → tool
   return parts.length > 0 ? parts[parts.length - 1] : normalized
 }
 
@@ -126,16 +127,23 @@ function getToolMatch(node: Node): ToolMatch | null {
   }
 }
 
+// Icon and color resolve separately, each from the first matching rule that
+// sets it, so a color-only rule keeps the default icon
 export function getToolUi(node: Node): UiSpec | null {
   const match = getToolMatch(node)
   if (!match) return null
-  const rules = loadRules()
-  for (const rule of rules) {
-    if (ruleMatches(rule, match)) {
-      return { iconText: rule.icon, iconId: rule.iconId }
+  let ui: UiSpec | null = null
+  for (const rule of loadRules()) {
+    if (!ruleMatches(rule, match)) continue
+    ui ??= {}
+    if (ui.iconText === undefined && ui.iconId === undefined && (rule.icon || rule.iconId)) {
+      ui.iconText = rule.icon
+      ui.iconId = rule.iconId
     }
+    if (ui.color === undefined && rule.color) ui.color = rule.color
+    if ((ui.iconText || ui.iconId) && ui.color) break
   }
-  return null
+  return ui
 }
 
 export { USER_RULES_PATH }
@@ -0,0 +1,62 @@
+import stringWidth from "string-width"
+import { glyphs } from "./glyphs"
+
+// Terminal column math. CJK and most emoji take two columns and combining
+// marks none, so String.length misplaces borders and column boundaries.
+
+const graphemes = new Intl.Segmenter()
+
+export function displayWidth(text: string): number {
+  return stringWidth(text)
+}
+
+// Longest prefix that fits in `width` columns, never splitting a grapheme
+export function sliceToWidth(text: string, width: number): string {
+  if (stringWidth(text) <= width) return text
+  let out = ""
+  let w = 0
+  for (const { segment } of graphemes.segment(text)) {
+    const cw = stringWidth(segment)
+    if (w + cw > width) break
+    out += segment
+    w += cw
+  }
+  return out
+}
+
+// Clip to `width` columns, marking the cut with an ellipsis
+export function truncateToWidth(text: string, width: number, ellipsis = glyphs().ellipsis): string {
+  if (stringWidth(text) <= width) return text
+  return sliceToWidth(text, Math.max(0, width - stringWidth(ellipsis))) + ellipsis
+}
+
+// Clip (without ellipsis) or space-pad to exactly `width` columns
+export function padToWidth(text: string, width: number): string {
+  const fitted = sliceToWidth(text, width)
+  const w = stringWidth(fitted)
+  return w >= width ? fitted : fitted + " ".repeat(width - w)
+}
+
+// Space-pad on the left to `width` columns, for right-aligned cells
+export function padStartToWidth(text: string, width: number): string {
+  const w = stringWidth(text)
+  return w >= width ? text : " ".repeat(width - w) + text
+}
+
+// Word-wrap a single line into [start, end) index ranges of at most `width`
+// columns, breaking at the last space when it falls in the second half
+export function wrapRanges(line: string, width: number): [number, number][] {
+  const end = line.trimEnd().length
+  const ranges: [number, number][] = []
+  let start = 0
+  while (stringWidth(line.slice(start, end)) > width) {
+    const fit = start + Math.max(1, sliceToWidth(line.slice(start, end), width).length)
+    const cut = line.lastIndexOf(" ", fit)
+    const at = cut - start > (fit - start) / 2 ? cut : fit
+    ranges.push([start, at])
+    start = at
+    while (start < end && line[start] === " ") start++
+  }
+  ranges.push([start, end])
+  return ranges
+}
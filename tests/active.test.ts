@@ -0,0 +1,44 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { findOverdueTools, toolThresholdMs, DEFAULT_TOOL_THRESHOLD_S } from "../src/core/active"
+import { formatDuration, formatClock } from "../src/core/time"
+import { toolCall } from "./fixtures"
+
+function call(name: string, timestamp: number, output: string | null): Node {
+  return toolCall(`${name}-${timestamp}`, name, { input: "", output }, { timestamp, branchLevel: 2 })
+}
+
+describe("toolThresholdMs", () => {
+  it("matches exact, namespaced and wildcard entries", () => {
+    const thresholds = { Bash: 60, search: 10, "*": 30 }
+    expect(toolThresholdMs("bash", thresholds)).toBe(60_000)
+    expect(toolThresholdMs("mcp__github__search", thresholds)).toBe(10_000)
+    expect(toolThresholdMs("Read", thresholds)).toBe(30_000)
+    expect(toolThresholdMs("Read")).toBe(DEFAULT_TOOL_THRESHOLD_S * 1000)
+  })
+})
+
+describe("findOverdueTools", () => {
+  it("reports only unresolved tools past their threshold, longest first", () => {
+    const nodes = [call("Bash", 0, null), call("Read", 50_000, null), call("Bash", 0, "done")]
+    const overdue = findOverdueTools(nodes, 100_000, { "*": 30 })
+    expect(overdue.map(t => [t.idx, t.elapsedMs])).toEqual([[0, 100_000], [1, 50_000]])
+    expect(findOverdueTools(nodes, 100_000, { Read: 60, "*": 120 })).toEqual([])
+  })
+})
+
+describe("formatDuration", () => {
+  it("scales units", () => {
+    expect(formatDuration(42_000)).toBe("42s")
+    expect(formatDuration(252_000)).toBe("4m12s")
+    expect(formatDuration(4_320_000)).toBe("1h12m")
+  })
+})
+
+describe("formatClock", () => {
+  it("pads each field and can drop the seconds", () => {
+    const ts = Date.UTC(2025, 0, 1, 9, 5, 7)
+    expect(formatClock(ts, { utc: true })).toBe("09:05:07")
+    expect(formatClock(ts, { utc: true, seconds: false })).toBe("09:05")
+  })
+})
@@ -0,0 +1,63 @@
+import { describe, it, expect } from "bun:test"
+import type { Graph, Node } from "../src/core/types"
+import { emptyStats } from "../src/core/stats"
+import { annotationKey, annotationRequest, parseAnnotation, withAnnotations, formatBadges, MAX_BADGES } from "../src/core/annotations"
+import { bash } from "./fixtures"
+
+function call(id: string, output: string | null): Node {
+  return bash(id, "make deploy", { output }, { branchLevel: 2 })
+}
+
+describe("annotationRequest", () => {
+  it("sends the node's id, kind and tool call", () => {
+    expect(JSON.parse(annotationRequest(call("t1", "ok")))).toEqual({
+      id: "t1",
+      kind: "tool_call",
+      timestamp: new Date(0).toISOString(),
+      agentId: null,
+      name: "Bash",
+      input: "{\"command\":\"make deploy\"}",
+      output: "ok",
+      isError: false,
+    })
+  })
+
+  it("sends a tool call again once it finishes", () => {
+    expect(annotationKey(call("t1", null))).not.toBe(annotationKey(call("t1", "ok")))
+  })
+})
+
+describe("parseAnnotation", () => {
+  it("reads a reply's badges", () => {
+    expect(parseAnnotation("{\"id\":\"t1\",\"badges\":[\"JIRA-142\",\" staging \"]}")).toEqual({ id: "t1", badges: ["JIRA-142", "staging"] })
+  })
+
+  it("ignores anything that isn't a reply", () => {
+    expect(parseAnnotation("starting up")).toBeNull()
+    expect(parseAnnotation("{\"id\":\"t1\"}")).toBeNull()
+    expect(parseAnnotation("[]")).toBeNull()
+  })
+
+  it("drops empty badges and caps count and length", () => {
+    const reply = parseAnnotation(JSON.stringify({ id: "t1", badges: ["", 3, "a", "b", "c", "d", "e", "x".repeat(40)] }))
+    expect(reply?.badges).toEqual(["a", "b", "c", "d"])
+    expect(reply?.badges.length).toBe(MAX_BADGES)
+    expect(parseAnnotation(JSON.stringify({ id: "t1", badges: ["x".repeat(40)] }))?.badges[0].length).toBe(24)
+  })
+})
+
+describe("withAnnotations", () => {
+  const graph: Graph = { nodes: [call("t1", "ok"), call("t2", "ok")], edges: [], stats: emptyStats() }
+
+  it("puts badges on the annotated nodes", () => {
+    const next = withAnnotations(graph, { t2: ["prod"] })
+    expect(next.nodes[0].badges).toBeUndefined()
+    expect(next.nodes[1].badges).toEqual(["prod"])
+    expect(formatBadges(next.nodes[1].badges)).toBe("[prod]")
+  })
+
+  it("keeps the graph when nothing applies", () => {
+    expect(withAnnotations(graph, {})).toBe(graph)
+    expect(withAnnotations(graph, { t1: [] })).toBe(graph)
+  })
+})
@@ -0,0 +1,44 @@
+import { describe, it, expect } from "bun:test"
+import type { Node, Usage } from "../src/core/types"
+import { attributeToolTokens } from "../src/core/attribution"
+import { node, toolCall } from "./fixtures"
+
+function call(name: string, output: string | null, usage: Usage, agentId?: string): Node {
+  return toolCall(`${name}-${Math.random()}`, name, { input: "", output }, { agentId, usage, branchLevel: agentId ? 1 : 0 })
+}
+
+function reply(usage: Usage, agentId?: string): Node {
+  return node(`a-${Math.random()}`, { kind: "assistant", text: "ok" }, { agentId, usage, branchLevel: agentId ? 1 : 0 })
+}
+
+describe("attributeToolTokens", () => {
+  it("splits the consuming request's new tokens by output size", () => {
+    const first: Usage = { input_tokens: 10 }
+    const nodes = [
+      call("Read", "x".repeat(300), first),
+      call("Grep", "x".repeat(100), first), // same response, so no request in between
+      reply({ input_tokens: 350, cache_creation_input_tokens: 50, cache_read_input_tokens: 9000 }),
+    ]
+    expect(attributeToolTokens(nodes)).toEqual([
+      { name: "Read", tokens: 300, calls: 1 },
+      { name: "Grep", tokens: 100, calls: 1 },
+    ])
+  })
+
+  it("charges each agent's results to that agent's next request", () => {
+    const nodes = [
+      call("Bash", "main output", { input_tokens: 1 }),
+      call("Read", "agent output", { input_tokens: 1 }, "g1"),
+      reply({ input_tokens: 70 }, "g1"),
+      reply({ input_tokens: 20 }),
+    ]
+    expect(attributeToolTokens(nodes)).toEqual([
+      { name: "Read", tokens: 70, calls: 1 },
+      { name: "Bash", tokens: 20, calls: 1 },
+    ])
+  })
+
+  it("ignores calls that have no result yet", () => {
+    expect(attributeToolTokens([call("Bash", null, { input_tokens: 5 }), reply({ input_tokens: 50 })])).toEqual([])
+  })
+})
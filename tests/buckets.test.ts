@@ -0,0 +1,38 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { needsBuckets, bucketNodes, bucketOf, stepBucket, BUCKET_FACTOR } from "../src/core/buckets"
+import { toolCall } from "./fixtures"
+
+function call(at: number, isError = false): Node {
+  return toolCall(`t${at}`, "Bash", { input: "", output: "", isError }, { timestamp: at })
+}
+
+const nodes = [call(0), call(1, true), call(2), call(90), call(99)]
+
+describe("timeline buckets", () => {
+  it("buckets only when nodes far outnumber columns", () => {
+    expect(needsBuckets(10 * BUCKET_FACTOR, 10)).toBe(false)
+    expect(needsBuckets(10 * BUCKET_FACTOR + 1, 10)).toBe(true)
+  })
+
+  it("splits the time span into one bucket per column", () => {
+    const buckets = bucketNodes(nodes, 4)
+    expect(buckets.map(b => b.count)).toEqual([3, 0, 0, 2])
+    expect(buckets[0]).toMatchObject({ first: 0, last: 2, tools: 3, errors: 1 })
+  })
+
+  it("finds a node's bucket and skips empty ones", () => {
+    const buckets = bucketNodes(nodes, 4)
+    expect(bucketOf(buckets, 99)).toBe(3)
+    expect(bucketOf(buckets, 1)).toBe(0)
+    expect(stepBucket(buckets, 0, 1)).toBe(3)
+    expect(stepBucket(buckets, 3, 1)).toBeNull()
+  })
+
+  it("anchors on time when nodes are out of order", () => {
+    const shuffled = [call(90), call(2), call(0), call(99), call(1, true)]
+    const buckets = bucketNodes(shuffled, 4)
+    expect(buckets[0]).toMatchObject({ first: 2, last: 1, count: 3 })
+    expect(bucketOf(buckets, shuffled[3].timestamp)).toBe(3)
+  })
+})
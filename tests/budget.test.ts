@@ -0,0 +1,23 @@
+import { describe, it, expect } from "bun:test"
+import { checkBudget } from "../src/core/budget"
+import { computeStats } from "../src/core/stats"
+
+const stats = { ...computeStats([{ usage: { input_tokens: 400, output_tokens: 100, cache_read_input_tokens: 500 } }]), totalCost: 2.5 }
+
+describe("checkBudget", () => {
+  it("stays quiet without a budget or under it", () => {
+    expect(checkBudget(stats, undefined)).toEqual([])
+    expect(checkBudget(stats, { tokens: 2000, cost: 5 })).toEqual([])
+  })
+
+  it("reports each limit that has been reached", () => {
+    expect(checkBudget(stats, { tokens: 1000, cost: 2 })).toEqual([
+      { kind: "tokens", used: 1000, limit: 1000 },
+      { kind: "cost", used: 2.5, limit: 2 },
+    ])
+  })
+
+  it("ignores limits that aren't positive numbers", () => {
+    expect(checkBudget(stats, { tokens: 0, cost: "1" as unknown as number })).toEqual([])
+  })
+})
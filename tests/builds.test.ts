@@ -0,0 +1,62 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { buildCommand, parseDiagnostics, buildCheck, buildBadge, formatBuildCounts } from "../src/core/builds"
+import { bash } from "./fixtures"
+
+function run(command: string, output: string | null, isError = false): Node {
+  return bash(command, command, { output, isError })
+}
+
+describe("buildCommand", () => {
+  it("tells builds from lints", () => {
+    expect(buildCommand("cargo clippy --all-targets")).toEqual({ kind: "lint", tool: "cargo clippy" })
+    expect(buildCommand("cd web && npm run build")).toEqual({ kind: "build", tool: "npm run build" })
+    expect(buildCommand("npx tsc --noEmit")).toEqual({ kind: "build", tool: "npx tsc" })
+    expect(buildCommand("ruff check .")).toEqual({ kind: "lint", tool: "ruff check" })
+    expect(buildCommand("make && ./run")).toEqual({ kind: "build", tool: "make" })
+  })
+
+  it("leaves test runs and other commands alone", () => {
+    expect(buildCommand("make test")).toBeNull()
+    expect(buildCommand("cargo test")).toBeNull()
+    expect(buildCommand("git status")).toBeNull()
+  })
+})
+
+describe("parseDiagnostics", () => {
+  it("prefers the tool's own summary", () => {
+    expect(parseDiagnostics("✖ 5 problems (3 errors, 2 warnings)")).toEqual({ errors: 3, warnings: 2 })
+    const tsc = "src/a.ts(1,2): error TS2322: bad\nsrc/b.ts(3,4): error TS2345: worse\n\nFound 2 errors in 2 files."
+    expect(parseDiagnostics(tsc)).toEqual({ errors: 2, warnings: null })
+  })
+
+  it("reads cargo's totals", () => {
+    expect(parseDiagnostics("warning: unused variable: `x`\n\nwarning: `demo` (bin \"demo\") generated 1 warning\n    Finished dev"))
+      .toEqual({ errors: null, warnings: 1 })
+    expect(parseDiagnostics("error[E0308]: mismatched types\nerror: could not compile `demo` (bin \"demo\") due to 1 previous error"))
+      .toEqual({ errors: 1, warnings: null })
+  })
+
+  it("counts diagnostic lines without a summary", () => {
+    expect(parseDiagnostics("a.py:1: error: no\na.py:2: warning: hmm")).toEqual({ errors: 1, warnings: 1 })
+  })
+})
+
+describe("buildCheck", () => {
+  it("badges finished build and lint calls", () => {
+    const lint = buildCheck(run("cargo clippy", "warning: `demo` (lib) generated 1 warning"))!
+    expect(lint).toEqual({ kind: "lint", tool: "cargo clippy", ok: true, errors: 0, warnings: 1 })
+    expect(buildBadge(lint)).toBe("lint ✓ 1w")
+    expect(formatBuildCounts(lint)).toBe("1 warning")
+
+    const build = buildCheck(run("tsc", "Found 2 errors in 1 file.", true))!
+    expect(buildBadge(build)).toBe("build ✗ 2e")
+    expect(formatBuildCounts(build)).toBe("2 errors")
+    expect(formatBuildCounts(buildCheck(run("npm run build", "exit 1", true))!)).toBe("failed")
+  })
+
+  it("skips pending and unrelated calls", () => {
+    expect(buildCheck(run("cargo build", null))).toBeNull()
+    expect(buildCheck(run("ls", "a b"))).toBeNull()
+  })
+})
@@ -0,0 +1,33 @@
+import { describe, it, expect } from "bun:test"
+import { getWindowStart, nextCameraMode, isCameraMode } from "../src/core/camera"
+
+describe("getWindowStart", () => {
+  it("centers the cursor, clamped at both ends", () => {
+    expect(getWindowStart("center", 50, 100, 10)).toBe(45)
+    expect(getWindowStart("center", 2, 100, 10)).toBe(0)
+    expect(getWindowStart("center", 99, 100, 10)).toBe(90)
+  })
+
+  it("anchors the cursor at the left edge", () => {
+    expect(getWindowStart("left", 50, 100, 10)).toBe(50)
+    expect(getWindowStart("left", 95, 100, 10)).toBe(90)
+  })
+
+  it("keeps the cursor at the trailing edge", () => {
+    expect(getWindowStart("trailing", 50, 100, 10)).toBe(41)
+    expect(getWindowStart("trailing", 3, 100, 10)).toBe(0)
+  })
+
+  it("shows everything when it fits", () => {
+    expect(getWindowStart("trailing", 4, 5, 10)).toBe(0)
+  })
+})
+
+describe("camera modes", () => {
+  it("cycles and validates", () => {
+    expect(nextCameraMode("center")).toBe("left")
+    expect(nextCameraMode("trailing")).toBe("center")
+    expect(isCameraMode("left")).toBe(true)
+    expect(isCameraMode("right")).toBe(false)
+  })
+})
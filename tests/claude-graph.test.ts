@@ -1,6 +1,7 @@
 import { describe, it, expect } from "bun:test"
 import type { SessionEvent } from "../src/core/types"
 import { buildGraph } from "../src/sources/claude/graph"
+import { isWaitingForUser } from "../src/sources/claude/parser"
 
 function ts(n: number): string {
   return new Date(1_700_000_000_000 + n).toISOString()
@@ -186,4 +187,65 @@ describe("claude buildGraph", () => {
     const agentUser = graph.nodes.find(n => n.agentId && n.nodeType.kind === "user")
     expect(agentUser).toBeUndefined()
   })
+
+  it("reads text blocks from array tool_result content and keeps raw input", () => {
+    const events: SessionEvent[] = [
+      {
+        uuid: "a1",
+        type: "assistant",
+        timestamp: ts(1),
+        message: {
+          role: "assistant",
+          content: [{ type: "tool_use", id: "tool-1", name: "read", input: { file_path: "a.txt" } }],
+        },
+      },
+      {
+        uuid: "u1",
+        type: "user",
+        timestamp: ts(2),
+        message: {
+          role: "user",
+          content: [
+            { type: "tool_result", tool_use_id: "tool-1", content: [{ type: "text", text: "line one" }, { type: "text", text: "line two" }] },
+          ],
+        },
+      },
+    ]
+    const graph = buildGraph(events)
+    const tool = graph.nodes.find(n => n.nodeType.kind === "tool_call")
+    if (tool?.nodeType.kind !== "tool_call") throw new Error("expected tool_call")
+    expect(tool.nodeType.output).toBe("line one\nline two")
+    expect(JSON.parse(tool.nodeType.input)).toEqual({ file_path: "a.txt" })
+  })
+})
+
+describe("claude isWaitingForUser", () => {
+  it("is true after a final assistant text reply", () => {
+    const events: SessionEvent[] = [
+      { uuid: "u1", type: "user", timestamp: ts(1), message: { role: "user", content: "hi" } },
+      { uuid: "a1", type: "assistant", timestamp: ts(2), message: { role: "assistant", content: [{ type: "text", text: "done" }] } },
+      { uuid: "p1", type: "progress", timestamp: ts(3) },
+    ]
+    expect(isWaitingForUser(events)).toBe(true)
+  })
+
+  it("is false while a tool call is pending or results are being fed back", () => {
+    const pending: SessionEvent[] = [
+      { uuid: "a1", type: "assistant", timestamp: ts(1), message: { role: "assistant", content: [{ type: "tool_use", id: "t1", name: "bash", input: {} }] } },
+    ]
+    const feeding: SessionEvent[] = [
+      ...pending,
+      { uuid: "u1", type: "user", timestamp: ts(2), message: { role: "user", content: [{ type: "tool_result", tool_use_id: "t1", content: "ok" }] } },
+    ]
+    expect(isWaitingForUser(pending)).toBe(false)
+    expect(isWaitingForUser(feeding)).toBe(false)
+  })
+
+  it("ignores subagent events", () => {
+    const events: SessionEvent[] = [
+      { uuid: "a1", type: "assistant", timestamp: ts(1), message: { role: "assistant", content: [{ type: "tool_use", id: "t1", name: "Task", input: {} }] } },
+      { uuid: "g1", type: "assistant", agentId: "agent-1", timestamp: ts(2), message: { role: "assistant", content: "agent says hi" } },
+    ]
+    expect(isWaitingForUser(events)).toBe(false)
+  })
 })
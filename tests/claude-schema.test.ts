@@ -0,0 +1,57 @@
+import { describe, it, expect } from "bun:test"
+import type { SessionEvent } from "../src/core/types"
+import { detectSchema, upgradeEvents } from "../src/sources/claude/schema"
+import { buildGraph } from "../src/sources/claude/graph"
+
+function ts(n: number): string {
+  return new Date(1_700_000_000_000 + n).toISOString()
+}
+
+function task(uuid: string, ids: string[], at: number): SessionEvent {
+  return {
+    uuid,
+    type: "assistant",
+    timestamp: ts(at),
+    message: { role: "assistant", content: ids.map(id => ({ type: "tool_use", id, name: "Task", input: {} })) },
+  }
+}
+
+function side(uuid: string, parentUuid: string | undefined, text: string, at: number): SessionEvent {
+  return { uuid, parentUuid, isSidechain: true, type: "assistant", timestamp: ts(at), message: { role: "assistant", content: text } }
+}
+
+// Two parallel Task calls whose subagents wrote into the session file itself
+const legacy: SessionEvent[] = [
+  { uuid: "u1", type: "user", timestamp: ts(0), message: { role: "user", content: "go" } },
+  task("a1", ["task-1", "task-2"], 1),
+  side("s1", undefined, "first agent", 2),
+  side("t1", undefined, "second agent", 3),
+  side("s2", "s1", "first agent again", 4),
+]
+
+describe("claude transcript schema", () => {
+  it("detects the legacy layout from agent-less sidechains", () => {
+    expect(detectSchema(legacy)).toBe("legacy")
+    expect(detectSchema([{ ...legacy[2], agentId: "x" }])).toBe("current")
+    expect(detectSchema(legacy.slice(0, 2))).toBe("current")
+  })
+
+  it("leaves current transcripts untouched", () => {
+    const current = legacy.slice(0, 2)
+    expect(upgradeEvents(current)).toBe(current)
+  })
+
+  it("gives each sidechain chain an agent linked to its Task call", () => {
+    const events = upgradeEvents(legacy)
+    const agents = events.filter(e => e.isSidechain).map(e => [e.uuid, e.agentId])
+    expect(agents).toEqual([["s1", "sidechain-s1"], ["t1", "sidechain-t1"], ["s2", "sidechain-s1"]])
+    const links = events.filter(e => e.type === "progress").map(e => [e.data?.agentId, e.parentToolUseID])
+    expect(links).toEqual([["sidechain-s1", "task-1"], ["sidechain-t1", "task-2"]])
+  })
+
+  it("places upgraded subagents on their own lanes", () => {
+    const graph = buildGraph(upgradeEvents(legacy))
+    const lanes = graph.nodes.filter(n => n.agentId).map(n => n.branchLevel)
+    expect(lanes.every(l => l > 0)).toBe(true)
+  })
+})
@@ -0,0 +1,129 @@
+import { describe, it, expect } from "bun:test"
+import { mkdirSync, writeFileSync, appendFileSync } from "node:fs"
+import { join } from "node:path"
+import { tmpdir } from "node:os"
+import type { ParseIssue } from "../src/core/types"
+import { readAllEvents, readAllEventsAsync, countParseErrors, setStrictSchema, listSessions } from "../src/sources/claude/watcher"
+
+function writeJsonl(path: string, events: object[]) {
+  writeFileSync(path, events.map(e => JSON.stringify(e)).join("\n") + "\n")
+}
+
+function ts(n: number): string {
+  return new Date(1_700_000_000_000 + n).toISOString()
+}
+
+describe("claude readAllEvents", () => {
+  const dir = join(tmpdir(), `vizier-watcher-${Date.now()}-${Math.random()}`)
+  mkdirSync(dir, { recursive: true })
+  const main = join(dir, "main.jsonl")
+  const agent = join(dir, "agent.jsonl")
+  writeJsonl(main, [
+    { uuid: "m2", type: "user", timestamp: ts(2) },
+    { uuid: "m1a", type: "assistant", timestamp: ts(1) },
+    { uuid: "m1b", type: "assistant", timestamp: ts(1) },
+  ])
+  writeJsonl(agent, [
+    { uuid: "g1", type: "assistant", timestamp: ts(1) },
+    { uuid: "g3", type: "assistant", timestamp: ts(3) },
+  ])
+
+  it("merges by timestamp with file and line order as tiebreakers", () => {
+    const ids = readAllEvents(main, [agent]).map(e => e.uuid)
+    expect(ids).toEqual(["m1a", "m1b", "g1", "m2", "g3"])
+  })
+
+  it("produces the same order when reading concurrently", async () => {
+    const ids = (await readAllEventsAsync(main, [agent])).map(e => e.uuid)
+    expect(ids).toEqual(["m1a", "m1b", "g1", "m2", "g3"])
+  })
+})
+
+describe("countParseErrors", () => {
+  it("counts malformed complete lines and stops before a partial tail", () => {
+    const content = '{"a":1}\nnot json\n\n{"b":\n{"c":3}\n{"partial'
+    expect(countParseErrors(content)).toEqual({ offset: content.lastIndexOf("\n") + 1, errors: 2 })
+  })
+
+  it("resumes from an offset", () => {
+    const content = 'bad\n{"ok":true}\nbad again\n'
+    expect(countParseErrors(content, 4).errors).toBe(1)
+  })
+})
+
+describe("claude parse issues", () => {
+  const dir = join(tmpdir(), `vizier-issues-${Date.now()}-${Math.random()}`)
+  mkdirSync(dir, { recursive: true })
+  const file = join(dir, "session.jsonl")
+  writeFileSync(file, [
+    JSON.stringify({ uuid: "a", type: "user", timestamp: ts(1) }),
+    "{broken",
+    JSON.stringify({ uuid: "b", type: "user", timestamp: ts(2) }),
+    "{\"uuid\": \"c\", \"type\"", // still being written
+  ].join("\n"))
+  const schema = join(dir, "schema.jsonl")
+  writeJsonl(schema, [
+    { uuid: "d" },
+    { uuid: "e", type: "user", timestamp: ts(3) },
+    [1, 2],
+    { uuid: "f", type: "system", timestamp: ts(4) },
+  ])
+
+  it("records skipped lines but not a trailing partial line", () => {
+    const issues: ParseIssue[] = []
+    expect(readAllEvents(file, [], issues).map(e => e.uuid)).toEqual(["a", "b"])
+    expect(issues.map(i => [i.file, i.line])).toEqual([[file, 2]])
+  })
+
+  it("collects the same issues when reading asynchronously", async () => {
+    const issues: ParseIssue[] = []
+    await readAllEventsAsync(file, [], issues)
+    expect(issues.map(i => i.line)).toEqual([2])
+  })
+
+  it("skips and records lines that parse but aren't events", () => {
+    const issues: ParseIssue[] = []
+    expect(readAllEvents(schema, [], issues).map(e => e.uuid)).toEqual(["e", "f"])
+    expect(issues.map(i => [i.line, i.message])).toEqual([
+      [1, "no event type"],
+      [3, "not a JSON object"],
+    ])
+  })
+
+  it("also refuses message-less prompts and replies under --strict", () => {
+    const issues: ParseIssue[] = []
+    setStrictSchema(true)
+    try {
+      expect(readAllEvents(schema, [], issues).map(e => e.uuid)).toEqual(["f"])
+    } finally {
+      setStrictSchema(false)
+    }
+    expect(issues.map(i => i.line)).toEqual([1, 2, 3])
+  })
+})
+
+describe("claude listSessions", () => {
+  const claudeDir = join(tmpdir(), `vizier-list-${Date.now()}-${Math.random()}`)
+  const projectDir = join(claudeDir, "projects", "p")
+  mkdirSync(projectDir, { recursive: true })
+  const file = join(projectDir, "s1.jsonl")
+  const line = (uuid: string, n: number) => JSON.stringify({ uuid, type: "user", timestamp: ts(n) }) + "\n"
+  const summary = () => listSessions(claudeDir, "p")[0]
+
+  it("keeps counting as the transcript grows", () => {
+    writeFileSync(file, line("a", 1) + "not json\n")
+    expect(summary()).toMatchObject({ id: "s1", nodeCount: 2, parseErrors: 1, startedAt: 1_700_000_000_001 })
+    appendFileSync(file, line("b", 2) + '{"partial')
+    expect(summary()).toMatchObject({ nodeCount: 4, parseErrors: 1, startedAt: 1_700_000_000_001 })
+  })
+
+  it("starts over when the file is rewritten shorter", () => {
+    writeFileSync(file, line("c", 5))
+    expect(summary()).toMatchObject({ nodeCount: 1, parseErrors: 0, startedAt: 1_700_000_000_005 })
+  })
+
+  it("reads lines longer than its scan window", () => {
+    writeFileSync(file, JSON.stringify({ uuid: "big", type: "user", timestamp: ts(7), pad: "x".repeat(3 * 1024 * 1024) }) + "\n" + line("d", 8))
+    expect(summary()).toMatchObject({ nodeCount: 2, parseErrors: 0, startedAt: 1_700_000_000_007 })
+  })
+})
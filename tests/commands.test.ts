@@ -0,0 +1,43 @@
+import { describe, it, expect } from "bun:test"
+import { parseCommand, landingIndex } from "../src/core/commands"
+import { message } from "./fixtures"
+
+const node = (id: string) => message(id, "user")
+
+describe("parseCommand", () => {
+  it("parses each command, with or without the colon", () => {
+    expect(parseCommand(":goto turn:12")).toEqual({ kind: "goto", target: { kind: "turn", turn: 12 }, spec: "turn:12" })
+    expect(parseCommand("view gantt")).toEqual({ kind: "view", view: "gantt" })
+    expect(parseCommand(":layout files")).toEqual({ kind: "layout", zoom: "files" })
+    expect(parseCommand(":open details")).toEqual({ kind: "open", panel: "details" })
+    expect(parseCommand(":follow")).toEqual({ kind: "follow" })
+  })
+
+  it("parses the filter expression, and clears on an empty one", () => {
+    const cmd = parseCommand(":filter error:true name:Bash")
+    expect(cmd).toMatchObject({ kind: "filter", filter: { text: "error:true name:Bash" } })
+    expect(parseCommand(":filter")).toEqual({ kind: "filter", filter: null })
+  })
+
+  it("reports what's wrong", () => {
+    expect(parseCommand(":filter error:maybe")).toHaveProperty("error")
+    expect(parseCommand(":goto turn:0")).toHaveProperty("error")
+    expect(parseCommand(":view reading")).toHaveProperty("error")
+    expect(parseCommand(":quit")).toEqual({ error: expect.stringContaining(":quit") })
+  })
+})
+
+describe("landingIndex", () => {
+  const raw = ["a", "b", "c", "d"].map(node)
+
+  it("stays on a shown node and moves forward from a hidden one", () => {
+    const shown = [raw[0], raw[2]]
+    expect(landingIndex(raw, shown, 2)).toBe(1)
+    expect(landingIndex(raw, shown, 1)).toBe(1)
+  })
+
+  it("falls back to the last shown node before the target", () => {
+    expect(landingIndex(raw, [raw[0], raw[1]], 3)).toBe(1)
+    expect(landingIndex(raw, [], 3)).toBeNull()
+  })
+})
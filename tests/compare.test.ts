@@ -0,0 +1,54 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { compareTools, toolKey, isConsistentFailure, formatToolComparison, toolComparisonToJson } from "../src/core/compare"
+import { graph, toolCall } from "./fixtures"
+
+function call(name: string, input: object, isError = false, output: string | null = "out"): Node {
+  return toolCall(`${name}-${Math.random()}`, name, { input: JSON.stringify(input), output, isError }, { branchLevel: 1 })
+}
+
+describe("toolKey", () => {
+  it("splits shell calls by the start of their command", () => {
+    expect(toolKey(call("Bash", { command: "bun test tests/a.test.ts" }))).toBe("Bash: bun test")
+    expect(toolKey(call("Bash", {}))).toBe("Bash")
+    expect(toolKey(call("Read", { file_path: "a.ts" }))).toBe("Read")
+  })
+
+  it("skips calls that are still running", () => {
+    expect(toolKey(call("Read", {}, false, null))).toBeNull()
+  })
+})
+
+describe("compareTools", () => {
+  const test = { command: "bun test" }
+  const rows = compareTools([
+    graph([call("Bash", test, true), call("Read", {})]),
+    graph([call("Read", {}, true), call("Read", {})]),
+    graph([call("Bash", test, true), call("Bash", test)]),
+  ])
+
+  it("tracks failures per session, worst first", () => {
+    expect(rows.map(r => [r.key, r.calls, r.errors, r.failingSessions, r.sessions])).toEqual([
+      ["Bash: bun test", 3, 2, 2, 2],
+      ["Read", 3, 1, 1, 2],
+    ])
+    expect(rows[0].history).toEqual(["failed", null, "failed"])
+  })
+
+  it("flags tools that fail in most sessions that use them", () => {
+    expect(rows.map(isConsistentFailure)).toEqual([true, false])
+  })
+
+  it("formats one row per tool", () => {
+    const out = formatToolComparison(rows, 3)
+    expect(out.split("\n")[0]).toBe("Tool failures across the last 3 sessions")
+    expect(out).toMatch(/Bash: bun test\s+3\s+2\s+67%\s+2\/2/)
+    expect(formatToolComparison([], 1)).toBe("No finished tool calls in the last 1 session\n")
+  })
+
+  it("has a versioned JSON form", () => {
+    const json = JSON.parse(toolComparisonToJson(rows, 3))
+    expect(json).toMatchObject({ schemaVersion: 1, kind: "compare-tools", sessions: 3 })
+    expect(json.tools[0]).toMatchObject({ tool: "Bash: bun test", consistent: true, history: ["failed", null, "failed"] })
+  })
+})
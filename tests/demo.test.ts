@@ -0,0 +1,28 @@
+import { describe, it, expect } from "bun:test"
+import { generateDemoEvents } from "../src/sources/demo/generate"
+import { buildGraph } from "../src/sources/claude/graph"
+
+describe("generateDemoEvents", () => {
+  it("is deterministic for a seed", () => {
+    expect(generateDemoEvents({ seed: 7 })).toEqual(generateDemoEvents({ seed: 7 }))
+    expect(generateDemoEvents({ seed: 7 })).not.toEqual(generateDemoEvents({ seed: 8 }))
+  })
+
+  it("builds a graph with turns, parallel tools and subagents", () => {
+    const graph = buildGraph(generateDemoEvents({ seed: 1, turns: 4 }))
+    const kinds = graph.nodes.map(n => n.nodeType.kind)
+    expect(kinds.filter(k => k === "user").length).toBe(4)
+    expect(graph.nodes.some(n => n.agentId && n.branchLevel > 0)).toBe(true)
+    // Every tool call got its result
+    expect(graph.nodes.every(n => n.nodeType.kind !== "tool_call" || n.nodeType.output !== null)).toBe(true)
+    const firstReads = graph.nodes.filter(n => n.nodeType.kind === "tool_call" && n.timestamp === graph.nodes[2].timestamp)
+    expect(firstReads.length).toBeGreaterThanOrEqual(2)
+    expect(graph.stats.totalInputTokens).toBeGreaterThan(0)
+  })
+
+  it("includes failures across seeds", () => {
+    const failed = [1, 2, 3, 4, 5].some(seed =>
+      buildGraph(generateDemoEvents({ seed })).nodes.some(n => n.nodeType.kind === "tool_call" && n.nodeType.isError))
+    expect(failed).toBe(true)
+  })
+})
@@ -0,0 +1,24 @@
+import { describe, it, expect } from "bun:test"
+import { nextFocusDock, isFocusDock, clampFocusLines, clampFocusWidth } from "../src/core/dock"
+
+describe("focus dock", () => {
+  it("cycles and validates", () => {
+    expect(nextFocusDock("bottom")).toBe("right")
+    expect(nextFocusDock("off")).toBe("bottom")
+    expect(isFocusDock("right")).toBe(true)
+    expect(isFocusDock("left")).toBe(false)
+  })
+
+  it("keeps a bottom pane between one line and half the screen", () => {
+    expect(clampFocusLines(0, 40)).toBe(1)
+    expect(clampFocusLines(5, 40)).toBe(5)
+    expect(clampFocusLines(30, 40)).toBe(20)
+  })
+
+  it("leaves room for the timeline beside a right pane", () => {
+    expect(clampFocusWidth(48, 120)).toBe(48)
+    expect(clampFocusWidth(100, 120)).toBe(80)
+    expect(clampFocusWidth(10, 120)).toBe(24)
+    expect(clampFocusWidth(48, 50)).toBe(24)
+  })
+})
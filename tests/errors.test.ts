@@ -0,0 +1,52 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { analyzeErrors, formatErrorReport, errorReportToJson } from "../src/core/errors"
+import { message, toolCall } from "./fixtures"
+
+function call(id: string, name: string, output: string | null, isError: boolean, agentId?: string): Node {
+  return toolCall(id, name, { input: `{"id":"${id}"}`, output, isError }, { agentId, branchLevel: 2 })
+}
+
+function user(id: string): Node {
+  return message(id, "user")
+}
+
+describe("analyzeErrors", () => {
+  const nodes: Node[] = [
+    user("u1"),
+    call("b1", "Bash", "exit 1", true),
+    call("r1", "Read", "ok", false),
+    call("b2", "Bash", "ok", false),
+    call("e1", "Edit", "no match", true),
+    call("e2", "Edit", "no match", true, "agent-1"),
+    user("u2"),
+    call("e3", "Edit", "ok", false),
+  ]
+
+  it("pairs each failure with the next call to the same tool", () => {
+    const errors = analyzeErrors(nodes)
+    expect(errors.map(e => [e.name, e.retry, e.retryIdx])).toEqual([
+      ["Bash", "succeeded", 3],
+      ["Edit", "none", null], // e2 belongs to an agent, e3 to the next turn
+      ["Edit", "none", null],
+    ])
+  })
+
+  it("formats a report", () => {
+    const report = formatErrorReport(nodes, analyzeErrors(nodes), "s1")
+    expect(report).toStartWith("3 failed tool calls in s1")
+    expect(report).toContain("Bash (retry succeeded)")
+    expect(report).toContain("      exit 1")
+  })
+
+  it("reports a clean session", () => {
+    expect(formatErrorReport([], [], "s1")).toBe("No failed tool calls in s1\n")
+  })
+
+  it("has a versioned JSON form", () => {
+    const json = JSON.parse(errorReportToJson(nodes, analyzeErrors(nodes), "s1"))
+    expect(json.schemaVersion).toBe(1)
+    expect(json.kind).toBe("errors")
+    expect(json.errors[0]).toMatchObject({ tool: "Bash", retry: "succeeded", retryIndex: 3, agentId: null })
+  })
+})
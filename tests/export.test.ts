@@ -0,0 +1,82 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { turnToMarkdown, turnToJson, pinsToMarkdown, forkToMarkdown, forkToJson } from "../src/core/export"
+import { node } from "./fixtures"
+
+const nodes: Node[] = [
+  node("u1", { kind: "user", text: "first" }),
+  node("a1", { kind: "assistant", text: "done" }),
+  node("u2", { kind: "user", text: "show ```code```" }),
+  node("t1", { kind: "tool_call", name: "Bash", input: "{\"cmd\":\"echo ```\"}", output: "x".repeat(2500), isError: false }),
+  node("a2", { kind: "assistant", text: "ok" }),
+]
+const meta = { sessionId: "abc", turn: 2 }
+
+describe("turnToMarkdown", () => {
+  const md = turnToMarkdown(nodes, { start: 2, end: 5 }, meta)
+
+  it("includes only the turn's nodes", () => {
+    expect(md).toStartWith("# Turn 2")
+    expect(md).toContain("## User\n\nshow ```code```")
+    expect(md).toContain("### Tool: Bash (ok)")
+    expect(md).toContain("## Assistant\n\nok")
+    expect(md).not.toContain("first")
+  })
+
+  it("uses fences longer than backtick runs in the content", () => {
+    expect(md).toContain("````json\n{\"cmd\":\"echo ```\"}\n````")
+  })
+
+  it("clips long tool output", () => {
+    expect(md).toContain("... (500 more chars)")
+  })
+})
+
+describe("turnToJson", () => {
+  it("serializes the turn's nodes with metadata", () => {
+    const parsed = JSON.parse(turnToJson(nodes, { start: 0, end: 2 }, { sessionId: "abc", turn: 1 }))
+    expect(parsed.turn).toBe(1)
+    expect(parsed.nodes.map((n: Node) => n.id)).toEqual(["u1", "a1"])
+  })
+})
+
+describe("pinsToMarkdown", () => {
+  const failed = node("t2", { kind: "tool_call", name: "Bash", input: "{\"command\":\"bun test\"}", output: "1 fail", isError: true }, { timestamp: 1000 })
+  const all = [...nodes, failed]
+  const md = pinsToMarkdown(all, [failed, all[3]], { sessionId: "abc", exportedAt: 0 })
+
+  it("lists pins in order with their metadata", () => {
+    expect(md).toStartWith("# Pinned nodes\n\nSession: abc")
+    expect(md).toContain("## 1. Tool: Bash (error)\n\n- Time: 1970-01-01 00:00:01\n- Node: t2\n- Turn: 2")
+    expect(md.indexOf("## 2. Tool: Bash (ok)")).toBeGreaterThan(md.indexOf("## 1."))
+  })
+
+  it("includes input and output", () => {
+    expect(md).toContain("Input:\n\n```json\n{\"command\":\"bun test\"}\n```\n\nOutput:\n\n```\n1 fail\n```")
+  })
+})
+
+describe("fork", () => {
+  const withAgent: Node[] = [
+    ...nodes.slice(0, 4),
+    { ...node("g1", { kind: "assistant", text: "agent step" }), agentId: "x" },
+    node("a2", { kind: "assistant", text: "ok" }),
+  ]
+  const forkMeta = { sessionId: "abc", exportedAt: 0 }
+
+  it("keeps the main conversation up to the node", () => {
+    const md = forkToMarkdown(withAgent, 3, forkMeta)
+    expect(md).toContain("session abc up to node t1")
+    expect(md).toContain("first")
+    expect(md).toContain("### Tool: Bash (ok)")
+    expect(md).not.toContain("agent step")
+    expect(md.trimEnd().endsWith("## Next instruction")).toBe(true)
+  })
+
+  it("writes the same messages as JSON", () => {
+    const json = JSON.parse(forkToJson(withAgent, 5, forkMeta))
+    expect(json.kind).toBe("fork")
+    expect(json.nodeId).toBe("a2")
+    expect(json.messages.map((m: { role: string }) => m.role)).toEqual(["user", "assistant", "user", "tool", "assistant"])
+  })
+})
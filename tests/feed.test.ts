@@ -0,0 +1,44 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { feedItems, mergeFeed } from "../src/core/feed"
+import { message, toolCall } from "./fixtures"
+
+function text(id: string, kind: "user" | "assistant", at: number): Node {
+  return message(id, kind, { timestamp: at })
+}
+
+function call(id: string, at: number, end: number | undefined, isError = false): Node {
+  return toolCall(id, "Bash", { input: "ls", output: end === undefined ? null : "out", isError }, { timestamp: at, endTimestamp: end, branchLevel: 2 })
+}
+
+describe("feedItems", () => {
+  const nodes: Node[] = [
+    text("old", "user", 5),
+    text("u1", "user", 10),
+    call("t1", 11, 40, true),
+    call("t2", 12, undefined),
+    { id: "p1", nodeType: { kind: "progress", text: "…" }, timestamp: 13, branchLevel: 0 },
+    text("a1", "assistant", 50),
+  ]
+
+  it("keeps prompts, replies and finished calls inside the window", () => {
+    const items = feedItems("api", "s1", nodes, 10)
+    expect(items.map(i => i.nodeId)).toEqual(["u1", "t1", "a1"])
+    expect(items.every(i => i.project === "api" && i.sessionId === "s1")).toBe(true)
+  })
+
+  it("times a tool call by its result and flags failures", () => {
+    const t1 = feedItems("api", "s1", nodes, 0).find(i => i.nodeId === "t1")!
+    expect(t1.timestamp).toBe(40)
+    expect(t1.error).toBe(true)
+    expect(t1.text).toContain("Bash")
+  })
+})
+
+describe("mergeFeed", () => {
+  it("interleaves projects newest first up to the limit", () => {
+    const api = feedItems("api", "s1", [text("u1", "user", 10), text("a1", "assistant", 30)], 0)
+    const web = feedItems("web", "s2", [text("u2", "user", 20), text("a2", "assistant", 40)], 0)
+    expect(mergeFeed([api, web], 3).map(i => i.nodeId)).toEqual(["a2", "a1", "u2"])
+  })
+})
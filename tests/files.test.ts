@@ -0,0 +1,45 @@
+import { describe, it, expect } from "bun:test"
+import type { Graph, Node } from "../src/core/types"
+import { touchedFile, rankModifiedFiles, applyFileLanes, OTHER_FILES } from "../src/core/files"
+import { emptyStats } from "../src/core/stats"
+import { toolCall } from "./fixtures"
+
+function call(id: string, name: string, file: string): Node {
+  return toolCall(id, name, { input: JSON.stringify({ file_path: file }) })
+}
+
+const prompt: Node = { id: "u", nodeType: { kind: "user", text: "refactor" }, timestamp: 0, branchLevel: 0 }
+const reply: Node = { id: "a", nodeType: { kind: "assistant", text: "ok" }, timestamp: 0, branchLevel: 0 }
+const nodes = [
+  prompt,
+  call("r1", "Read", "src/a.ts"),
+  call("e1", "Edit", "src/a.ts"),
+  call("e2", "Edit", "src/b.ts"),
+  call("e3", "Edit", "src/b.ts"),
+  reply,
+  call("r2", "Read", "README.md"),
+]
+
+describe("file swimlanes", () => {
+  it("reads the file a tool call touched", () => {
+    expect(touchedFile(nodes[1])).toBe("src/a.ts")
+    expect(touchedFile(reply)).toBeNull()
+  })
+
+  it("ranks files by modifications", () => {
+    expect(rankModifiedFiles(nodes)).toEqual(["src/b.ts", "src/a.ts"])
+  })
+
+  it("assigns a row per file, an other row, and keeps prompts on row 0", () => {
+    const graph: Graph = { nodes, edges: [], stats: emptyStats() }
+    const laid = applyFileLanes(graph)
+    expect(laid.fileLanes).toEqual(["src/b.ts", "src/a.ts", OTHER_FILES])
+    expect(laid.nodes.map(n => n.fileLane)).toEqual([0, 2, 2, 1, 1, -1, 3])
+  })
+
+  it("folds files past the limit into the other row", () => {
+    const laid = applyFileLanes({ nodes, edges: [], stats: emptyStats() }, 1)
+    expect(laid.fileLanes).toEqual(["src/b.ts", OTHER_FILES])
+    expect(laid.nodes[2].fileLane).toBe(2)
+  })
+})
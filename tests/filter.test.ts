@@ -0,0 +1,143 @@
+import { describe, it, expect } from "bun:test"
+import type { Graph, Node } from "../src/core/types"
+import { parseFilter, matchesFilter, filterContext, quickFilters, nextQuickFilter } from "../src/core/filter"
+import type { NodeFilter } from "../src/core/filter"
+import { applyVisibility, SHOW_ALL } from "../src/core/zoom"
+import { toolCall } from "./fixtures"
+
+function at(hours: number, minutes: number): number {
+  return new Date(2025, 0, 1, hours, minutes).getTime()
+}
+
+function call(id: string, name: string, isError: boolean, ts: number, agentId?: string, input = "{}"): Node {
+  return toolCall(id, name, { input, output: "done", isError }, { timestamp: ts, branchLevel: agentId ? 1 : 0, agentId })
+}
+
+const nodes: Node[] = [
+  { id: "u", nodeType: { kind: "user", text: "fix the build" }, timestamp: at(13, 50), branchLevel: 0 },
+  call("bash-ok", "Bash", false, at(13, 55)),
+  call("task", "Task", false, at(14, 0), undefined, JSON.stringify({ subagent_type: "researcher" })),
+  { ...call("agent-bash", "Bash", true, at(14, 5), "a1"), parentId: "task" },
+  call("bash-fail", "Bash", true, at(14, 10)),
+  call("mcp", "mcp__github__create_issue", false, at(14, 20)),
+]
+
+function parsed(text: string): NodeFilter {
+  const f = parseFilter(text)
+  if ("error" in f) throw new Error(f.error)
+  return f
+}
+
+function ids(text: string): string[] {
+  const f = parsed(text)
+  const ctx = filterContext(nodes)
+  return nodes.filter(n => matchesFilter(n, f, ctx)).map(n => n.id)
+}
+
+describe("parseFilter", () => {
+  it("reports unknown keys and bad values", () => {
+    expect(parseFilter("colour:red")).toHaveProperty("error")
+    expect(parseFilter("type:widget")).toHaveProperty("error")
+    expect(parseFilter("error:maybe")).toHaveProperty("error")
+    expect(parseFilter("after:25:00")).toHaveProperty("error")
+    expect(parseFilter("name:")).toHaveProperty("error")
+  })
+
+  it("keeps the expression for the header", () => {
+    expect(parsed("  type:tool  error:true ").text).toBe("type:tool  error:true")
+  })
+})
+
+describe("matchesFilter", () => {
+  it("ANDs terms together", () => {
+    expect(ids("type:tool name:Bash error:true")).toEqual(["agent-bash", "bash-fail"])
+  })
+
+  it("matches tool names with wildcards, ignoring case", () => {
+    expect(ids("name:mcp__github__*")).toEqual(["mcp"])
+    expect(ids("name:bash")).toEqual(["bash-ok", "agent-bash", "bash-fail"])
+  })
+
+  it("bounds by local time of day", () => {
+    expect(ids("after:14:00 before:14:10")).toEqual(["task", "agent-bash"])
+  })
+
+  it("finds subagents by the type their Task call asked for", () => {
+    expect(ids("agent:research")).toEqual(["agent-bash"])
+    expect(ids("agent:main type:tool")).toEqual(["bash-ok", "task", "bash-fail", "mcp"])
+  })
+
+  it("negates terms and matches bare words against text", () => {
+    expect(ids("-type:tool")).toEqual(["u"])
+    expect(ids("build")).toEqual(["u"])
+  })
+
+  it("keeps only build and lint commands", () => {
+    const shell = [
+      call("clippy", "Bash", false, 0, undefined, JSON.stringify({ command: "cargo clippy -- -D warnings" })),
+      call("ls", "Bash", false, 0, undefined, JSON.stringify({ command: "ls" })),
+    ]
+    const f = parsed("build:true")
+    expect(shell.filter(n => matchesFilter(n, f, filterContext(shell))).map(n => n.id)).toEqual(["clippy"])
+  })
+})
+
+describe("quoted terms and file:", () => {
+  const edits = [
+    call("edit", "Edit", false, 0, undefined, JSON.stringify({ file_path: "/repo/src/app.tsx" })),
+    call("read", "Read", true, 0, undefined, JSON.stringify({ file_path: "/repo/my notes.md" })),
+  ]
+  const match = (text: string) => edits.filter(n => matchesFilter(n, parsed(text), filterContext(edits))).map(n => n.id)
+
+  it("matches a file by full path or its end", () => {
+    expect(match("file:/repo/src/app.tsx")).toEqual(["edit"])
+    expect(match("file:src/app.tsx")).toEqual(["edit"])
+    expect(match("file:app")).toEqual([])
+  })
+
+  it("keeps quoted phrases and values together", () => {
+    expect(match('file:"my notes.md"')).toEqual(["read"])
+    expect(match('"my notes"')).toEqual(["read"])
+    expect(parsed('error:true "not found"').terms).toHaveLength(2)
+  })
+})
+
+describe("quickFilters", () => {
+  const failed: Node = {
+    id: "f",
+    nodeType: { kind: "tool_call", name: "Read", input: JSON.stringify({ file_path: "/repo/a.ts" }), output: "\nFile \"a.ts\" not found\nmore", isError: true },
+    timestamp: 0,
+    branchLevel: 1,
+    agentId: "a1",
+  }
+
+  it("offers tool, file, agent and error in order", () => {
+    expect(quickFilters(failed)).toEqual(["name:Read", "file:/repo/a.ts", "agent:a1", 'error:true "File \\"a.ts\\" not found"'])
+    expect(quickFilters(nodes[0])).toEqual(["agent:main"])
+  })
+
+  it("every option parses and matches its node", () => {
+    for (const text of quickFilters(failed)) expect(matchesFilter(failed, parsed(text), filterContext([failed]))).toBe(true)
+  })
+
+  it("escapes quotes in paths", () => {
+    const odd: Node = { ...failed, nodeType: { kind: "tool_call", name: "Read", input: JSON.stringify({ file_path: '/repo/say "hi".md' }), output: "ok", isError: false } }
+    expect(quickFilters(odd)[1]).toBe('file:"/repo/say \\"hi\\".md"')
+    expect(matchesFilter(odd, parsed(quickFilters(odd)[1]), filterContext([odd]))).toBe(true)
+  })
+
+  it("cycles from the active filter, then clears", () => {
+    expect(nextQuickFilter(failed)).toBe("name:Read")
+    expect(nextQuickFilter(failed, "type:tool")).toBe("name:Read")
+    expect(nextQuickFilter(failed, "name:Read")).toBe("file:/repo/a.ts")
+    expect(nextQuickFilter(failed, 'error:true "File \\"a.ts\\" not found"')).toBeNull()
+  })
+})
+
+describe("applyVisibility with a filter", () => {
+  it("drops nodes that don't match", () => {
+    const graph = { nodes } as Graph
+    expect(applyVisibility(graph, { ...SHOW_ALL, filter: parsed("error:true") }).nodes.map(n => n.id)).toEqual(["agent-bash", "bash-fail"])
+    expect(applyVisibility(graph, SHOW_ALL)).toBe(graph)
+  })
+})
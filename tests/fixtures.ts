@@ -0,0 +1,38 @@
+import type { Graph, Node } from "../src/core/types"
+import { emptyStats } from "../src/core/stats"
+
+// Node and graph factories shared by the tests. Each fills in what a test
+// doesn't care about: timestamp 0, the main branch, a finished call. `extra`
+// sets anything else on the node.
+
+export function graph(nodes: Node[]): Graph {
+  return { nodes, edges: [], stats: emptyStats() }
+}
+
+export function node(id: string, nodeType: Node["nodeType"], extra: Partial<Node> = {}): Node {
+  return { id, nodeType, timestamp: 0, branchLevel: 0, ...extra }
+}
+
+// A prompt or reply whose text is its id
+export function message(id: string, kind: "user" | "assistant", extra: Partial<Node> = {}): Node {
+  return node(id, { kind, text: id }, extra)
+}
+
+export type CallFields = { input?: string; output?: string | null; isError?: boolean }
+
+// output null leaves the call running
+export function toolCall(id: string, name: string, fields: CallFields = {}, extra: Partial<Node> = {}): Node {
+  const { input = "{}", output = "ok", isError = false } = fields
+  return node(id, { kind: "tool_call", name, input, output, isError }, extra)
+}
+
+export function bash(id: string, command: string, fields: Omit<CallFields, "input"> = {}, extra: Partial<Node> = {}): Node {
+  return toolCall(id, "Bash", { ...fields, input: JSON.stringify({ command }) }, extra)
+}
+
+// A session over time, for state and lifecycle tests: a prompt, Bash calls
+// and replies, identified by when they happened
+export const user = (timestamp: number): Node => message(`u${timestamp}`, "user", { timestamp })
+export const reply = (timestamp: number, extra: Partial<Node> = {}): Node => message(`a${timestamp}`, "assistant", { timestamp, ...extra })
+export const callAt = (timestamp: number, output: string | null, isError = false): Node =>
+  toolCall(`t${timestamp}`, "Bash", { output, isError }, { timestamp, branchLevel: 1 })
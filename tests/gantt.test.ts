@@ -0,0 +1,53 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { buildGanttBars, barForNode, barColumns } from "../src/core/gantt"
+import { message, toolCall } from "./fixtures"
+
+function call(id: string, name: string, start: number, end: number | null, extra: Partial<Node> = {}): Node {
+  const input = JSON.stringify({ description: "explore" })
+  return toolCall(id, name, { input, output: end === null ? null : "ok" }, { timestamp: start, endTimestamp: end ?? undefined, ...extra })
+}
+
+function step(id: string, at: number, agentId: string, parentId?: string): Node {
+  return message(id, "assistant", { parentId, agentId, timestamp: at, branchLevel: 1 })
+}
+
+const nodes: Node[] = [
+  { id: "u", nodeType: { kind: "user", text: "go" }, timestamp: 0, branchLevel: 0 },
+  call("task", "Task", 10, 90),
+  step("g1", 12, "agent-1", "task"),
+  call("read", "Read", 20, 30, { agentId: "agent-1", branchLevel: 1 }),
+  step("g2", 80, "agent-1"),
+  call("bash", "Bash", 15, null),
+]
+
+describe("buildGanttBars", () => {
+  const bars = buildGanttBars(nodes, 100)
+
+  it("orders tool and agent bars by start", () => {
+    expect(bars.map(b => b.label)).toEqual(["Task", "agent: explore", "Bash", "Read"])
+  })
+
+  it("spans each bar from start to end", () => {
+    expect(bars.find(b => b.label === "Read")).toMatchObject({ start: 20, end: 30, depth: 1 })
+    expect(bars.find(b => b.kind === "agent")).toMatchObject({ start: 12, end: 80, running: false })
+  })
+
+  it("runs unfinished calls to now", () => {
+    expect(bars.find(b => b.label === "Bash")).toMatchObject({ end: 100, running: true })
+  })
+
+  it("keeps the cursor's bar, or the latest earlier one, in view", () => {
+    expect(bars[barForNode(bars, 3)].label).toBe("Read")
+    expect(bars[barForNode(bars, 4)].label).toBe("Read")
+    expect(barForNode(bars, 0)).toBe(-1)
+  })
+})
+
+describe("barColumns", () => {
+  it("scales bars onto the axis with at least one column", () => {
+    const bar = { nodeIdx: 0, kind: "tool" as const, label: "x", start: 50, end: 50, running: false, isError: false, depth: 0 }
+    expect(barColumns(bar, 0, 100, 10)).toEqual({ start: 5, length: 1 })
+    expect(barColumns({ ...bar, start: 0, end: 100 }, 0, 100, 10)).toEqual({ start: 0, length: 10 })
+  })
+})
@@ -0,0 +1,23 @@
+import { describe, it, expect } from "bun:test"
+import { ASCII_GLYPHS, UNICODE_GLYPHS, glyphs, setAsciiMode, isAsciiMode } from "../src/ui/glyphs"
+
+describe("glyph sets", () => {
+  it("ascii set contains only printable ASCII", () => {
+    for (const value of Object.values(ASCII_GLYPHS)) {
+      if (typeof value !== "string") continue
+      expect(/^[\x20-\x7e]+$/.test(value)).toBe(true)
+    }
+  })
+
+  it("defines the same keys in both sets", () => {
+    expect(Object.keys(ASCII_GLYPHS).sort()).toEqual(Object.keys(UNICODE_GLYPHS).sort())
+  })
+
+  it("switches the active set", () => {
+    setAsciiMode(true)
+    expect(isAsciiMode()).toBe(true)
+    expect(glyphs().vLine).toBe("|")
+    setAsciiMode(false)
+    expect(glyphs()).toBe(UNICODE_GLYPHS)
+  })
+})
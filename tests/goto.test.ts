@@ -0,0 +1,46 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { parseGotoTarget, resolveGotoTarget } from "../src/core/goto"
+
+function at(h: number, m: number): number {
+  return new Date(2024, 0, 1, h, m).getTime()
+}
+
+const nodes: Node[] = [
+  { id: "u-aaaa", nodeType: { kind: "user", text: "first" }, timestamp: at(9, 0), branchLevel: 0 },
+  { id: "a-bbbb", nodeType: { kind: "assistant", text: "ok" }, timestamp: at(9, 5), branchLevel: 0 },
+  { id: "u-cccc", nodeType: { kind: "user", text: "second" }, timestamp: at(10, 30), branchLevel: 0 },
+]
+
+describe("parseGotoTarget", () => {
+  it("parses ids, turns and times", () => {
+    expect(parseGotoTarget("abc-123")).toEqual({ kind: "id", id: "abc-123" })
+    expect(parseGotoTarget("turn:2")).toEqual({ kind: "turn", turn: 2 })
+    expect(parseGotoTarget("time:14:05")).toEqual({ kind: "time", minutes: 14 * 60 + 5 })
+  })
+
+  it("rejects malformed turn/time specs", () => {
+    expect(parseGotoTarget("turn:0")).toBeNull()
+    expect(parseGotoTarget("time:25:00")).toBeNull()
+    expect(parseGotoTarget("time:noon")).toBeNull()
+    expect(parseGotoTarget("")).toBeNull()
+  })
+})
+
+describe("resolveGotoTarget", () => {
+  it("resolves exact ids and unique prefixes", () => {
+    expect(resolveGotoTarget(nodes, { kind: "id", id: "a-bbbb" })).toBe(1)
+    expect(resolveGotoTarget(nodes, { kind: "id", id: "u-c" })).toBe(2)
+    expect(resolveGotoTarget(nodes, { kind: "id", id: "zzz" })).toBeNull()
+  })
+
+  it("counts user turns from 1", () => {
+    expect(resolveGotoTarget(nodes, { kind: "turn", turn: 2 })).toBe(2)
+    expect(resolveGotoTarget(nodes, { kind: "turn", turn: 3 })).toBeNull()
+  })
+
+  it("finds the first node at or after a time", () => {
+    expect(resolveGotoTarget(nodes, { kind: "time", minutes: 9 * 60 + 1 })).toBe(1)
+    expect(resolveGotoTarget(nodes, { kind: "time", minutes: 23 * 60 })).toBeNull()
+  })
+})
@@ -0,0 +1,30 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { toolGroupOf, toolLabel } from "../src/core/groups"
+import { computeTurnStats } from "../src/core/turns"
+import { toolCall } from "./fixtures"
+
+const groups = {
+  search: ["Read", "Grep", "Glob"],
+  write: ["Edit", "Write", "MultiEdit"],
+  github: ["mcp__github__*"],
+}
+
+function call(name: string): Node {
+  return toolCall(name, name, { input: "" })
+}
+
+describe("tool groups", () => {
+  it("matches names case-insensitively and by prefix", () => {
+    expect(toolGroupOf("grep", groups)).toBe("search")
+    expect(toolGroupOf("mcp__github__create_issue", groups)).toBe("github")
+    expect(toolGroupOf("Bash", groups)).toBeNull()
+    expect(toolLabel("Bash", groups)).toBe("Bash")
+  })
+
+  it("aggregates turn stats by group", () => {
+    const nodes = [call("Read"), call("Grep"), call("Edit"), call("Bash")]
+    const stats = computeTurnStats(nodes, { start: 0, end: nodes.length }, groups)
+    expect(stats.tools).toEqual({ search: 2, write: 1, Bash: 1 })
+  })
+})
@@ -0,0 +1,24 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { tokenHeat } from "../src/core/heat"
+import { message } from "./fixtures"
+
+function reply(id: string, output: number): Node {
+  return message(id, "assistant", { usage: { output_tokens: output } })
+}
+
+describe("tokenHeat", () => {
+  it("buckets replies by output token quantiles", () => {
+    const nodes = Array.from({ length: 10 }, (_, i) => reply(`a${i}`, i + 1))
+    expect(tokenHeat(nodes)).toEqual([0, 0, 0, 0, 0, 1, 1, 2, 2, 3])
+  })
+
+  it("skips nodes without output tokens", () => {
+    const user: Node = { id: "u", nodeType: { kind: "user", text: "hi" }, timestamp: 0, branchLevel: 0 }
+    expect(tokenHeat([user, reply("a", 10)])).toEqual([null, 0])
+  })
+
+  it("keeps a flat distribution cold", () => {
+    expect(tokenHeat([reply("a", 5), reply("b", 5), reply("c", 5)])).toEqual([0, 0, 0])
+  })
+})
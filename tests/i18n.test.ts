@@ -0,0 +1,76 @@
+import { describe, it, expect, afterEach } from "bun:test"
+import { tr, setLocale, parseLocale, catalogFor } from "../src/ui/i18n"
+import { KEYMAP, KEY_SECTIONS, helpLine, cheatsheetMarkdown } from "../src/core/keymap"
+import { LIFECYCLE_LABELS } from "../src/core/lifecycle"
+import { TUTORIAL_STEPS } from "../src/core/tutorial"
+import { pollingNotice } from "../src/sources/watch"
+
+afterEach(() => setLocale("en"))
+
+describe("parseLocale", () => {
+  it("accepts known languages and their regional forms", () => {
+    expect(parseLocale("es")).toBe("es")
+    expect(parseLocale("es-MX")).toBe("es")
+    expect(parseLocale("EN_us")).toBe("en")
+    expect(parseLocale("fr")).toBeNull()
+    expect(parseLocale(undefined)).toBeNull()
+  })
+})
+
+describe("tr", () => {
+  it("fills placeholders in English", () => {
+    expect(tr("exported {path}", { path: "/tmp/x.md" })).toBe("exported /tmp/x.md")
+    expect(tr("{count} nodes")).toBe("{count} nodes")
+  })
+
+  it("translates and falls back to English", () => {
+    setLocale("es")
+    expect(tr("session {n}/{total}: {label}", { n: 2, total: 5, label: "abc" })).toBe("sesión 2/5: abc")
+    expect(tr("not in any catalog")).toBe("not in any catalog")
+  })
+})
+
+describe("es catalog", () => {
+  const es = catalogFor("es")
+
+  it("covers every binding, section and state label", () => {
+    const strings = [
+      ...KEYMAP.flatMap(b => b.hint ? [b.action, b.hint] : [b.action]),
+      ...KEY_SECTIONS,
+      ...Object.values(LIFECYCLE_LABELS),
+    ]
+    expect(strings.filter(s => es[s] === undefined)).toEqual([])
+  })
+
+  it("covers the panels, the tutorial and the polling notice", () => {
+    const strings = [
+      "No node selected",
+      "No messages yet",
+      "Reading {percent}%  j/k:scroll ctrl+d/u:page g/G:top/end R:exit",
+      "Nothing pinned: press a on a node to add it here",
+      "Pinned {count}/{max}",
+      "Gantt",
+      "Keys",
+      "Tutorial {step}/{total}: {title}",
+      "Tutorial complete",
+      ...TUTORIAL_STEPS.flatMap(s => [s.title, s.prompt]),
+      "file watching unavailable ({reason}): polling every {seconds}s",
+      "inotify watch limit reached",
+    ]
+    expect(strings.filter(s => es[s] === undefined)).toEqual([])
+    setLocale("es")
+    expect(pollingNotice(Object.assign(new Error("full"), { code: "ENOSPC" })))
+      .toBe("vigilancia de archivos no disponible (se alcanzó el límite de vigilancias de inotify): sondeo cada 1s")
+  })
+
+  it("keeps each translation's placeholders", () => {
+    const names = (s: string) => (s.match(/\{\w+\}/g) ?? []).sort()
+    for (const [en, translated] of Object.entries(es)) expect(names(translated)).toEqual(names(en))
+  })
+
+  it("translates the help line and cheatsheet", () => {
+    setLocale("es")
+    expect(helpLine(KEYMAP).endsWith("f:seguir ?:teclas q:salir")).toBe(true)
+    expect(cheatsheetMarkdown(KEYMAP)).toContain("## Navegación")
+  })
+})
@@ -0,0 +1,46 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { findIssueLinks, issueLabel, issueComment } from "../src/core/issues"
+import { emptyStats } from "../src/core/stats"
+
+describe("issue links", () => {
+  it("labels GitHub and Jira issues", () => {
+    expect(issueLabel("https://github.com/acme/shop/issues/42")).toBe("acme/shop#42")
+    expect(issueLabel("https://github.com/acme/shop/pull/7")).toBe("acme/shop#7")
+    expect(issueLabel("https://acme.atlassian.net/browse/SHOP-12")).toBe("SHOP-12")
+    expect(issueLabel("https://example.com/x")).toBe("https://example.com/x")
+  })
+
+  it("finds URLs in a note, without trailing punctuation or repeats", () => {
+    const links = findIssueLinks("fixes https://github.com/acme/shop/issues/42. see https://github.com/acme/shop/issues/42")
+    expect(links).toEqual([{ url: "https://github.com/acme/shop/issues/42", label: "acme/shop#42" }])
+  })
+})
+
+describe("issueComment", () => {
+  const nodes: Node[] = [
+    { id: "u", nodeType: { kind: "user", text: "fix the login bug" }, timestamp: 0, branchLevel: 0 },
+    { id: "e", nodeType: { kind: "tool_call", name: "Edit", input: JSON.stringify({ file_path: "src/login.ts" }), output: "ok", isError: false }, timestamp: 1000, branchLevel: 0 },
+    { id: "t", nodeType: { kind: "tool_call", name: "Bash", input: JSON.stringify({ command: "bun test" }), output: "1 fail", isError: true }, timestamp: 2000, branchLevel: 0 },
+    { id: "a", nodeType: { kind: "assistant", text: "Fixed the redirect.\nDetails follow." }, timestamp: 60_000, branchLevel: 0 },
+  ]
+
+  it("summarizes the session with its key steps and links", () => {
+    const text = issueComment(nodes, emptyStats(), { sessionId: "claude:abcdef123456", note: "login redirect https://github.com/acme/shop/issues/42" })
+    expect(text).toContain("**Agent session `abcdef12`** over 1m")
+    expect(text).toContain("login redirect\n")
+    expect(text).toContain("tool calls: 2 (1 failed)")
+    expect(text).toContain("Files changed: `src/login.ts`")
+    expect(text).toContain("User: fix the login bug")
+    expect(text).toContain("Tool Bash, error")
+    expect(text).toContain("Assistant: Fixed the redirect.")
+    expect(text).not.toContain("Tool Edit")
+    expect(text.trimEnd().endsWith("- [acme/shop#42](https://github.com/acme/shop/issues/42)")).toBe(true)
+  })
+
+  it("lists pinned nodes and leaves out empty sections", () => {
+    const text = issueComment(nodes, emptyStats(), { sessionId: "s", pinned: [nodes[1]] })
+    expect(text).toContain("Tool Edit, ok")
+    expect(text).not.toContain("**Links**")
+  })
+})
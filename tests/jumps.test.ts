@@ -0,0 +1,37 @@
+import { describe, it, expect } from "bun:test"
+import { recordJump, jumpBack, jumpForward, EMPTY_JUMPS, MAX_JUMPS } from "../src/core/jumps"
+
+describe("jump list", () => {
+  it("goes back through recorded positions and forward again", () => {
+    let list = recordJump(recordJump(EMPTY_JUMPS, "a"), "b")
+    const back1 = jumpBack(list, "c")!
+    expect(back1.target).toBe("b")
+    const back2 = jumpBack(back1.list, "b")!
+    expect(back2.target).toBe("a")
+    expect(jumpBack(back2.list, "a")).toBeNull()
+    const fwd1 = jumpForward(back2.list)!
+    expect(fwd1.target).toBe("b")
+    list = jumpForward(fwd1.list)!.list
+    expect(list.entries[list.pos]).toBe("c")
+    expect(jumpForward(list)).toBeNull()
+  })
+
+  it("drops forward history on a new jump", () => {
+    const back = jumpBack(recordJump(recordJump(EMPTY_JUMPS, "a"), "b"), "c")!
+    const list = recordJump(back.list, "b")
+    expect(list.entries).toEqual(["a", "b"])
+    expect(jumpForward(list)).toBeNull()
+  })
+
+  it("skips repeats and keeps the newest entries", () => {
+    expect(recordJump(recordJump(EMPTY_JUMPS, "a"), "a").entries).toEqual(["a"])
+    let list = EMPTY_JUMPS
+    for (let i = 0; i < MAX_JUMPS + 5; i++) list = recordJump(list, String(i))
+    expect(list.entries.length).toBe(MAX_JUMPS)
+    expect(list.entries[0]).toBe("5")
+  })
+
+  it("has nothing to go back to when empty", () => {
+    expect(jumpBack(EMPTY_JUMPS, "a")).toBeNull()
+  })
+})
@@ -0,0 +1,96 @@
+import { describe, it, expect } from "bun:test"
+import { KEYMAP, parseKeyRemaps, remapKey, activeBindings, helpLine, cheatsheetLines, cheatsheetMarkdown } from "../src/core/keymap"
+
+describe("helpLine", () => {
+  it("lists hinted bindings in keymap order", () => {
+    const line = helpLine(KEYMAP)
+    expect(line.startsWith("h/l:chrono ]t/[t:same tool p/c:parent/child")).toBe(true)
+    expect(line.endsWith("f:follow ?:keys q:quit")).toBe(true)
+    expect(line).not.toContain("g/G")
+  })
+})
+
+describe("parseKeyRemaps", () => {
+  it("keeps single keys mapped onto bound keys", () => {
+    const { remaps, errors } = parseKeyRemaps({ Q: "q", "^x": "q", W: "^w", N: "=" })
+    expect(remaps).toEqual({ Q: "q" })
+    expect(errors).toHaveLength(3)
+  })
+
+  it("accepts space by name", () => {
+    expect(parseKeyRemaps({ S: "space" }).remaps).toEqual({ S: " " })
+  })
+})
+
+describe("activeBindings", () => {
+  const remaps = { n: "l", j: "k" }
+
+  it("moves remapped keys to the binding they now trigger", () => {
+    expect(remapKey("n", remaps)).toBe("l")
+    expect(remapKey("l", remaps)).toBe("l")
+    const bindings = activeBindings(remaps)
+    expect(bindings.find(b => b.hint === "chrono")?.keys).toEqual(["h", "l", "n"])
+    expect(bindings.find(b => b.hint === "row")?.keys).toEqual(["k", "j"])
+    expect(helpLine(bindings)).toContain("k/j:row")
+  })
+})
+
+describe("cheatsheet", () => {
+  it("groups bindings by section", () => {
+    const lines = cheatsheetLines(KEYMAP)
+    expect(lines[0]).toBe("Navigation")
+    expect(lines[1]).toMatch(/^ {2}h\/l\s+Previous \/ next node in time/)
+    expect(lines).toContain("General")
+  })
+
+  it("escapes pipes in markdown tables", () => {
+    const md = cheatsheetMarkdown(KEYMAP)
+    expect(md).toContain("## Session list")
+    expect(md).toContain("| `\\|` | Close the split view |")
+  })
+})
+
+// Bindings outside the picker all act on the timeline at once
+const timelineKeys = () => KEYMAP.filter(b => b.section !== "Session list").flatMap(b => b.keys)
+
+describe("session switching keys", () => {
+  it("binds tab without taking a key from another timeline binding", () => {
+    expect(KEYMAP.find(b => b.keys.includes("tab"))?.section).toBe("Sessions")
+    expect(timelineKeys().filter(k => k === "tab")).toHaveLength(1)
+    expect(helpLine(KEYMAP)).toContain("tab:next session")
+  })
+
+  it("can't have a letter remapped onto tab", () => {
+    expect(parseKeyRemaps({ T: "tab" }).errors).toHaveLength(1)
+  })
+})
+
+describe("split view keys", () => {
+  it("uses | to open from the picker and to close from the timeline", () => {
+    const pipes = KEYMAP.filter(b => b.keys.includes("|"))
+    expect(pipes.map(b => b.section).sort()).toEqual(["Session list", "Sessions"])
+    expect(timelineKeys().filter(k => k === "|" || k === "^w")).toEqual(["|", "^w"])
+  })
+
+  it("moves | with a remap in both places", () => {
+    const bindings = activeBindings({ "\\": "|" })
+    expect(bindings.filter(b => b.keys.includes("\\")).map(b => b.section).sort()).toEqual(["Session list", "Sessions"])
+  })
+})
+
+describe("pause key", () => {
+  it("binds space in the live section", () => {
+    expect(KEYMAP.find(b => b.keys.includes("space"))).toMatchObject({ section: "Live", hint: "pause" })
+    expect(timelineKeys().filter(k => k === "space")).toHaveLength(1)
+  })
+
+  it("can be moved to p, which then stops meaning parent", () => {
+    const { remaps, errors } = parseKeyRemaps({ p: "space" })
+    expect(errors).toEqual([])
+    expect(remapKey("p", remaps)).toBe(" ")
+    const bindings = activeBindings(remaps)
+    expect(bindings.find(b => b.hint === "pause")?.keys).toEqual(["space", "p"])
+    expect(bindings.find(b => b.hint === "parent/child")?.keys).toEqual(["c"])
+    expect(helpLine(bindings)).toContain("space/p:pause")
+  })
+})
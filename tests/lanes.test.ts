@@ -0,0 +1,35 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { LANES, nodeLane, toolLane } from "../src/core/lanes"
+import { getVisualBranch } from "../src/core/zoom"
+import { toolCall } from "./fixtures"
+
+function call(name: string, branchLevel = 0): Node {
+  return toolCall(name, name, { input: "" }, { branchLevel })
+}
+
+describe("tool lanes", () => {
+  it("places tools by kind of work", () => {
+    expect(toolLane("Grep")).toBe("Search")
+    expect(toolLane("MultiEdit")).toBe("Write")
+    expect(toolLane("bash")).toBe("Exec")
+    expect(toolLane("TodoWrite")).toBe("Other")
+  })
+
+  it("lets configured groups pick the lane", () => {
+    expect(toolLane("mcp__db__query", { exec: ["mcp__db__*"] })).toBe("Exec")
+    expect(toolLane("Read", { exec: ["Read"] })).toBe("Exec")
+    expect(toolLane("Read", { files: ["Read"] })).toBe("Search")
+  })
+
+  it("gathers subagents and Task calls in the agents lane", () => {
+    expect(nodeLane(call("Read", 1))).toBe("Agents")
+    expect(nodeLane(call("Task"))).toBe("Agents")
+  })
+
+  it("maps lanes to timeline rows at the lanes zoom", () => {
+    const user: Node = { id: "u", nodeType: { kind: "user", text: "hi" }, timestamp: 0, branchLevel: 0 }
+    expect(getVisualBranch(user, "lanes")).toBe(0)
+    expect(getVisualBranch(call("Edit"), "lanes")).toBe(LANES.indexOf("Write"))
+  })
+})
@@ -0,0 +1,53 @@
+import { describe, it, expect } from "bun:test"
+import type { Graph, Node } from "../src/core/types"
+import { layoutNeeds, formatLayoutNeeds, columnsPerScreen, COL_W_SYMBOL } from "../src/core/layout"
+import { emptyStats } from "../src/core/stats"
+
+function node(id: string, kind: "user" | "assistant" | "tool_call", branchLevel = 0, name = "Bash"): Node {
+  const nodeType = kind === "tool_call"
+    ? { kind, name, input: JSON.stringify(name === "Edit" ? { file_path: "a.ts" } : { command: "ls" }), output: "ok", isError: false }
+    : { kind, text: id }
+  return { id, nodeType, timestamp: 0, branchLevel, agentId: branchLevel > 0 ? "a1" : undefined } as Node
+}
+
+const graph: Graph = {
+  nodes: [
+    node("u", "user"),
+    node("a", "assistant"),
+    node("e", "tool_call", 0, "Edit"),
+    node("sub", "assistant", 1),
+    node("r", "tool_call", 0, "Bash"),
+  ],
+  edges: [],
+  stats: emptyStats(),
+}
+
+describe("layoutNeeds", () => {
+  it("counts rows and columns for each layout and cell mode", () => {
+    const needs = layoutNeeds(graph, 80)
+    expect(needs.map(n => `${n.zoom}/${n.cellMode}`)).toEqual([
+      "details/symbol", "details/preview", "lanes/symbol", "lanes/preview", "files/symbol", "files/preview",
+    ])
+    const details = needs[0]
+    expect(details.rows).toBe(4) // User, Asst, Tool, agent Asst
+    expect(details.columns).toBe(5)
+    expect(details.screens).toBe(1)
+    const files = needs[4]
+    expect(files.columns).toBe(2) // the prompt and the edit; the rest touch no modified file
+  })
+
+  it("splits long sessions into screens at the given width", () => {
+    const long: Graph = { ...graph, nodes: Array.from({ length: 100 }, (_, i) => node(`n${i}`, "assistant")) }
+    const details = layoutNeeds(long, 80)[0]
+    expect(details.perScreen).toBe(columnsPerScreen("details", "symbol", 80))
+    expect(details.screens).toBe(Math.ceil(100 / details.perScreen))
+    expect(details.fullWidth).toBeGreaterThan(100 * COL_W_SYMBOL)
+  })
+
+  it("formats a table with one line per layout", () => {
+    const text = formatLayoutNeeds(layoutNeeds(graph, 80), "s1", graph.nodes.length, 80)
+    expect(text).toContain("Layout of s1 (5 nodes) at width 80")
+    expect(text).toContain("lanes (L)")
+    expect(text.trimEnd().split("\n").length).toBe(9)
+  })
+})
@@ -0,0 +1,48 @@
+import { describe, it, expect } from "bun:test"
+import type { SessionInfo } from "../src/core/types"
+import { sessionLifecycle, lifecycleOfInfo, isAgentTurn, IDLE_AFTER_MS } from "../src/core/lifecycle"
+import { ENDED_AFTER_MS } from "../src/core/state"
+import { user, reply, callAt as call } from "./fixtures"
+
+describe("sessionLifecycle", () => {
+  it("follows a turn from prompt to hand-back", () => {
+    expect(sessionLifecycle([], 0)).toBe("starting")
+    expect(sessionLifecycle([user(0)], 1000)).toBe("starting")
+    expect(sessionLifecycle([user(0), call(1000, null)], 2000)).toBe("tool-running")
+    expect(sessionLifecycle([user(0), call(1000, "ok")], 2000)).toBe("streaming")
+    expect(sessionLifecycle([user(0), call(1000, "ok"), reply(2000)], 3000)).toBe("waiting-for-user")
+  })
+
+  it("calls a stalled turn idle and a quiet session ended", () => {
+    const working = [user(0), call(1000, "ok")]
+    expect(sessionLifecycle(working, 1000 + IDLE_AFTER_MS + 1)).toBe("idle")
+    expect(sessionLifecycle(working, 1000 + ENDED_AFTER_MS + 1)).toBe("ended")
+  })
+
+  it("reports an unrecovered failure once the agent stops", () => {
+    const failed = [user(0), call(1000, "exit 1", true), reply(2000)]
+    expect(sessionLifecycle(failed, 3000)).toBe("error")
+    expect(sessionLifecycle(failed.slice(0, 2), 3000)).toBe("streaming")
+    expect(sessionLifecycle(failed, 2000 + ENDED_AFTER_MS + 1)).toBe("error")
+  })
+})
+
+describe("lifecycleOfInfo", () => {
+  const info = (timestamp: number, waitingForUser: boolean, nodeCount = 3): SessionInfo => ({ id: "s", timestamp, nodeCount, waitingForUser })
+
+  it("reads what the session list knows", () => {
+    expect(lifecycleOfInfo(info(0, false, 0), 0)).toBe("starting")
+    expect(lifecycleOfInfo(info(0, true), 1000)).toBe("waiting-for-user")
+    expect(lifecycleOfInfo(info(0, false), 1000)).toBe("streaming")
+    expect(lifecycleOfInfo(info(0, false), IDLE_AFTER_MS + 1)).toBe("idle")
+    expect(lifecycleOfInfo(info(0, true), ENDED_AFTER_MS + 1)).toBe("ended")
+  })
+})
+
+describe("isAgentTurn", () => {
+  it("covers the states the agent may still act in", () => {
+    expect((["starting", "streaming", "tool-running", "idle"] as const).every(isAgentTurn)).toBe(true)
+    expect(isAgentTurn("waiting-for-user")).toBe(false)
+    expect(isAgentTurn("error")).toBe(false)
+  })
+})
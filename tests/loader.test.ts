@@ -0,0 +1,39 @@
+import { describe, it, expect } from "bun:test"
+import { mkdirSync, writeFileSync } from "node:fs"
+import { join } from "node:path"
+import { tmpdir } from "node:os"
+import { parseSessionGraph } from "../src/sources/claude/loader"
+
+function ts(n: number): string {
+  return new Date(1_700_000_000_000 + n).toISOString()
+}
+
+describe("parseSessionGraphAsync", () => {
+  const dir = join(tmpdir(), `vizier-loader-${Date.now()}-${Math.random()}`)
+  mkdirSync(dir, { recursive: true })
+  const main = join(dir, "main.jsonl")
+  writeFileSync(main, [
+    { uuid: "u1", type: "user", timestamp: ts(1), message: { role: "user", content: "hi" } },
+    { uuid: "a1", type: "assistant", timestamp: ts(2), message: { role: "assistant", content: "hello" } },
+  ].map(e => JSON.stringify(e)).join("\n") + "\n")
+
+  // A separate process, like a one-shot command: it has to stay alive until
+  // the worker replies, and shouldn't fall back to the main thread
+  function run(env: Record<string, string> = {}) {
+    const loader = join(import.meta.dir, "../src/sources/claude/loader.ts")
+    const script = `import { parseSessionGraphAsync } from ${JSON.stringify(loader)}
+const graph = await parseSessionGraphAsync(${JSON.stringify(main)}, [])
+console.log(graph.nodes.map(n => n.id).join(","))`
+    return Bun.spawnSync([process.execPath, "-e", script], { env: { ...process.env, ...env } })
+  }
+
+  it("parses on the worker before the process exits", () => {
+    const result = run()
+    expect(result.stdout.toString().trim()).toBe(parseSessionGraph(main, []).nodes.map(n => n.id).join(","))
+    expect(result.stderr.toString()).toBe("")
+  })
+
+  it("parses on the calling thread with VIZIER_NO_WORKER", () => {
+    expect(run({ VIZIER_NO_WORKER: "1" }).stdout.toString().trim()).toBe(parseSessionGraph(main, []).nodes.map(n => n.id).join(","))
+  })
+})
@@ -0,0 +1,32 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { detectLoop } from "../src/core/loops"
+import { toolCall } from "./fixtures"
+
+function call(name: string, input = name, agentId?: string): Node {
+  return toolCall(`${name}-${Math.random()}`, name, { input, output: "" }, { agentId, branchLevel: 2 })
+}
+
+describe("detectLoop", () => {
+  it("finds a repeated cycle at the tail", () => {
+    const nodes = [call("Read"), call("Edit"), call("Bash"), call("Edit"), call("Bash"), call("Edit"), call("Bash")]
+    expect(detectLoop(nodes)).toEqual({ agentId: undefined, tools: ["Edit", "Bash"], repeats: 3 })
+  })
+
+  it("ignores cycles the agent has moved past", () => {
+    const nodes = [call("Edit"), call("Bash"), call("Edit"), call("Bash"), call("Edit"), call("Bash"), call("Read")]
+    expect(detectLoop(nodes)).toBeNull()
+  })
+
+  it("treats differing input as progress", () => {
+    const nodes = [call("Edit", "a"), call("Edit", "b"), call("Edit", "c")]
+    expect(detectLoop(nodes)).toBeNull()
+  })
+
+  it("reports collapsed repeats and agent loops", () => {
+    const collapsed = { ...call("Bash"), repeatCount: 4 }
+    expect(detectLoop([collapsed])?.repeats).toBe(4)
+    const agent = [call("Grep", "x", "a1"), call("Grep", "x", "a1"), call("Grep", "x", "a1"), call("Read")]
+    expect(detectLoop(agent)).toEqual({ agentId: "a1", tools: ["Grep"], repeats: 3 })
+  })
+})
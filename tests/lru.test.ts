@@ -0,0 +1,24 @@
+import { describe, it, expect } from "bun:test"
+import { createLru } from "../src/core/lru"
+
+describe("createLru", () => {
+  it("drops the least recently used entry", () => {
+    const lru = createLru<string, number>(2)
+    lru.set("a", 1)
+    lru.set("b", 2)
+    lru.get("a") // a is now newer than b
+    lru.set("c", 3)
+    expect(lru.get("b")).toBeUndefined()
+    expect(lru.get("a")).toBe(1)
+    expect(lru.get("c")).toBe(3)
+    expect(lru.size()).toBe(2)
+  })
+
+  it("replaces a key without growing", () => {
+    const lru = createLru<string, number>(2)
+    lru.set("a", 1)
+    lru.set("a", 2)
+    expect(lru.get("a")).toBe(2)
+    expect(lru.size()).toBe(1)
+  })
+})
@@ -0,0 +1,54 @@
+import { describe, it, expect } from "bun:test"
+import { parseInline, renderMarkdown, extractCodeBlocks, codeBlockExtension } from "../src/core/markdown"
+import { glyphs } from "../src/ui/glyphs"
+
+describe("parseInline", () => {
+  it("splits bold and inline code out of plain text", () => {
+    expect(parseInline("run `bun test` **now** please")).toEqual([
+      { text: "run " },
+      { text: "bun test", code: true },
+      { text: " " },
+      { text: "now", bold: true },
+      { text: " please" },
+    ])
+  })
+
+  it("leaves unmatched markers alone", () => {
+    expect(parseInline("a * b and `c")).toEqual([{ text: "a * b and `c" }])
+  })
+})
+
+describe("renderMarkdown", () => {
+  it("drops heading, bullet and fence markers", () => {
+    const lines = renderMarkdown("## Plan\n- read **a.ts**\n```ts\nconst x = 1\n```")
+    expect(lines.map(l => l.text)).toEqual(["Plan", `${glyphs().bullet} read a.ts`, "const x = 1"])
+    expect(lines[0].spans).toEqual([{ text: "Plan", bold: true }])
+    expect(lines[2].spans).toEqual([{ text: "const x = 1", code: true }])
+  })
+
+  it("wraps across spans without losing styling", () => {
+    const lines = renderMarkdown("one two `three four` five", 10)
+    expect(lines.map(l => l.text)).toEqual(["one two", "three four", "five"])
+    expect(lines[1].spans).toEqual([{ text: "three four", code: true }])
+  })
+})
+
+describe("extractCodeBlocks", () => {
+  it("returns fenced blocks with their language", () => {
+    const text = "Apply this:\n```diff\n-a\n+b\n```\nthen\n~~~\nplain\n~~~"
+    expect(extractCodeBlocks(text)).toEqual([
+      { lang: "diff", code: "-a\n+b" },
+      { lang: "", code: "plain" },
+    ])
+  })
+
+  it("keeps an unterminated block", () => {
+    expect(extractCodeBlocks("```py\nprint(1)")).toEqual([{ lang: "py", code: "print(1)" }])
+  })
+
+  it("maps languages to file extensions", () => {
+    expect(codeBlockExtension("TypeScript")).toBe("ts")
+    expect(codeBlockExtension("patch")).toBe("diff")
+    expect(codeBlockExtension("")).toBe("txt")
+  })
+})
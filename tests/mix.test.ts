@@ -0,0 +1,50 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { mixKind, bucketMix, stackColumn } from "../src/core/mix"
+import { bucketNodes } from "../src/core/buckets"
+import { toolCall } from "./fixtures"
+
+function call(name: string, ts: number, isError = false): Node {
+  return toolCall(`${name}-${ts}`, name, { output: "", isError }, { timestamp: ts })
+}
+
+describe("mixKind", () => {
+  it("sorts nodes into messages, reads, edits, runs and errors", () => {
+    expect(mixKind({ id: "u", nodeType: { kind: "user", text: "hi" }, timestamp: 0, branchLevel: 0 })).toBe("message")
+    expect(mixKind(call("Read", 0))).toBe("read")
+    expect(mixKind(call("Edit", 0))).toBe("edit")
+    expect(mixKind(call("Bash", 0))).toBe("run")
+    expect(mixKind(call("Edit", 0, true))).toBe("error")
+    expect(mixKind({ id: "p", nodeType: { kind: "progress", text: "" }, timestamp: 0, branchLevel: 0 })).toBeNull()
+  })
+
+  it("counts a failure once, on the call", () => {
+    const result: Node = { id: "r", nodeType: { kind: "tool_result", output: "boom", isError: true }, timestamp: 0, branchLevel: 0 }
+    expect(mixKind(result)).toBeNull()
+  })
+})
+
+describe("bucketMix", () => {
+  it("counts each bucket's kinds", () => {
+    const nodes = [call("Read", 0), call("Grep", 1), call("Edit", 10), call("Bash", 11, true)]
+    const mixes = bucketMix(nodes, bucketNodes(nodes, 2))
+    expect(mixes[0]).toEqual({ message: 0, read: 2, edit: 0, run: 0, error: 0 })
+    expect(mixes[1]).toEqual({ message: 0, read: 0, edit: 1, run: 0, error: 1 })
+  })
+})
+
+describe("stackColumn", () => {
+  it("splits the height in proportion, bottom first", () => {
+    expect(stackColumn({ message: 2, read: 2, edit: 0, run: 0, error: 0 }, 4)).toEqual(["message", "message", "read", "read"])
+  })
+
+  it("hands leftover cells to the largest remainders", () => {
+    const col = stackColumn({ message: 1, read: 1, edit: 1, run: 0, error: 0 }, 5)
+    expect(col.length).toBe(5)
+    expect(col.filter(k => k === "message").length).toBe(2)
+  })
+
+  it("is empty for an empty bucket", () => {
+    expect(stackColumn({ message: 0, read: 0, edit: 0, run: 0, error: 0 }, 3)).toEqual([null, null, null])
+  })
+})
@@ -0,0 +1,129 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { findSameKind, findParent, findFirstChild, findAgentLink, getBreadcrumb } from "../src/core/navigation"
+import { SHOW_ALL } from "../src/core/zoom"
+import { parseFilter } from "../src/core/filter"
+import { message } from "./fixtures"
+
+function parsedFilter(text: string) {
+  const f = parseFilter(text)
+  if ("error" in f) throw new Error(f.error)
+  return f
+}
+
+function tool(id: string, name: string): Node {
+  return { id, nodeType: { kind: "tool_call", name, input: "", output: "ok", isError: false }, timestamp: 0, branchLevel: 0 }
+}
+
+function text(id: string, kind: "user" | "assistant"): Node {
+  return message(id, kind)
+}
+
+describe("findSameKind", () => {
+  const nodes: Node[] = [
+    tool("e1", "Edit"),
+    text("a1", "assistant"),
+    tool("r1", "Read"),
+    tool("e2", "Edit"),
+    text("a2", "assistant"),
+    tool("e3", "Edit"),
+  ]
+
+  it("follows the same tool name", () => {
+    expect(findSameKind(nodes, 0, 1)).toBe(3)
+    expect(findSameKind(nodes, 5, -1)).toBe(3)
+    expect(findSameKind(nodes, 0, 1, 2)).toBe(5)
+  })
+
+  it("falls back to node kind for non-tool nodes", () => {
+    expect(findSameKind(nodes, 1, 1)).toBe(4)
+    expect(findSameKind(nodes, 4, 1)).toBeNull()
+  })
+
+  it("skips nodes the visibility mask hides", () => {
+    const hidden: Node = { ...tool("e2", "Edit"), nodeType: { kind: "tool_call", name: "Edit", input: "", output: "skip me", isError: false } }
+    const list = [nodes[0], hidden, nodes[5]]
+    expect(findSameKind(list, 0, 1)).toBe(1)
+    expect(findSameKind(list, 0, 1, 1, { ...SHOW_ALL, filter: parsedFilter("-skip") })).toBe(2)
+    const progress = (id: string): Node => ({ id, nodeType: { kind: "progress", text: id }, timestamp: 0, branchLevel: 0 })
+    expect(findSameKind([progress("p1"), progress("p2")], 0, 1, 1, { ...SHOW_ALL, hideProgress: true })).toBeNull()
+  })
+
+  it("stops at the last match when the count overshoots", () => {
+    expect(findSameKind(nodes, 0, 1, 10)).toBe(5)
+  })
+})
+
+describe("findParent / findFirstChild", () => {
+  const nodes: Node[] = [
+    { ...text("u1", "user") },
+    { ...text("a1", "assistant"), parentId: "u1" },
+    { ...tool("t1", "Read"), parentId: "a1" },
+    { ...tool("t2", "Edit"), parentId: "a1" },
+    { id: "r9", parentId: "missing", nodeType: { kind: "tool_result", output: "", isError: true }, timestamp: 0, branchLevel: 0 },
+  ]
+
+  it("walks parent links", () => {
+    expect(findParent(nodes, 2)).toBe(1)
+    expect(findParent(nodes, 1)).toBe(0)
+    expect(findParent(nodes, 0)).toBeNull()
+    expect(findParent(nodes, 4)).toBeNull()
+  })
+
+  it("finds the first child", () => {
+    expect(findFirstChild(nodes, 1)).toBe(2)
+    expect(findFirstChild(nodes, 0)).toBe(1)
+    expect(findFirstChild(nodes, 3)).toBeNull()
+  })
+})
+
+describe("getBreadcrumb", () => {
+  const nodes: Node[] = [
+    { ...text("u1", "user") },
+    { ...text("a1", "assistant"), parentId: "u1" },
+    { ...tool("task", "Task"), parentId: "a1" },
+    { ...text("g1", "assistant"), parentId: "task", agentId: "x" },
+    { ...tool("g2", "Read"), parentId: "g1", agentId: "x" },
+  ]
+
+  it("runs from the turn prompt to the node", () => {
+    expect(getBreadcrumb(nodes, 2)).toEqual([0, 1, 2])
+    expect(getBreadcrumb(nodes, 0)).toEqual([0])
+  })
+
+  it("passes through the spawning tool for agent nodes", () => {
+    expect(getBreadcrumb(nodes, 4)).toEqual([0, 1, 2, 3, 4])
+  })
+})
+
+describe("findAgentLink", () => {
+  const nodes: Node[] = [
+    { ...text("u1", "user") },
+    { ...text("a1", "assistant"), parentId: "u1" },
+    { ...tool("task", "Task"), parentId: "a1" },
+    { ...text("g1", "assistant"), parentId: "task", agentId: "x" },
+    { ...tool("g2", "Read"), parentId: "g1", agentId: "x" },
+    { ...text("g3", "assistant"), parentId: "g2", agentId: "x" },
+    { id: "end", nodeType: { kind: "agent_end", agentId: "x" }, timestamp: 0, branchLevel: 1 },
+    { ...text("a2", "assistant"), parentId: "task" },
+  ]
+
+  it("goes from the Task call to the agent's last reply", () => {
+    expect(findAgentLink(nodes, 2)).toBe(5)
+  })
+
+  it("goes from inside the agent to its last reply", () => {
+    expect(findAgentLink(nodes, 3)).toBe(5)
+    expect(findAgentLink(nodes, 4)).toBe(5)
+  })
+
+  it("goes from the last reply or agent_end back to the Task call", () => {
+    expect(findAgentLink(nodes, 5)).toBe(2)
+    expect(findAgentLink(nodes, 6)).toBe(2)
+  })
+
+  it("returns null outside subagents", () => {
+    expect(findAgentLink(nodes, 1)).toBeNull()
+    expect(findAgentLink(nodes, 7)).toBeNull()
+  })
+})
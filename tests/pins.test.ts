@@ -0,0 +1,30 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { togglePin, resolvePins, MAX_PINS } from "../src/core/pins"
+import { toolCall } from "./fixtures"
+
+function bash(id: string, isError: boolean): Node {
+  return toolCall(id, "Bash", { output: id, isError }, { branchLevel: 2 })
+}
+
+describe("togglePin", () => {
+  it("pins and unpins", () => {
+    const pins = togglePin(togglePin([], "a"), "b")
+    expect(pins).toEqual(["a", "b"])
+    expect(togglePin(pins, "a")).toEqual(["b"])
+  })
+
+  it("drops the oldest pin past the limit", () => {
+    let pins: string[] = []
+    for (let i = 0; i <= MAX_PINS; i++) pins = togglePin(pins, `n${i}`)
+    expect(pins.length).toBe(MAX_PINS)
+    expect(pins[0]).toBe("n1")
+  })
+})
+
+describe("resolvePins", () => {
+  it("returns pinned nodes in pin order and skips missing ones", () => {
+    const nodes = [bash("fail", true), bash("pass", false)]
+    expect(resolvePins(nodes, ["pass", "gone", "fail"]).map(n => n.id)).toEqual(["pass", "fail"])
+  })
+})
@@ -0,0 +1,85 @@
+import { describe, it, expect, afterEach } from "bun:test"
+import type { Node } from "../src/core/types"
+import { setRenderPlugins, pluginFor, pluginLabel, pluginDetails } from "../src/core/plugins"
+import { loadPlugins, instantiatePlugin } from "../src/plugins/wasm"
+import { getNodePreview } from "../src/core/zoom"
+import { toolCall } from "./fixtures"
+
+function call(name: string, output: string | null = "[[1,2]]"): Node {
+  return toolCall(name, name, { output })
+}
+
+afterEach(() => setRenderPlugins([]))
+
+describe("render plugins", () => {
+  it("picks the first plugin listing the tool", () => {
+    const sql = { name: "sql", tools: ["mcp__postgres__*"] }
+    setRenderPlugins([sql, { name: "all", tools: ["*"] }])
+    expect(pluginFor("MCP__postgres__query")).toBe(sql)
+    expect(pluginFor("Bash")?.name).toBe("all")
+    setRenderPlugins([sql])
+    expect(pluginFor("Bash")).toBeNull()
+  })
+
+  it("renders labels and details through the plugin", () => {
+    setRenderPlugins([{
+      name: "sql",
+      tools: ["query"],
+      label: c => `SQL ${c.isError ? "failed" : "ok"}`,
+      details: c => (JSON.parse(c.output ?? "[]") as number[][]).map(row => row.join(" | ")),
+    }])
+    expect(pluginLabel(call("query"))).toBe("SQL ok")
+    expect(getNodePreview(call("query"))).toBe("SQL ok")
+    expect(pluginDetails(call("query"))).toEqual(["1 | 2"])
+    expect(getNodePreview(call("Bash"))).toBe("Bash")
+  })
+
+  it("falls back when a hook declines or throws", () => {
+    setRenderPlugins([{ name: "broken", tools: ["*"], label: () => { throw new Error("boom") }, details: () => null }])
+    expect(pluginLabel(call("query"))).toBeNull()
+    expect(pluginDetails(call("query"))).toBeNull()
+    expect(getNodePreview(call("query"))).toBe("query")
+  })
+})
+
+// A minimal module: alloc always returns 1024, label echoes its argument
+// back, and details returns "one\ntwo" from a data segment at offset 16
+const ECHO_WASM = new Uint8Array([
+  0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x02, 0x60,
+  0x01, 0x7f, 0x01, 0x7f, 0x60, 0x02, 0x7f, 0x7f, 0x01, 0x7e, 0x03, 0x04,
+  0x03, 0x00, 0x01, 0x01, 0x05, 0x03, 0x01, 0x00, 0x01, 0x07, 0x24, 0x04,
+  0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x05, 0x61, 0x6c,
+  0x6c, 0x6f, 0x63, 0x00, 0x00, 0x05, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x00,
+  0x01, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x00, 0x02, 0x0a,
+  0x1e, 0x03, 0x05, 0x00, 0x41, 0x80, 0x08, 0x0b, 0x0c, 0x00, 0x20, 0x00,
+  0xad, 0x42, 0x20, 0x86, 0x20, 0x01, 0xad, 0x84, 0x0b, 0x09, 0x00, 0x42,
+  0x87, 0x80, 0x80, 0x80, 0x80, 0x02, 0x0b, 0x0b, 0x0d, 0x01, 0x00, 0x41,
+  0x10, 0x0b, 0x07, 0x6f, 0x6e, 0x65, 0x0a, 0x74, 0x77, 0x6f,
+])
+
+describe("wasm plugins", () => {
+  it("passes the call in as JSON and reads strings back out", () => {
+    const plugin = instantiatePlugin(ECHO_WASM, "echo.wasm", ["query"])
+    setRenderPlugins([plugin])
+    const node = call("query")
+    expect(pluginLabel(node)).toBe('{"name":"query","input":"{}","output":"[[1,2]]","isError":false}')
+    expect(pluginDetails(node)).toEqual(["one", "two"])
+  })
+
+  it("rejects modules without memory and alloc", () => {
+    const empty = new Uint8Array([0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00])
+    expect(() => instantiatePlugin(empty, "empty.wasm", ["x"])).toThrow("must export memory and alloc")
+  })
+})
+
+describe("loadPlugins", () => {
+  it("reports plugins that can't load and keeps going", () => {
+    const { plugins, errors } = loadPlugins([
+      { wasm: "missing.wasm", tools: ["x"] },
+      { tools: ["y"] } as never,
+    ], "/nonexistent/config.json")
+    expect(plugins).toEqual([])
+    expect(errors.length).toBe(2)
+    expect(errors[0]).toContain("missing.wasm")
+  })
+})
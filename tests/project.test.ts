@@ -0,0 +1,79 @@
+import { describe, it, expect } from "bun:test"
+import { mkdtempSync, mkdirSync, symlinkSync, realpathSync } from "node:fs"
+import { tmpdir } from "node:os"
+import { join, relative } from "node:path"
+import { canonicalProjectPath, projectSetting, watchedDirs, workspaceDirs } from "../src/sources/project"
+
+describe("canonicalProjectPath", () => {
+  const root = realpathSync(mkdtempSync(join(tmpdir(), "vizier-project-")))
+  const real = join(root, "foo")
+  mkdirSync(real)
+  symlinkSync(real, join(root, "link"))
+
+  it("normalizes relative paths and trailing slashes", () => {
+    expect(canonicalProjectPath(`${real}/`)).toBe(real)
+    expect(canonicalProjectPath(`${relative(process.cwd(), real)}/`)).toBe(real)
+  })
+
+  it("resolves symlinks", () => {
+    expect(canonicalProjectPath(join(root, "link"))).toBe(real)
+  })
+
+  it("keeps missing directories under their canonical ancestor", () => {
+    expect(canonicalProjectPath(join(root, "link", "gone", "deeper/"))).toBe(join(real, "gone", "deeper"))
+  })
+})
+
+describe("workspaceDirs", () => {
+  const root = realpathSync(mkdtempSync(join(tmpdir(), "vizier-workspace-")))
+  const repo = join(root, "repo")
+  mkdirSync(join(repo, "packages", "api"), { recursive: true })
+  mkdirSync(join(repo, "packages", "web"), { recursive: true })
+  const workspaces = { repo: [repo, `${repo}/packages/*`] }
+
+  it("lists the whole workspace, starting with the project itself", () => {
+    const api = join(repo, "packages", "api")
+    expect(workspaceDirs(api, workspaces)).toEqual([api, repo, join(repo, "packages", "web")])
+  })
+
+  it("includes directories below a member", () => {
+    const src = join(repo, "packages", "api", "src")
+    expect(workspaceDirs(src, workspaces)[0]).toBe(src)
+    expect(workspaceDirs(src, workspaces)).toContain(repo)
+  })
+
+  it("leaves projects outside any workspace alone", () => {
+    expect(workspaceDirs(join(root, "repo-other"), workspaces)).toEqual([join(root, "repo-other")])
+    expect(workspaceDirs(repo)).toEqual([repo])
+  })
+
+  it("expands ~ against the home directory", () => {
+    expect(workspaceDirs(repo, { repo: ["~/repo"] }, root)).toEqual([repo])
+  })
+})
+
+describe("projectSetting", () => {
+  const root = realpathSync(mkdtempSync(join(tmpdir(), "vizier-setting-")))
+  const repo = join(root, "repo")
+  mkdirSync(join(repo, "api"), { recursive: true })
+  const byDir = { [repo]: "repo", [join(repo, "api")]: "api", "~/elsewhere": "home" }
+
+  it("takes the deepest directory containing the project", () => {
+    expect(projectSetting(join(repo, "api", "src"), byDir)).toBe("api")
+    expect(projectSetting(repo, byDir)).toBe("repo")
+    expect(projectSetting(root, byDir)).toBeNull()
+  })
+
+  it("expands ~ against the home directory", () => {
+    expect(projectSetting(join(root, "elsewhere"), byDir, root)).toBe("home")
+  })
+})
+
+describe("watchedDirs", () => {
+  const root = realpathSync(mkdtempSync(join(tmpdir(), "vizier-watch-")))
+  mkdirSync(join(root, "api"))
+
+  it("expands ~, dedupes and leaves out the current project", () => {
+    expect(watchedDirs(join(root, "web"), ["~/api", join(root, "api"), join(root, "web")], root)).toEqual([join(root, "api")])
+  })
+})
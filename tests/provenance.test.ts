@@ -0,0 +1,74 @@
+import { describe, it, expect } from "bun:test"
+import { createHash } from "node:crypto"
+import type { SourceFile } from "../src/core/provenance"
+import { sessionHash, sha256Of, provenanceMarkdown, provenanceComment, provenanceToJson, provenanceLine, sourcesMarkdown, sourcesToJson } from "../src/core/provenance"
+import { turnToJson, turnToMarkdown } from "../src/core/export"
+import { buildReport, formatReportMarkdown, reportToJson } from "../src/core/report"
+import { errorReportToJson } from "../src/core/errors"
+import { toolComparisonToJson } from "../src/core/compare"
+import { graph } from "./fixtures"
+import type { Node } from "../src/core/types"
+
+function file(path: string, content: string): SourceFile {
+  return { path, bytes: content.length, modifiedAt: 0, sha256: sha256Of(content) }
+}
+
+const main = file("/home/me/.claude/projects/p/abc.jsonl", "{}\n")
+const agent = file("/home/me/.claude/projects/p/abc/subagents/agent-1.jsonl", "[]\n")
+
+describe("sessionHash", () => {
+  it("is the file's own hash for a single file", () => {
+    expect(sessionHash([main])).toBe(main.sha256)
+  })
+
+  it("hashes the sha256sum lines of several files", () => {
+    const lines = `${main.sha256}  abc.jsonl\n${agent.sha256}  agent-1.jsonl\n`
+    expect(sessionHash([main, agent])).toBe(createHash("sha256").update(lines).digest("hex"))
+    expect(sessionHash([agent, main])).not.toBe(sessionHash([main, agent]))
+  })
+})
+
+describe("provenance in exports", () => {
+  const provenance = { files: [main, agent], sha256: sessionHash([main, agent]) }
+  const nodes: Node[] = [{ id: "u1", timestamp: 0, branchLevel: 0, nodeType: { kind: "user", text: "go" } }]
+
+  it("lists every file", () => {
+    expect(provenanceMarkdown(provenance)).toContain(`- SHA-256: \`${provenance.sha256}\``)
+    expect(provenanceComment(provenance)).toHaveLength(3)
+    expect(provenanceToJson(provenance)).toEqual({
+      sha256: provenance.sha256,
+      files: [main, agent].map(f => ({ ...f, modifiedAt: "1970-01-01T00:00:00.000Z" })),
+    })
+  })
+
+  it("is added to turn exports when known", () => {
+    const bounds = { start: 0, end: 1 }
+    expect(turnToMarkdown(nodes, bounds, { sessionId: "abc", turn: 1, provenance })).toContain("## Source")
+    expect(JSON.parse(turnToJson(nodes, bounds, { sessionId: "abc", turn: 1, provenance })).provenance.sha256).toBe(provenance.sha256)
+    expect(JSON.parse(turnToJson(nodes, bounds, { sessionId: "abc", turn: 1 })).provenance).toBeUndefined()
+  })
+})
+
+describe("provenance in reports", () => {
+  const provenance = { files: [main], sha256: main.sha256 }
+  const sources = [{ sessionId: "abc", provenance }]
+  const report = buildReport([graph([])], 0, 1)
+
+  it("lists a line per session", () => {
+    expect(sourcesMarkdown(sources)).toBe(`## Sources\n\n- abc: ${provenanceLine(provenance)}`)
+    expect(sourcesToJson(sources)).toEqual([{ sessionId: "abc", ...provenanceToJson(provenance) }])
+  })
+
+  it("ends the markdown report with the sources", () => {
+    expect(formatReportMarkdown(report, "1d", sources)).toEndWith(`${sourcesMarkdown(sources)}\n`)
+    expect(formatReportMarkdown(report, "1d")).not.toContain("## Sources")
+  })
+
+  it("is in the JSON of report, errors and compare-tools", () => {
+    expect(JSON.parse(reportToJson(report, "1d", sources)).sources[0].sha256).toBe(main.sha256)
+    expect(JSON.parse(reportToJson(report, "1d")).sources).toEqual([])
+    expect(JSON.parse(toolComparisonToJson([], 1, sources)).sources[0].sessionId).toBe("abc")
+    expect(JSON.parse(errorReportToJson([], [], "abc", provenance)).provenance.sha256).toBe(main.sha256)
+    expect(JSON.parse(errorReportToJson([], [], "abc")).provenance).toBeNull()
+  })
+})
@@ -0,0 +1,74 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { computeRelations } from "../src/core/related"
+import { listTurns, getTurnBounds, computeTurnStats, turnPrompt } from "../src/core/turns"
+
+function n(id: string, kind: "user" | "assistant", parentId?: string, agentId?: string): Node {
+  return { id, parentId, agentId, nodeType: { kind, text: id }, timestamp: 0, branchLevel: agentId ? 1 : 0 }
+}
+
+const nodes: Node[] = [
+  n("u1", "user"),
+  n("a1", "assistant", "u1"),
+  n("a2", "assistant"),
+  n("g1", "assistant", "a1", "agent-1"),
+  n("g2", "assistant", undefined, "agent-1"),
+  n("u2", "user", "a2"),
+  n("a3", "assistant", "u2"),
+]
+
+describe("turns", () => {
+  it("splits at main-session user prompts", () => {
+    expect(listTurns(nodes)).toEqual([{ start: 0, end: 5 }, { start: 5, end: 7 }])
+    expect(getTurnBounds(nodes, 3)).toEqual({ start: 0, end: 5 })
+    expect(getTurnBounds(nodes, 6)).toEqual({ start: 5, end: 7 })
+  })
+
+  it("finds the prompt that opened the turn", () => {
+    expect(turnPrompt(nodes, 3)).toEqual({ index: 0, turn: 1, text: "u1" })
+    expect(turnPrompt(nodes, 6)).toEqual({ index: 5, turn: 2, text: "u2" })
+    expect(turnPrompt(nodes.slice(1), 1)).toBeNull()
+  })
+})
+
+describe("computeRelations", () => {
+  it("marks lineage, same agent, turn and unrelated nodes", () => {
+    const rel = computeRelations(nodes, 1)
+    expect(rel).toEqual(["lineage", "self", "turn", "lineage", "turn", "none", "none"])
+  })
+
+  it("groups nodes of the same agent", () => {
+    const rel = computeRelations(nodes, 4)
+    expect(rel[3]).toBe("lineage")
+    expect(rel[4]).toBe("self")
+  })
+
+  it("keeps lineage within the selected turn", () => {
+    const rel = computeRelations(nodes, 5)
+    expect(rel[2]).toBe("none")
+    expect(rel[6]).toBe("lineage")
+  })
+
+  it("returns all none without a selection", () => {
+    expect(computeRelations(nodes, null).every(r => r === "none")).toBe(true)
+  })
+})
+
+describe("computeTurnStats", () => {
+  const turn: Node[] = [
+    { id: "u", nodeType: { kind: "user", text: "go" }, timestamp: 1000, branchLevel: 0 },
+    { id: "a", nodeType: { kind: "assistant", text: "ok" }, timestamp: 2000, branchLevel: 1, usage: { input_tokens: 10, output_tokens: 5 } },
+    { id: "t1", nodeType: { kind: "tool_call", name: "Read", input: "", output: "", isError: false }, timestamp: 3000, branchLevel: 2 },
+    { id: "t2", nodeType: { kind: "tool_call", name: "Read", input: "", output: "no", isError: true }, timestamp: 4000, branchLevel: 2 },
+    { id: "t3", nodeType: { kind: "tool_call", name: "Edit", input: "", output: null, isError: false }, timestamp: 6000, branchLevel: 2 },
+  ]
+
+  it("aggregates tools, errors, tokens and duration", () => {
+    const stats = computeTurnStats(turn, { start: 0, end: turn.length })
+    expect(stats.tools).toEqual({ Read: 2, Edit: 1 })
+    expect(stats.toolCalls).toBe(3)
+    expect(stats.errors).toBe(1)
+    expect(stats.durationMs).toBe(5000)
+    expect(stats.tokens.totalInputTokens).toBe(10)
+  })
+})
@@ -0,0 +1,85 @@
+import { describe, it, expect } from "bun:test"
+import { renderSnapshot } from "../src/cli/render"
+import { graphAtFrame, graphAtTime, eventTimes, stepTime, parseFrame } from "../src/core/frames"
+import { buildGraph } from "../src/sources/claude/graph"
+import { generateDemoEvents } from "../src/sources/demo/generate"
+
+const graph = buildGraph(generateDemoEvents({ seed: 3, turns: 3 }))
+
+describe("parseFrame", () => {
+  it("accepts node counts and last", () => {
+    expect(parseFrame("last")).toBe("last")
+    expect(parseFrame("12")).toBe(12)
+    expect(parseFrame("-1")).toBeNull()
+    expect(parseFrame("first")).toBeNull()
+  })
+})
+
+describe("graphAtFrame", () => {
+  it("keeps the first n nodes and their edges", () => {
+    const frame = graphAtFrame(graph, 5)
+    expect(frame.nodes).toEqual(graph.nodes.slice(0, 5))
+    const ids = new Set(frame.nodes.map(n => n.id))
+    expect(frame.edges.every(e => ids.has(e.from) && ids.has(e.to))).toBe(true)
+    expect(frame.stats.totalInputTokens).toBeLessThanOrEqual(graph.stats.totalInputTokens)
+    expect(graphAtFrame(graph, "last")).toBe(graph)
+  })
+})
+
+describe("graphAtTime", () => {
+  const call = graph.nodes.find(n => n.nodeType.kind === "tool_call" && n.endTimestamp !== undefined && n.endTimestamp > n.timestamp)!
+
+  it("hides later nodes and reopens calls still running then", () => {
+    const past = graphAtTime(graph, call.timestamp)
+    expect(past.nodes.every(n => n.timestamp <= call.timestamp)).toBe(true)
+    const then = past.nodes.find(n => n.id === call.id)!
+    expect(then.nodeType.kind === "tool_call" && then.nodeType.output).toBeNull()
+    expect(then.endTimestamp).toBeUndefined()
+  })
+
+  it("shows the result once it has arrived", () => {
+    const past = graphAtTime(graph, call.endTimestamp!)
+    expect(past.nodes.find(n => n.id === call.id)).toBe(call)
+    expect(graphAtTime(graph, Number.MAX_SAFE_INTEGER)).toBe(graph)
+  })
+})
+
+describe("stepTime", () => {
+  const times = [10, 20, 30]
+
+  it("steps from event to event, stopping at the ends", () => {
+    expect(stepTime(times, 20, 1)).toBe(30)
+    expect(stepTime(times, 20, -1)).toBe(10)
+    expect(stepTime(times, 30, 5)).toBe(30)
+    expect(stepTime(times, 10, -5)).toBe(10)
+  })
+
+  it("lands on the nearest event from between two", () => {
+    expect(stepTime(times, 15, 1)).toBe(20)
+    expect(stepTime(times, 15, -1)).toBe(10)
+  })
+
+  it("lists node starts and tool results in order", () => {
+    const all = eventTimes(graph)
+    expect(all).toEqual([...all].sort((a, b) => a - b))
+    expect(all).toContain(call.endTimestamp!)
+  })
+})
+
+describe("renderSnapshot", () => {
+  it("renders the same plain text every time", () => {
+    const options = { width: 100, height: 80, frame: "last" as const }
+    const a = renderSnapshot(graph, options)
+    expect(renderSnapshot(graph, options)).toBe(a)
+    expect(a).not.toContain("\x1b[")
+    expect(a).toContain(`${graph.nodes.length} nodes`)
+  })
+
+  it("clips to the requested height", () => {
+    expect(renderSnapshot(graph, { width: 100, height: 10, frame: "last" }).split("\n").length - 1).toBe(10)
+  })
+
+  it("renders an earlier frame", () => {
+    expect(renderSnapshot(graph, { width: 100, height: 80, frame: 4 })).toContain("4 nodes")
+  })
+})
@@ -0,0 +1,43 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { collapseRepeats } from "../src/core/repeats"
+import { message, toolCall } from "./fixtures"
+
+function call(id: string, input: string, parentId?: string, agentId?: string): Node {
+  return toolCall(id, "Bash", { input, output: "fail", isError: true }, { parentId, agentId, branchLevel: 2 })
+}
+
+function text(id: string, kind: "user" | "assistant", parentId?: string): Node {
+  return message(id, kind, { parentId, branchLevel: kind === "user" ? 0 : 1 })
+}
+
+describe("collapseRepeats", () => {
+  it("folds identical calls into the last one", () => {
+    const { nodes, removed } = collapseRepeats([
+      text("a0", "assistant"),
+      call("b1", "{\"cmd\":\"npm test\"}", "a0"),
+      text("a1", "assistant", "b1"),
+      call("b2", "{\"cmd\":\"npm  test\"}", "a1"),
+      call("b3", "{\"cmd\":\"npm test\"}", "b2"),
+    ])
+    expect(removed).toBe(2)
+    expect(nodes.map(n => n.id)).toEqual(["a0", "a1", "b3"])
+    expect(nodes[2].repeatCount).toBe(3)
+    // children of removed calls move up to the removed call's parent
+    expect(nodes[1].parentId).toBe("a0")
+    expect(nodes[2].parentId).toBe("a1")
+  })
+
+  it("keeps calls with different input, actors or a prompt in between", () => {
+    const input: Node[] = [
+      call("b1", "ls"),
+      call("b2", "ls -la"),
+      call("g1", "ls -la", undefined, "agent-1"),
+      text("u1", "user"),
+      call("b3", "ls -la"),
+    ]
+    const { nodes, removed } = collapseRepeats(input)
+    expect(removed).toBe(0)
+    expect(nodes).toBe(input)
+  })
+})
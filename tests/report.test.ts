@@ -0,0 +1,78 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { parseSince, buildReport, formatReport, formatReportMarkdown, reportToJson, IDLE_GAP_MS } from "../src/core/report"
+import { graph, toolCall } from "./fixtures"
+
+const day = (d: number, hour = 12, minute = 0) => new Date(2025, 0, d, hour, minute).getTime()
+
+function call(name: string, at: number, isError = false): Node {
+  return toolCall(`${name}-${at}`, name, { input: "", output: "", isError }, { timestamp: at, branchLevel: 1 })
+}
+
+describe("parseSince", () => {
+  it("reads minutes, hours, days and weeks", () => {
+    expect(parseSince("90m")).toBe(90 * 60_000)
+    expect(parseSince("24h")).toBe(86_400_000)
+    expect(parseSince("7d")).toBe(7 * 86_400_000)
+    expect(parseSince("2w")).toBe(14 * 86_400_000)
+  })
+
+  it("rejects anything else", () => {
+    expect(parseSince("7")).toBeNull()
+    expect(parseSince("0d")).toBeNull()
+    expect(parseSince("a week")).toBeNull()
+  })
+})
+
+describe("buildReport", () => {
+  const user: Node = { id: "u", nodeType: { kind: "user", text: "go" }, timestamp: day(14, 9), branchLevel: 0, usage: { input_tokens: 100, output_tokens: 10 } }
+  const graphs = [
+    graph([call("Bash", day(1)), user, call("Bash", day(14, 9, 1), true), call("Read", day(14, 9, 3))]),
+    graph([call("Read", day(15, 10)), call("Read", day(15, 11))]),
+    graph([call("Edit", day(2))]),
+  ]
+  const report = buildReport(graphs, day(13, 0), day(15, 23))
+
+  it("counts only sessions and events inside the window", () => {
+    expect(report.sessions).toBe(2)
+    expect(report.tools).toEqual([
+      { name: "Read", calls: 3, errors: 0 },
+      { name: "Bash", calls: 1, errors: 1 },
+    ])
+    expect(report.tokens.totalInputTokens).toBe(100)
+  })
+
+  it("caps idle gaps when adding up active time", () => {
+    expect(report.activeMs).toBe(3 * 60_000 + IDLE_GAP_MS)
+  })
+
+  it("has one row per day for the error trend", () => {
+    expect(report.days.map(d => [d.day, d.sessions, d.calls, d.errors])).toEqual([
+      ["2025-01-13", 0, 0, 0],
+      ["2025-01-14", 1, 2, 1],
+      ["2025-01-15", 1, 2, 0],
+    ])
+  })
+
+  it("has each calendar day once across a DST change", () => {
+    // US and EU clocks both change in March
+    const march = buildReport([], new Date(2025, 2, 1, 23, 30).getTime(), new Date(2025, 2, 31, 0, 30).getTime())
+    expect(march.days.map(d => d.day)).toEqual(Array.from({ length: 31 }, (_, i) => `2025-03-${String(i + 1).padStart(2, "0")}`))
+  })
+
+  it("formats as a table and as markdown", () => {
+    const table = formatReport(report, "3d")
+    expect(table).toContain("Sessions:     2")
+    expect(table).toMatch(/Bash\s+1\s+1\s+100%/)
+    const md = formatReportMarkdown(report, "3d")
+    expect(md).toContain("| Read | 3 | 0 | 0% |")
+    expect(md).toContain("| 2025-01-13 | 0 | 0 | 0 | - |")
+  })
+
+  it("has a versioned JSON form", () => {
+    const json = JSON.parse(reportToJson(report, "3d"))
+    expect(json).toMatchObject({ schemaVersion: 1, kind: "report", window: "3d", sessions: 2 })
+    expect(json.tokens).toEqual({ input: 100, output: 10, cacheRead: 0, cacheCreation: 0, total: 110, cost: null })
+    expect(json.days).toHaveLength(3)
+  })
+})
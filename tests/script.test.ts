@@ -0,0 +1,39 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { toShellScript } from "../src/core/script"
+import { toolCall } from "./fixtures"
+
+function bash(id: string, input: object, isError = false, extra: Partial<Node> = {}): Node {
+  return toolCall(id, "Bash", { input: JSON.stringify(input), output: "", isError }, { timestamp: Date.UTC(2026, 0, 1, 14, 2, 11), branchLevel: 2, ...extra })
+}
+
+const meta = { sessionId: "abc", exportedAt: Date.UTC(2026, 0, 1) }
+
+describe("toShellScript", () => {
+  const nodes: Node[] = [
+    bash("t1", { command: "npm ci", description: "Install\n  dependencies" }),
+    { id: "r1", nodeType: { kind: "tool_call", name: "Read", input: "{\"file_path\":\"a\"}", output: "", isError: false }, timestamp: 0, branchLevel: 2 },
+    bash("t2", { command: "npm test" }, true, { agentId: "x" }),
+    bash("t3", { command: "npm run dev", run_in_background: true }),
+  ]
+  const script = toShellScript(nodes, meta)
+
+  it("lists the shell commands in order under a header", () => {
+    expect(script.startsWith("#!/usr/bin/env bash\n# Shell commands from session abc\n# Nodes t1 to t3\n")).toBe(true)
+    expect(script.indexOf("npm ci")).toBeLessThan(script.indexOf("npm test"))
+    expect(script).not.toContain("file_path")
+  })
+
+  it("cites each command's node", () => {
+    expect(script).toContain("# t1 14:02:11\n# Install dependencies\nnpm ci\n")
+    expect(script).toContain("# t2 14:02:11, agent x, failed\nnpm test\n")
+  })
+
+  it("backgrounds what ran in the background", () => {
+    expect(script).toContain("# ran in the background\nnpm run dev &\n")
+  })
+
+  it("says so when the range has no commands", () => {
+    expect(toShellScript([nodes[1]], meta)).toContain("# No shell commands in this range")
+  })
+})
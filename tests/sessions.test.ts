@@ -0,0 +1,96 @@
+import { describe, it, expect } from "bun:test"
+import type { SessionInfo } from "../src/core/types"
+import { sortSessions, nextSessionSort, filterSessions, sessionStamp, shortSessionId, refreshedSessions, cycleRecentSession } from "../src/core/sessions"
+
+function session(id: string, timestamp: number, extra: Partial<SessionInfo> = {}): SessionInfo {
+  return { id, timestamp, nodeCount: 0, waitingForUser: false, ...extra }
+}
+
+describe("sortSessions", () => {
+  const list = [
+    session("a", 300, { startedAt: 100, nodeCount: 5 }),
+    session("b", 200, { startedAt: 250, nodeCount: 50, waitingForUser: true }),
+    session("c", 100, { startedAt: 50, nodeCount: 20 }),
+  ]
+
+  it("orders by each key, newest/largest first", () => {
+    expect(sortSessions(list, "activity").map(s => s.id)).toEqual(["a", "b", "c"])
+    expect(sortSessions(list, "started").map(s => s.id)).toEqual(["b", "a", "c"])
+    expect(sortSessions(list, "events").map(s => s.id)).toEqual(["b", "c", "a"])
+    expect(sortSessions(list, "waiting").map(s => s.id)).toEqual(["b", "a", "c"])
+  })
+
+  it("does not mutate the input", () => {
+    sortSessions(list, "events")
+    expect(list.map(s => s.id)).toEqual(["a", "b", "c"])
+  })
+
+  it("cycles through all sort modes", () => {
+    expect(nextSessionSort("activity")).toBe("started")
+    expect(nextSessionSort("waiting")).toBe("activity")
+  })
+})
+
+describe("filterSessions", () => {
+  it("matches title, id and notes case-insensitively", () => {
+    const list = [
+      session("abc123", 1, { title: "Fix login" }),
+      session("def456", 2),
+    ]
+    const notes: Record<string, string> = { def456: "Auth bug repro" }
+    const noteFor = (s: SessionInfo) => notes[s.id]
+    expect(filterSessions(list, "login", noteFor).map(s => s.id)).toEqual(["abc123"])
+    expect(filterSessions(list, "auth", noteFor).map(s => s.id)).toEqual(["def456"])
+    expect(filterSessions(list, "DEF", noteFor).map(s => s.id)).toEqual(["def456"])
+    expect(filterSessions(list, "  ", noteFor).length).toBe(2)
+  })
+})
+
+describe("sessionStamp", () => {
+  it("changes with the transcript", () => {
+    const info = { id: "s1", timestamp: 1000, nodeCount: 5, waitingForUser: false, sizeBytes: 200 }
+    expect(sessionStamp(info)).toBe(sessionStamp({ ...info, waitingForUser: true }))
+    expect(sessionStamp(info)).not.toBe(sessionStamp({ ...info, sizeBytes: 300 }))
+    expect(sessionStamp(undefined)).toBeNull()
+  })
+})
+
+describe("shortSessionId", () => {
+  it("drops the source prefix and keeps 8 characters", () => {
+    expect(shortSessionId("claude:0123456789")).toBe("01234567")
+    expect(shortSessionId("opencode:ses_abcdefghij")).toBe("ses_abcd")
+    expect(shortSessionId("abc")).toBe("abc")
+  })
+})
+
+describe("refreshedSessions", () => {
+  const prev = [session("a", 300, { nodeCount: 5 }), session("b", 200)]
+
+  it("keeps the previous list when nothing shown changed", () => {
+    expect(refreshedSessions(prev, prev.map(s => ({ ...s })))).toBe(prev)
+  })
+
+  it("takes the new list when a session moved on", () => {
+    const next = [session("a", 400, { nodeCount: 6 }), session("b", 200)]
+    expect(refreshedSessions(prev, next)).toBe(next)
+    expect(refreshedSessions(prev, [session("b", 200, { waitingForUser: true }), prev[0]])).not.toBe(prev)
+  })
+})
+
+describe("cycleRecentSession", () => {
+  const list = ["a", "b", "c", "d"].map((id, i) => session(id, 400 - i))
+
+  it("steps through the most recent sessions and wraps", () => {
+    expect(cycleRecentSession(list, "a", 1, 3)).toEqual({ index: 1, total: 3, id: "b" })
+    expect(cycleRecentSession(list, "c", 1, 3)?.id).toBe("a")
+    expect(cycleRecentSession(list, "a", -1, 3)?.id).toBe("c")
+  })
+
+  it("starts from the newest when the current session is older", () => {
+    expect(cycleRecentSession(list, "d", 1, 3)).toEqual({ index: 0, total: 3, id: "a" })
+  })
+
+  it("has nothing to switch to with a single session", () => {
+    expect(cycleRecentSession(list.slice(0, 1), "a", 1, 3)).toBeNull()
+  })
+})
@@ -0,0 +1,41 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { isAwaitingUser, diffLiveEvents } from "../src/core/state"
+import { graph, message, toolCall } from "./fixtures"
+
+function text(id: string, kind: "user" | "assistant", timestamp = 0, agentId?: string): Node {
+  return message(id, kind, { agentId, timestamp, branchLevel: agentId ? 1 : 0 })
+}
+
+function call(id: string, isError = false, output: string | null = "done"): Node {
+  return toolCall(id, "Bash", { output, isError })
+}
+
+describe("isAwaitingUser", () => {
+  it("is true once the main session ends on a reply", () => {
+    expect(isAwaitingUser([text("u", "user"), call("t"), text("a", "assistant")])).toBe(true)
+    expect(isAwaitingUser([text("u", "user"), text("a", "assistant"), call("t")])).toBe(false)
+    expect(isAwaitingUser([])).toBe(false)
+  })
+
+  it("looks past subagent activity", () => {
+    expect(isAwaitingUser([text("a", "assistant"), text("g", "assistant", 0, "agent-1")])).toBe(true)
+    expect(isAwaitingUser([text("u", "user"), text("g", "assistant", 0, "agent-1")])).toBe(false)
+  })
+})
+
+describe("diffLiveEvents", () => {
+  it("reports new tool failures and the hand-back to the user", () => {
+    const before = graph([text("u", "user"), call("t1", true, "old")])
+    const after = graph([text("u", "user"), call("t1", true, "old"), call("t2", true, "exit 1\nmore"), text("a", "assistant")])
+    expect(diffLiveEvents(before, after, "s1")).toEqual([
+      { kind: "tool_error", sessionId: "s1", nodeId: "t2", message: "Bash failed: exit 1" },
+      { kind: "waiting", sessionId: "s1", message: "waiting for input" },
+    ])
+  })
+
+  it("stays quiet when nothing changed", () => {
+    const g = graph([text("a", "assistant")])
+    expect(diffLiveEvents(g, g, "s1")).toEqual([])
+  })
+})
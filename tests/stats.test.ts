@@ -1,5 +1,5 @@
 import { describe, it, expect } from "bun:test"
-import { computeStats, emptyStats } from "../src/core/stats"
+import { computeStats, emptyStats, totalTokens } from "../src/core/stats"
 
 describe("computeStats", () => {
   it("returns zeros for empty input", () => {
@@ -58,6 +58,24 @@ describe("computeStats", () => {
     expect(s.totalOutputTokens).toBe(0)
     expect(s.model).toBe("gpt-4")
   })
+
+  it("takes the context size from the latest request", () => {
+    const s = computeStats([
+      { usage: { input_tokens: 10, cache_read_input_tokens: 100 } },
+      { usage: { input_tokens: 5, output_tokens: 40, cache_read_input_tokens: 300, cache_creation_input_tokens: 20 } },
+      { model: "claude-3-sonnet" },
+    ])
+    expect(s.contextTokens).toBe(325)
+    expect(computeStats([]).contextTokens).toBeUndefined()
+  })
+})
+
+describe("totalTokens", () => {
+  it("sums input, output and cache traffic", () => {
+    const s = computeStats([{ usage: { input_tokens: 1, output_tokens: 2, cache_read_input_tokens: 30, cache_creation_input_tokens: 400 } }])
+    expect(totalTokens(s)).toBe(433)
+    expect(totalTokens(emptyStats())).toBe(0)
+  })
 })
 
 describe("emptyStats", () => {
@@ -0,0 +1,65 @@
+import { describe, it, expect } from "bun:test"
+import { summarizeStatus, formatStatusBrief, statusExitCode, waitSatisfied, isWaitCondition, EXIT_WAITING, EXIT_RUNNING, EXIT_ERROR } from "../src/core/status"
+import { graph, user, reply, callAt as call } from "./fixtures"
+
+describe("summarizeStatus", () => {
+  it("names the tool that is still running", () => {
+    const status = summarizeStatus([user(0), call(60_000, null)], "claude:0123456789ab", 190_000)
+    expect(status).toMatchObject({ state: "tool-running", activeTool: { name: "Bash", elapsedMs: 130_000 }, turnElapsedMs: 190_000 })
+    expect(formatStatusBrief(status)).toBe("01234567 RUNNING Bash 2m10s")
+  })
+
+  it("shows how long a handed-back session has waited", () => {
+    const status = summarizeStatus([user(0), call(1000, "ok"), reply(2000)], "s1", 5000)
+    expect(status.activeTool).toBeNull()
+    expect(formatStatusBrief(status)).toBe("s1 WAITING 3s")
+  })
+
+  it("reports a quiet session as ended", () => {
+    expect(formatStatusBrief(summarizeStatus([user(0)], "s1", 24 * 3_600_000))).toBe("s1 ENDED")
+  })
+})
+
+describe("statusExitCode", () => {
+  it("maps session states to exit codes", () => {
+    expect(statusExitCode(summarizeStatus([user(0), reply(1000)], "s1", 2000))).toBe(EXIT_WAITING)
+    expect(statusExitCode(summarizeStatus([user(0), call(1000, null)], "s1", 2000))).toBe(EXIT_RUNNING)
+  })
+
+  it("reports an unrecovered failure in the latest turn once the agent stops", () => {
+    const failed = [user(0), call(1000, "exit 1", true), reply(2000)]
+    expect(statusExitCode(summarizeStatus(failed, "s1", 3000))).toBe(EXIT_ERROR)
+    const retried = [user(0), call(1000, "exit 1", true), call(1500, "ok"), reply(2000)]
+    expect(statusExitCode(summarizeStatus(retried, "s1", 3000))).toBe(EXIT_WAITING)
+    const earlierTurn = [user(0), call(1000, "exit 1", true), reply(2000), user(3000), reply(4000)]
+    expect(statusExitCode(summarizeStatus(earlierTurn, "s1", 5000))).toBe(EXIT_WAITING)
+  })
+})
+
+describe("waitSatisfied", () => {
+  const start = graph([user(0), call(1000, "exit 1", true)])
+
+  it("waits for the hand-back", () => {
+    expect(waitSatisfied("waiting", start, start, 2000)).toBe(false)
+    expect(waitSatisfied("waiting", start, graph([...start.nodes, reply(2000)]), 3000)).toBe(true)
+  })
+
+  it("only counts failures after the wait began", () => {
+    expect(waitSatisfied("error", start, start, 2000)).toBe(false)
+    expect(waitSatisfied("error", start, graph([...start.nodes, call(2000, "boom", true)]), 3000)).toBe(true)
+  })
+
+  it("waits out the idle period", () => {
+    expect(waitSatisfied("idle", start, start, 1000 + 59_000, 60_000)).toBe(false)
+    expect(waitSatisfied("idle", start, start, 1000 + 60_001, 60_000)).toBe(true)
+  })
+
+  it("doesn't read a running tool as idle", () => {
+    const running = graph([user(0), call(1000, null)])
+    expect(waitSatisfied("idle", running, running, 1000 + 600_000, 60_000)).toBe(false)
+  })
+
+  it("accepts only known conditions", () => {
+    expect(["waiting", "error", "idle", "done"].map(isWaitCondition)).toEqual([true, true, true, false])
+  })
+})
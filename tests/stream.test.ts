@@ -0,0 +1,30 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { firstChangedIndex, appendMessage } from "../src/core/stream"
+import { graph, toolCall } from "./fixtures"
+
+function call(id: string, output: string | null): Node {
+  return toolCall(id, "Bash", { output })
+}
+
+const user: Node = { id: "u", nodeType: { kind: "user", text: "go" }, timestamp: 0, branchLevel: 0 }
+
+describe("stream append messages", () => {
+  it("appends new nodes after the unchanged prefix", () => {
+    const msg = appendMessage(graph([user]), graph([user, call("t1", null)]), "s1")
+    expect(msg).toMatchObject({ type: "append", sessionId: "s1", from: 1 })
+    expect(msg?.type === "append" && msg.nodes.map(n => n.id)).toEqual(["t1"])
+  })
+
+  it("re-sends a tool call once its result arrives", () => {
+    expect(firstChangedIndex([user, call("t1", null)], [user, call("t1", "ok"), call("t2", null)])).toBe(1)
+  })
+
+  it("sends nothing when nothing changed", () => {
+    expect(appendMessage(graph([user, call("t1", "ok")]), graph([user, call("t1", "ok")]), "s1")).toBeNull()
+  })
+
+  it("truncates when the session shrank", () => {
+    expect(appendMessage(graph([user, call("t1", "ok")]), graph([user]), "s1")).toMatchObject({ from: 1, nodes: [] })
+  })
+})
@@ -0,0 +1,59 @@
+import { describe, it, expect } from "bun:test"
+import type { Graph, Node } from "../src/core/types"
+import { emptyStats } from "../src/core/stats"
+import { summarizeGraph, isHistorical } from "../src/core/summary"
+import { ENDED_AFTER_MS } from "../src/core/state"
+import { message, toolCall } from "./fixtures"
+
+function text(id: string, kind: "user" | "assistant", extra: Partial<Node> = {}): Node {
+  return message(id, kind, { branchLevel: kind === "user" ? 0 : 1, ...extra })
+}
+
+function call(id: string, isError: boolean): Node {
+  return toolCall(id, "Bash", { input: "", output: "", isError }, { branchLevel: 2 })
+}
+
+const graph: Graph = {
+  nodes: [
+    text("u1", "user"),
+    text("a1", "assistant"),
+    call("t1", false),
+    call("t2", true),
+    text("g1", "assistant", { agentId: "x" }),
+    text("a2", "assistant"),
+    text("u2", "user"),
+    text("a3", "assistant"),
+  ],
+  edges: [{ from: "u1", to: "a1", isBranch: false }, { from: "u1", to: "a2", isBranch: false }],
+  stats: emptyStats(),
+}
+
+describe("summarizeGraph", () => {
+  const summary = summarizeGraph(graph)
+
+  it("keeps each turn's prompt, final reply and failures", () => {
+    expect(summary.nodes.map(n => n.id)).toEqual(["u1", "t2", "a2", "u2", "a3"])
+  })
+
+  it("records what each turn left out on its first node", () => {
+    expect(summary.nodes[0].summarized).toBe(3)
+    expect(summary.nodes.find(n => n.id === "u2")?.summarized).toBeUndefined()
+  })
+
+  it("drops edges to nodes left out", () => {
+    expect(summary.edges).toEqual([{ from: "u1", to: "a2", isBranch: false }])
+  })
+
+  it("leaves a graph with nothing to cut alone", () => {
+    const small: Graph = { ...graph, nodes: [text("u2", "user"), text("a3", "assistant")] }
+    expect(summarizeGraph(small)).toBe(small)
+  })
+})
+
+describe("isHistorical", () => {
+  it("only counts sessions quiet for longer than the ended threshold", () => {
+    expect(isHistorical(graph, ENDED_AFTER_MS + 1)).toBe(true)
+    expect(isHistorical(graph, 1000)).toBe(false)
+    expect(isHistorical({ ...graph, nodes: [] }, ENDED_AFTER_MS + 1)).toBe(false)
+  })
+})
@@ -0,0 +1,65 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { testRunner, parseTestCounts, findTestRuns, runAt, formatTestCounts } from "../src/core/testruns"
+import { bash } from "./fixtures"
+
+function run(command: string, output: string, isError = false, ts = 0): Node {
+  return bash(`${command}-${ts}`, command, { output, isError }, { timestamp: ts })
+}
+
+describe("testRunner", () => {
+  it("recognizes common runners anywhere in the command", () => {
+    expect(testRunner("cargo test --workspace")).toBe("cargo test")
+    expect(testRunner("cd api && python -m pytest -x")).toBe("python -m pytest")
+    expect(testRunner("npm run test -- --watch=false")).toBe("npm run test")
+    expect(testRunner("bun test tests/zoom.test.ts")).toBe("bun test")
+    expect(testRunner("go test ./...")).toBe("go test")
+    expect(testRunner("git status")).toBeNull()
+    expect(testRunner("cargo build")).toBeNull()
+  })
+})
+
+describe("parseTestCounts", () => {
+  it("sums cargo's per-binary results", () => {
+    const out = "test result: ok. 10 passed; 0 failed; 1 ignored\ntest result: FAILED. 3 passed; 2 failed; 0 ignored"
+    expect(parseTestCounts(out)).toEqual({ passed: 13, failed: 2 })
+  })
+
+  it("reads pytest, bun, mocha and rspec summaries", () => {
+    expect(parseTestCounts("==== 1 failed, 10 passed in 0.5s ====")).toEqual({ passed: 10, failed: 1 })
+    expect(parseTestCounts(" 12 pass\n 1 fail\nRan 13 tests across 2 files.")).toEqual({ passed: 12, failed: 1 })
+    expect(parseTestCounts("  7 passing (20ms)")).toEqual({ passed: 7, failed: null })
+    expect(parseTestCounts("13 examples, 1 failure")).toEqual({ passed: 12, failed: 1 })
+  })
+
+  it("uses only the Tests line from jest and vitest", () => {
+    expect(parseTestCounts("Test Suites: 1 failed, 3 passed, 4 total\nTests:       2 failed, 20 passed, 22 total")).toEqual({ passed: 20, failed: 2 })
+    expect(parseTestCounts(" Test Files  1 failed | 3 passed (4)\n      Tests  2 failed | 20 passed (22)")).toEqual({ passed: 20, failed: 2 })
+  })
+
+  it("counts go's per-test lines", () => {
+    expect(parseTestCounts("--- PASS: TestA\n--- FAIL: TestB\n--- PASS: TestC")).toEqual({ passed: 2, failed: 1 })
+  })
+})
+
+describe("findTestRuns", () => {
+  const nodes = [
+    run("bun test", " 3 pass\n 2 fail", true, 1),
+    run("ls", "a b", false, 2),
+    run("bun test", " 5 pass\n 0 fail", false, 3),
+    run("make test", "all good", false, 4),
+  ]
+
+  it("tracks each run's outcome in order", () => {
+    const runs = findTestRuns(nodes)
+    expect(runs.map(r => [r.nodeIdx, r.ok, r.passed, r.failed])).toEqual([[0, false, 3, 2], [2, true, 5, 0], [3, true, null, 0]])
+    expect(formatTestCounts(runs[0])).toBe("3 passed, 2 failed")
+  })
+
+  it("finds the run at or before a node", () => {
+    const runs = findTestRuns(nodes)
+    expect(runAt(runs, 1)).toBe(0)
+    expect(runAt(runs, 3)).toBe(2)
+    expect(runAt([], 3)).toBe(-1)
+  })
+})
@@ -0,0 +1,48 @@
+import { describe, it, expect } from "bun:test"
+import { paint, cursorStyle, borderColor, setMonochrome, shouldDisableColor, setTheme, parseTheme } from "../src/ui/theme"
+
+describe("shouldDisableColor", () => {
+  it("honors the flag, NO_COLOR and non-tty output", () => {
+    expect(shouldDisableColor(true, {}, true)).toBe(true)
+    expect(shouldDisableColor(false, { NO_COLOR: "1" }, true)).toBe(true)
+    expect(shouldDisableColor(false, { NO_COLOR: "" }, true)).toBe(false)
+    expect(shouldDisableColor(false, {}, false)).toBe(true)
+    expect(shouldDisableColor(false, {}, true)).toBe(false)
+  })
+})
+
+describe("paint", () => {
+  it("passes colors through by default", () => {
+    setMonochrome(false)
+    expect(paint("red")).toEqual({ color: "red" })
+    expect(borderColor("cyan")).toBe("cyan")
+    expect(cursorStyle().backgroundColor).toBe("white")
+  })
+
+  it("uses modifiers instead of colors in monochrome", () => {
+    setMonochrome(true)
+    expect(paint("red").color).toBeUndefined()
+    expect(paint("red").bold).toBe(true)
+    expect(paint("gray").dimColor).toBe(true)
+    expect(borderColor("cyan")).toBeUndefined()
+    expect(cursorStyle()).toEqual({ inverse: true })
+    setMonochrome(false)
+  })
+
+  it("swaps colors for the accessible themes", () => {
+    setTheme("colorblind")
+    expect(paint("green")).toEqual({ color: "blue" })
+    expect(paint("red")).toEqual({ color: "yellow" })
+    expect(paint("cyan")).toEqual({ color: "cyan" })
+    setTheme("high-contrast")
+    expect(paint("gray")).toEqual({ color: "white", bold: true })
+    expect(borderColor("blue")).toBe("cyan")
+    setTheme("default")
+  })
+
+  it("only accepts known theme names", () => {
+    expect(parseTheme("colorblind")).toBe("colorblind")
+    expect(parseTheme("solarized")).toBeNull()
+    expect(parseTheme(undefined)).toBeNull()
+  })
+})
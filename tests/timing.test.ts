@@ -0,0 +1,44 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { toolTimings, timingAdvice, flaggedTimings, isErratic, TOOL_TIMEOUT_MS } from "../src/core/timing"
+
+let seq = 0
+function call(name: string, command: string | null, ms: number): Node {
+  const input = command === null ? "{}" : JSON.stringify({ command })
+  return { id: `t${++seq}`, nodeType: { kind: "tool_call", name, input, output: "ok", isError: false }, timestamp: 0, endTimestamp: ms, branchLevel: 2 }
+}
+
+describe("toolTimings", () => {
+  const nodes = [
+    call("Bash", "bun test", 5_000),
+    call("Bash", "bun test", 110_000),
+    call("Bash", "git status", 200),
+    call("Read", null, 50),
+    { ...call("Read", null, 0), endTimestamp: undefined },
+  ]
+
+  it("pairs calls with their results per tool and shell command", () => {
+    const rows = toolTimings(nodes)
+    expect(rows.map(r => [r.key, r.calls])).toEqual([["Bash: bun test", 2], ["Bash: git status", 1], ["Read", 1]])
+    expect(rows[0].maxMs).toBe(110_000)
+    expect(rows[0].nearTimeout).toBe(1)
+  })
+
+  it("advises backgrounding calls that come close to the timeout", () => {
+    const [bunTest] = toolTimings(nodes)
+    expect(timingAdvice(bunTest)).toBe(`1 of 2 near the ${TOOL_TIMEOUT_MS / 60_000}m timeout: run it in the background`)
+    expect(flaggedTimings(nodes).map(t => t.key)).toEqual(["Bash: bun test"])
+  })
+})
+
+describe("isErratic", () => {
+  it("needs enough slow, widely spread calls", () => {
+    const spread = [1_000, 1_000, 1_000, 60_000, 60_000].map(ms => call("mcp__ci__run", null, ms))
+    const [row] = toolTimings(spread)
+    expect(isErratic(row)).toBe(true)
+    expect(timingAdvice(row)).toContain("vary widely")
+
+    const fast = [10, 10, 10, 10, 900].map(ms => call("Grep", null, ms))
+    expect(isErratic(toolTimings(fast)[0])).toBe(false)
+  })
+})
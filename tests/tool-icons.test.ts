@@ -147,3 +147,35 @@ describe("tool icon rules", () => {
     expect(getToolUi(node as any)?.iconText).toBe("📖")
   })
 })
+
+describe("tool colors", () => {
+  it("resolves color and icon from separate rules", async () => {
+    const configPath = join(tmpdir(), `vizier-tool-icons-${Date.now()}.json`)
+    writeFileSync(
+      configPath,
+      JSON.stringify({
+        rules: [
+          { tool: "read", color: "cyan" },
+          { toolPattern: "^mcp__github__", icon: "🐙", color: "magenta" },
+        ],
+      }),
+    )
+    process.env.VIZIER_TOOL_ICONS = configPath
+    const { getToolUi } = await loadModule("../src/ui/tool-icons.ts")
+
+    const read: ToolNode = { nodeType: { kind: "tool_call", name: "Read", input: "" } }
+    const mcp: ToolNode = { nodeType: { kind: "tool_call", name: "mcp__github__create_issue", input: "" } }
+    expect(getToolUi(read as any)).toEqual({ iconText: "📖", iconId: undefined, color: "cyan" })
+    expect(getToolUi(mcp as any)).toEqual({ iconText: "🐙", iconId: undefined, color: "magenta" })
+  })
+
+  it("matches MCP tools by their base name", async () => {
+    const configPath = join(tmpdir(), `vizier-tool-icons-${Date.now()}.json`)
+    writeFileSync(configPath, JSON.stringify({ rules: [{ tool: "create_issue", icon: "🎫" }] }))
+    process.env.VIZIER_TOOL_ICONS = configPath
+    const { getToolUi } = await loadModule("../src/ui/tool-icons.ts")
+
+    const node: ToolNode = { nodeType: { kind: "tool_use", name: "mcp__github__create_issue", input: "" } }
+    expect(getToolUi(node as any)?.iconText).toBe("🎫")
+  })
+})
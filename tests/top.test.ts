@@ -0,0 +1,64 @@
+import { describe, it, expect } from "bun:test"
+import type { SessionInfo } from "../src/core/types"
+import { sessionActivity, rankActivity, isRecentSession, formatTop } from "../src/core/top"
+import { graph, user, reply as replyAt, callAt as call } from "./fixtures"
+import { displayWidth } from "../src/ui/width"
+
+const NOW = 10 * 3_600_000
+const MIN = 60_000
+
+const info = (id: string, timestamp = NOW): SessionInfo => ({ id, timestamp, nodeCount: 0, waitingForUser: false })
+const reply = (timestamp: number, tokens: number) => replyAt(timestamp, { usage: { input_tokens: tokens } })
+
+const busy = graph([user(NOW - 20 * MIN), reply(NOW - 4 * MIN, 6000), call(NOW - 2 * MIN, "boom", true), call(NOW - MIN, null)])
+const young = graph([user(NOW - 30_000), reply(NOW - 20_000, 1000)])
+
+describe("sessionActivity", () => {
+  it("measures tokens and tool calls over the window", () => {
+    const a = sessionActivity(busy, info("busy"), NOW, 5 * MIN)
+    expect(a).toMatchObject({ tokens: 6000, tokensPerMin: 1200, toolCalls: 2, errors: 1 })
+    expect(a.status.activeTool).toEqual({ name: "Bash", elapsedMs: MIN })
+  })
+
+  it("measures a young session over at least a minute", () => {
+    expect(sessionActivity(young, info("young"), NOW, 5 * MIN).tokensPerMin).toBe(1000)
+  })
+})
+
+describe("rankActivity", () => {
+  it("puts the fastest burner first", () => {
+    const rows = [sessionActivity(young, info("young"), NOW), sessionActivity(busy, info("busy"), NOW)]
+    expect(rankActivity(rows).map(r => r.sessionId)).toEqual(["busy", "young"])
+  })
+})
+
+describe("formatTop", () => {
+  it("shows one row per session with what it's doing", () => {
+    const out = formatTop([sessionActivity(busy, info("busy"), NOW)], NOW, "5m")
+    expect(out).toContain("rates over the last 5m")
+    expect(out).toMatch(/busy\s+RUNNING\s+1\.2k\s+6\.0k\s+-\s+2\s+1\s+Bash 1m00s/)
+  })
+
+  it("shows how long a session has waited on the user", () => {
+    const waiting = graph([user(NOW - 10 * MIN), reply(NOW - 3 * MIN, 100)])
+    expect(formatTop([sessionActivity(waiting, info("waiting"), NOW)], NOW, "5m")).toMatch(/waiting\s+WAITING\s.*idle 3m00s/)
+  })
+
+  it("aligns columns by display width", () => {
+    const rows = [sessionActivity(busy, { ...info("busy"), title: "日本語のタイトル" }, NOW), sessionActivity(young, { ...info("young"), title: "plain" }, NOW)]
+    const lines = formatTop(rankActivity(rows), NOW, "5m").trimEnd().split("\n").slice(2)
+    const titleColumn = lines.map(l => displayWidth(l) - displayWidth(l.replace(/^.*  /, "")))
+    expect(new Set(titleColumn).size).toBe(1)
+  })
+
+  it("says so when nothing is running", () => {
+    expect(formatTop([], NOW, "5m")).toContain("No sessions active")
+  })
+})
+
+describe("isRecentSession", () => {
+  it("drops sessions that ended", () => {
+    expect(isRecentSession(info("a", NOW - 10 * MIN), NOW)).toBe(true)
+    expect(isRecentSession(info("a", NOW - 31 * MIN), NOW)).toBe(false)
+  })
+})
@@ -0,0 +1,62 @@
+import { describe, it, expect } from "bun:test"
+import type { Node } from "../src/core/types"
+import { buildTranscript, buildReadingLines, lineOfNode } from "../src/core/transcript"
+import { node } from "./fixtures"
+
+const nodes: Node[] = [
+  node("u1", { kind: "user", text: "fix the bug" }),
+  node("p1", { kind: "progress", text: "..." }),
+  node("a1", { kind: "assistant", text: "one two three four five six seven eight nine ten" }),
+  node("t1", { kind: "tool_call", name: "Read", input: "{\"file\":\"a.ts\"}", output: "ok", isError: false }),
+]
+
+describe("buildTranscript", () => {
+  it("renders messages with headers and tools on one line", () => {
+    const lines = buildTranscript(nodes, 80)
+    expect(lines.map(l => l.text)).toEqual([
+      "User:",
+      "  fix the bug",
+      "",
+      "Assistant:",
+      "  one two three four five six seven eight nine ten",
+      "  [Read ok] {\"file\":\"a.ts\"}",
+    ])
+    expect(lines[5].nodeIdx).toBe(3)
+  })
+
+  it("wraps long message text at word boundaries", () => {
+    const lines = buildTranscript(nodes, 20).filter(l => l.nodeIdx === 2 && l.text.startsWith("  "))
+    expect(lines.length).toBeGreaterThan(1)
+    for (const l of lines) expect(l.text.length).toBeLessThanOrEqual(20)
+  })
+})
+
+describe("lineOfNode", () => {
+  const lines = buildTranscript(nodes, 80)
+
+  it("finds the first line of a node", () => {
+    expect(lineOfNode(lines, 0)).toBe(0)
+    expect(lineOfNode(lines, 2)).toBe(2)
+    expect(lineOfNode(lines, 3)).toBe(5)
+  })
+
+  it("falls back to the preceding node for nodes without lines", () => {
+    expect(lineOfNode(lines, 1)).toBe(0)
+  })
+})
+
+describe("buildReadingLines", () => {
+  it("keeps only main-session prompts and replies", () => {
+    const withAgent: Node[] = [...nodes, { ...node("g1", { kind: "assistant", text: "agent" }), agentId: "a", branchLevel: 1 }]
+    const lines = buildReadingLines(withAgent, 80)
+    expect(lines.filter(l => l.heading).map(l => l.nodeIdx)).toEqual([0, 2])
+    expect(lines.some(l => l.text.includes("Read"))).toBe(false)
+    expect(lines.some(l => l.text === "agent")).toBe(false)
+  })
+
+  it("heads each message with its local time", () => {
+    const at = new Date(2025, 0, 1, 9, 5).getTime()
+    const lines = buildReadingLines([{ ...nodes[0], timestamp: at }], 80)
+    expect(lines[0].text).toEndWith("  09:05")
+  })
+})
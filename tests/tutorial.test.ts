@@ -0,0 +1,25 @@
+import { describe, it, expect } from "bun:test"
+import { TUTORIAL_STEPS, advanceTutorial } from "../src/core/tutorial"
+
+describe("advanceTutorial", () => {
+  it("advances on one of the current step's keys", () => {
+    expect(advanceTutorial(0, "l")).toBe(1)
+    expect(advanceTutorial(0, "h")).toBe(1)
+    expect(advanceTutorial(1, "j")).toBe(2)
+  })
+
+  it("ignores other keys, including later steps' keys", () => {
+    expect(advanceTutorial(0, "d")).toBe(0)
+    expect(advanceTutorial(0, "x")).toBe(0)
+  })
+
+  it("stays finished after the last step", () => {
+    const last = TUTORIAL_STEPS.length - 1
+    expect(advanceTutorial(last, TUTORIAL_STEPS[last].keys[0])).toBe(TUTORIAL_STEPS.length)
+    expect(advanceTutorial(TUTORIAL_STEPS.length, "l")).toBe(TUTORIAL_STEPS.length)
+  })
+
+  it("gives every step a key", () => {
+    expect(TUTORIAL_STEPS.every(s => s.keys.length > 0)).toBe(true)
+  })
+})
@@ -0,0 +1,45 @@
+import { describe, it, expect } from "bun:test"
+import { validateSession, formatValidationReport, validationReportToJson } from "../src/sources/claude/validate"
+
+const ts = "2025-01-15T09:00:00.000Z"
+const line = (event: unknown) => JSON.stringify(event)
+
+describe("validateSession", () => {
+  it("reports malformed lines, unknown types and missing fields with line numbers", () => {
+    const content = [
+      line({ type: "user", timestamp: ts, message: { role: "user", content: "hi" } }),
+      "{not json",
+      "",
+      line({ type: "telemetry", timestamp: ts }),
+      line({ type: "assistant", timestamp: "yesterday", message: { role: "assistant", content: "ok" } }),
+      line({ uuid: "x" }),
+    ].join("\n")
+    const report = validateSession([{ path: "s.jsonl", content }])
+    expect(report.lines).toBe(5)
+    expect(report.issues.map(i => [i.line, i.kind])).toEqual([
+      [2, "malformed"],
+      [4, "unknown-type"],
+      [5, "missing-field"],
+      [6, "missing-field"],
+    ])
+  })
+
+  it("matches tool results across subagent files", () => {
+    const main = line({ type: "assistant", timestamp: ts, message: { role: "assistant", content: [{ type: "tool_use", id: "t1", name: "Task" }] } })
+    const agent = [
+      line({ type: "assistant", timestamp: ts, message: { role: "assistant", content: [{ type: "tool_use", id: "t2", name: "Read" }] } }),
+      line({ type: "user", timestamp: ts, message: { role: "user", content: [{ type: "tool_result", tool_use_id: "t2" }] } }),
+      line({ type: "user", timestamp: ts, message: { role: "user", content: [{ type: "tool_result", tool_use_id: "t9" }] } }),
+    ].join("\n")
+    const report = validateSession([{ path: "s.jsonl", content: main }, { path: "agent.jsonl", content: agent }])
+    expect(report.issues).toEqual([
+      { file: "agent.jsonl", line: 3, kind: "orphan-result", message: "tool result for unknown tool use t9" },
+    ])
+  })
+
+  it("summarizes a clean session", () => {
+    const report = validateSession([{ path: "s.jsonl", content: line({ type: "summary", summary: "x" }) }])
+    expect(formatValidationReport(report)).toBe("OK: 1 line in 1 file\n")
+    expect(JSON.parse(validationReportToJson(report))).toEqual({ schemaVersion: 1, kind: "validate", issues: [], files: 1, lines: 1 })
+  })
+})
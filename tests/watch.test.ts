@@ -0,0 +1,86 @@
+import { describe, it, expect } from "bun:test"
+import type { ChokidarOptions } from "chokidar"
+import type { WatchFactory } from "../src/sources/watch"
+import { watchFiles, watchFailureReason, pollingNotice, POLL_INTERVAL_MS } from "../src/sources/watch"
+
+function fsError(code: string): Error {
+  return Object.assign(new Error(`${code}: failed`), { code })
+}
+
+describe("watch fallback notice", () => {
+  it("names the usual refusals", () => {
+    expect(watchFailureReason(fsError("ENOSPC"))).toBe("inotify watch limit reached")
+    expect(watchFailureReason(fsError("EMFILE"))).toBe("too many open files")
+    expect(watchFailureReason(fsError("EACCES"))).toBe("permission denied")
+  })
+
+  it("falls back to the error's own message", () => {
+    expect(watchFailureReason(new Error("boom"))).toBe("boom")
+    expect(watchFailureReason("odd")).toBe("odd")
+  })
+
+  it("says how often it polls", () => {
+    expect(pollingNotice(fsError("ENOSPC"))).toBe("file watching unavailable (inotify watch limit reached): polling every 1s")
+  })
+})
+
+type StubWatcher = { paths: string[]; options: ChokidarOptions; listeners: Map<string, (arg: unknown) => void>; closed: boolean }
+
+// Records each watcher chokidar would have made; the first can refuse to start
+function stubWatch(refuseFirst = false): { made: StubWatcher[]; watch: WatchFactory } {
+  const made: StubWatcher[] = []
+  const watch = (paths: string[], options: ChokidarOptions) => {
+    if (refuseFirst && made.length === 0 && !options.usePolling) throw fsError("ENOSPC")
+    const stub: StubWatcher = { paths: [...paths], options, listeners: new Map(), closed: false }
+    made.push(stub)
+    const watcher = {
+      on(event: string, listener: (arg: unknown) => void) {
+        stub.listeners.set(event, listener)
+        return watcher
+      },
+      add(path: string) {
+        stub.paths.push(path)
+        return watcher
+      },
+      close() {
+        stub.closed = true
+        return Promise.resolve()
+      },
+    }
+    return watcher as never
+  }
+  return { made, watch }
+}
+
+describe("watchFiles", () => {
+  it("polls when the native watcher can't start", () => {
+    const { made, watch } = stubWatch(true)
+    const notices: string[] = []
+    watchFiles(["/a.jsonl"], { ignoreInitial: true }, {}, n => notices.push(n), watch)
+    expect(made.length).toBe(1)
+    expect(made[0].options).toEqual({ ignoreInitial: true, usePolling: true, interval: POLL_INTERVAL_MS })
+    expect(notices).toEqual([pollingNotice(fsError("ENOSPC"))])
+  })
+
+  it("switches to polling on a watch error and says so once", () => {
+    const { made, watch } = stubWatch()
+    const notices: string[] = []
+    const changed: string[] = []
+    const watcher = watchFiles(["/a.jsonl"], {}, { change: p => changed.push(p) }, n => notices.push(n), watch)
+    watcher.add("/b.jsonl")
+    made[0].listeners.get("error")!(fsError("ENOSPC"))
+    expect(made[0].closed).toBe(true)
+    expect(made[1].options.usePolling).toBe(true)
+    expect(made[1].paths).toEqual(["/a.jsonl", "/b.jsonl"])
+
+    made[1].listeners.get("error")!(fsError("EMFILE"))
+    made[0].listeners.get("error")!(fsError("ENOSPC"))
+    expect(made.length).toBe(2)
+    expect(notices.length).toBe(1)
+
+    made[1].listeners.get("change")!("/b.jsonl")
+    expect(changed).toEqual(["/b.jsonl"])
+    watcher.close()
+    expect(made[1].closed).toBe(true)
+  })
+})
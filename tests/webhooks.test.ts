@@ -0,0 +1,31 @@
+import { describe, it, expect } from "bun:test"
+import type { LiveEvent } from "../src/core/state"
+import { webhookPayload, wantsEvent } from "../src/notify/webhooks"
+
+const event: LiveEvent = { kind: "tool_error", sessionId: "claude:0123456789", nodeId: "t1", message: "Bash failed: exit 1" }
+
+describe("webhookPayload", () => {
+  it("sends chat messages to Slack and Discord", () => {
+    expect(webhookPayload({ url: "x", format: "slack" }, event)).toEqual({ text: "vizier: session 01234567 Bash failed: exit 1" })
+    expect(webhookPayload({ url: "x", format: "discord" }, event)).toEqual({ content: "vizier: session 01234567 Bash failed: exit 1" })
+  })
+
+  it("sends the event itself to generic endpoints", () => {
+    expect(webhookPayload({ url: "x" }, event, Date.UTC(2025, 0, 1))).toEqual({
+      event: "tool_error",
+      sessionId: "claude:0123456789",
+      nodeId: "t1",
+      message: "Bash failed: exit 1",
+      state: null,
+      time: "2025-01-01T00:00:00.000Z",
+    })
+    expect(webhookPayload({ url: "x" }, { ...event, state: "tool-running" })).toMatchObject({ state: "tool-running" })
+  })
+})
+
+describe("wantsEvent", () => {
+  it("defaults to every event", () => {
+    expect(wantsEvent({ url: "x" }, "completed")).toBe(true)
+    expect(wantsEvent({ url: "x", events: ["waiting"] }, "tool_error")).toBe(false)
+  })
+})
@@ -0,0 +1,44 @@
+import { describe, it, expect } from "bun:test"
+import type { Graph, Node, Usage } from "../src/core/types"
+import { emptyStats } from "../src/core/stats"
+import { heavyPrompts, withPromptWeights, weightBadge } from "../src/core/weight"
+import { message } from "./fixtures"
+
+function prompt(id: string): Node {
+  return message(id, "user")
+}
+
+function reply(id: string, usage: Usage): Node {
+  return message(id, "assistant", { branchLevel: 1, usage })
+}
+
+const nodes: Node[] = [
+  prompt("u1"), reply("a1", { input_tokens: 2_000, cache_read_input_tokens: 90_000 }),
+  prompt("u2"), reply("a2", { input_tokens: 1_000, cache_creation_input_tokens: 40_000 }),
+  prompt("u3"), reply("a3", { input_tokens: 3_000, cache_read_input_tokens: 150_000 }),
+  prompt("u4"), reply("a4", { input_tokens: 2_500 }),
+]
+
+describe("heavyPrompts", () => {
+  it("flags the turn that pulled in far more new context than the median", () => {
+    expect([...heavyPrompts(nodes)]).toEqual([["u2", 41_000]])
+  })
+
+  it("ignores cache reads, however large", () => {
+    expect(heavyPrompts(nodes).has("u3")).toBe(false)
+  })
+
+  it("needs a minimum size before anything is heavy", () => {
+    const light = [prompt("u1"), reply("a1", { input_tokens: 100 }), prompt("u2"), reply("a2", { input_tokens: 5_000 })]
+    expect(heavyPrompts(light).size).toBe(0)
+  })
+})
+
+describe("withPromptWeights", () => {
+  it("adds the weight after the prompt's other badges", () => {
+    const graph: Graph = { nodes: nodes.map(n => n.id === "u2" ? { ...n, badges: ["JIRA-1"] } : n), edges: [], stats: emptyStats() }
+    const weighed = withPromptWeights(graph, heavyPrompts(nodes))
+    expect(weighed.nodes[2].badges).toEqual(["JIRA-1", weightBadge(41_000)])
+    expect(weightBadge(41_000)).toBe("+41.0k ctx")
+  })
+})
@@ -0,0 +1,28 @@
+import { describe, it, expect } from "bun:test"
+import { displayWidth, sliceToWidth, truncateToWidth, padToWidth, padStartToWidth, wrapRanges } from "../src/ui/width"
+
+describe("column width helpers", () => {
+  it("counts wide characters as two columns", () => {
+    expect(displayWidth("abc")).toBe(3)
+    expect(displayWidth("日本語")).toBe(6)
+  })
+
+  it("never splits a wide character", () => {
+    expect(sliceToWidth("日本語", 5)).toBe("日本")
+    expect(truncateToWidth("日本語テキスト", 7, "~")).toBe("日本語~")
+    expect(truncateToWidth("short", 10)).toBe("short")
+  })
+
+  it("pads to an exact column count", () => {
+    expect(padToWidth("日本", 6)).toBe("日本  ")
+    expect(padToWidth("日本語", 5)).toBe("日本 ")
+    expect(padStartToWidth("日本", 6)).toBe("  日本")
+  })
+
+  it("wraps at spaces by columns, not characters", () => {
+    const line = "日本語 テキスト"
+    const parts = wrapRanges(line, 8).map(([a, b]) => line.slice(a, b))
+    expect(parts).toEqual(["日本語", "テキスト"])
+    for (const p of parts) expect(displayWidth(p)).toBeLessThanOrEqual(8)
+  })
+})
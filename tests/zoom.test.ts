@@ -1,6 +1,6 @@
 import { describe, it, expect } from "bun:test"
 import type { Node } from "../src/core/types"
-import { getVisualBranch, filterByZoom, getNodePreview, findStickyNode } from "../src/core/zoom"
+import { getVisualBranch, filterByZoom, getNodePreview, findStickyNode, applyVisibility, SHOW_ALL } from "../src/core/zoom"
 
 function node(kind: Node["nodeType"]["kind"], branchLevel = 0): Node {
   return {
@@ -79,3 +79,33 @@ describe("findStickyNode", () => {
     expect(idx).toBe(1)
   })
 })
+
+describe("applyVisibility", () => {
+  const graph = {
+    nodes: [
+      node("user"),
+      node("progress"),
+      node("assistant"),
+      { ...node("tool_result"), nodeType: { kind: "tool_result", output: "ok", isError: false } },
+      { ...node("tool_result"), nodeType: { kind: "tool_result", output: "boom", isError: true } },
+    ] as Node[],
+    edges: [],
+    stats: { totalInputTokens: 0, totalOutputTokens: 0, totalCacheRead: 0, totalCacheCreation: 0, model: null },
+  }
+
+  it("returns the same graph when nothing is hidden", () => {
+    expect(applyVisibility(graph, SHOW_ALL)).toBe(graph)
+  })
+
+  it("drops progress nodes when masked", () => {
+    const visible = applyVisibility(graph, { ...SHOW_ALL, hideProgress: true })
+    expect(visible.nodes.map(n => n.nodeType.kind)).toEqual(["user", "assistant", "tool_result", "tool_result"])
+    expect(visible.stats).toBe(graph.stats)
+  })
+
+  it("compact mode keeps failed results only", () => {
+    const visible = applyVisibility(graph, { ...SHOW_ALL, hideOkResults: true })
+    expect(visible.nodes).toHaveLength(4)
+    expect(visible.nodes[3].nodeType).toEqual({ kind: "tool_result", output: "boom", isError: true })
+  })
+})